@@ -44,9 +44,16 @@ pub enum Commands {
         /// Generate SWR hooks
         #[arg(long, action = clap::ArgAction::SetTrue)]
         swr: bool,
+        /// Skip operations marked `deprecated: true`
+        #[arg(long)]
+        no_deprecated: bool,
     },
     /// Update existing generated code
-    Update,
+    Update {
+        /// Skip operations marked `deprecated: true`
+        #[arg(long)]
+        no_deprecated: bool,
+    },
     /// Inspect OpenAPI spec without generating code
     Inspect {
         /// Path or URL to Swagger/OpenAPI spec (for single-spec mode)
@@ -71,6 +78,30 @@ pub enum Commands {
         #[arg(long)]
         json: bool,
     },
+    /// Serve a spec locally with mock responses
+    Mock {
+        /// Mock specific spec by name (for multi-spec mode)
+        #[arg(long)]
+        spec_name: Option<String>,
+        /// Port to listen on
+        #[arg(short, long, default_value_t = 4010)]
+        port: u16,
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Reject requests whose parameters or body do not match the spec
+        #[arg(long)]
+        validate: bool,
+        /// Delay responses by a fixed (200) or random (100-500) number of milliseconds
+        #[arg(long, value_parser = crate::mock::parse_delay)]
+        delay: Option<crate::mock::Delay>,
+        /// Fraction of requests (0 to 1) answered with --error-status
+        #[arg(long, default_value_t = 0.0, value_parser = crate::mock::parse_error_rate)]
+        error_rate: f64,
+        /// Status of injected errors
+        #[arg(long, default_value_t = 500)]
+        error_status: u16,
+    },
     /// Manage templates
     Templates {
         #[command(subcommand)]
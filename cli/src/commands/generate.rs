@@ -4,7 +4,6 @@ use crate::error::Result;
 use crate::progress::ProgressReporter;
 use colored::*;
 use std::path::PathBuf;
-use tabled::Tabled;
 
 #[allow(clippy::too_many_arguments)]
 pub async fn run(
@@ -17,6 +16,7 @@ pub async fn run(
     force: bool,
     react_query: bool,
     swr: bool,
+    no_deprecated: bool,
 ) -> Result<()> {
     // Validate hook flags - only one can be set
     let hook_flags_count = [react_query, swr].iter().filter(|&&f| f).count();
@@ -41,20 +41,6 @@ pub async fn run(
     // Resolve which specs to generate
     let specs_to_generate = resolve_spec_selection(&config, spec_name.clone(), all_specs)?;
 
-    // Ensure runtime client exists at root_dir (shared across all specs)
-    use crate::generator::writer::{ensure_directory, write_runtime_client};
-    let root_dir_path = PathBuf::from(&config.root_dir);
-    ensure_directory(&root_dir_path)?;
-    let runtime_dir = root_dir_path.join("runtime");
-    if !runtime_dir.exists() {
-        // Use first spec's apis config for runtime client configuration (or default)
-        let apis_config = config.specs.first().map(|s| &s.apis);
-        write_runtime_client(&root_dir_path, None, apis_config)?;
-        if verbose {
-            progress.success("Created runtime client files");
-        }
-    }
-
     // Determine hook type: CLI flags take precedence, then check config
     let hook_type = if react_query {
         Some(crate::specs::runner::HookType::ReactQuery)
@@ -94,8 +80,27 @@ pub async fn run(
         },
         verbose,
         hook_type,
+        skip_deprecated: no_deprecated,
     };
 
+    // Write the runtime client at root_dir (shared across all specs); existing files are
+    // rewritten so they stay in sync with the generated code that calls them
+    use crate::generator::writer::{ensure_directory, write_runtime_client_with_options};
+    let root_dir_path = PathBuf::from(&config.root_dir);
+    ensure_directory(&root_dir_path)?;
+    // The first spec decides whether the axios client is written as well
+    let apis_config = config.specs.first().map(|s| &s.apis);
+    write_runtime_client_with_options(
+        &root_dir_path,
+        None,
+        apis_config,
+        options.use_backup,
+        options.use_force,
+    )?;
+    if verbose {
+        progress.success("Wrote runtime client files");
+    }
+
     if specs_to_generate.len() > 1 {
         // Generate all selected specs
         progress.success("Starting multi-spec generation...");
@@ -161,10 +161,14 @@ pub async fn run() -> Result<()> {
 
         println!();
 
-        let spec_api_style_options = ["fetch"];
+        let spec_api_style_options = ["fetch", "axios", "angular"];
         let spec_api_style_index = Select::new()
             .with_prompt(format!("API client style for '{}'", spec_name.trim()))
-            .items(&["fetch - Native Fetch API (recommended)"])
+            .items(&[
+                "fetch - Native Fetch API (recommended)",
+                "axios - Injectable Axios instance",
+                "angular - Angular HttpClient services",
+            ])
             .default(0)
             .interact()
             .map_err(|e| GenerationError::InvalidOperation {
@@ -276,18 +280,18 @@ pub async fn run() -> Result<()> {
                 schemas: crate::config::model::SchemasConfig {
                     output: spec_schemas_output.trim().to_string(),
                     naming: spec_naming,
+                    ..Default::default()
                 },
                 apis: crate::config::model::ApisConfig {
                     output: spec_apis_output.trim().to_string(),
                     style: spec_api_style,
                     base_url: spec_base_url,
                     header_strategy: spec_header_strategy,
-                    timeout: None,
-                    retries: None,
-                    retry_delay: None,
-                    headers: None,
+                    ..Default::default()
                 },
                 hooks: hooks_config,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig {
                     ignore: vec![],
                     selected: vec![],
@@ -358,6 +362,7 @@ pub async fn run() -> Result<()> {
             config.generation.conflict_strategy == "force", // force
             false,                                          // react_query
             false,                                          // swr
+            false,                                          // no_deprecated
         )
         .await
         {
@@ -1,5 +1,7 @@
+use crate::config::model::SpecEntry;
 use crate::error::Result;
-use crate::generator::swagger_parser::fetch_and_parse_spec;
+use crate::generator::deprecation::{deprecated_operations, operation_deprecation};
+use crate::generator::swagger_parser::{fetch_and_parse_spec, ParsedSpec};
 use colored::*;
 use tabled::{Table, Tabled};
 
@@ -62,7 +64,8 @@ pub async fn run(
                                 "endpoints": ops,
                                 "schemas": schemas_count
                             })
-                        }).collect::<Vec<_>>()
+                        }).collect::<Vec<_>>(),
+                        "deprecated_operations": deprecated_operations_json(&parsed, spec_entry),
                     }));
                 }
                 println!(
@@ -97,6 +100,7 @@ pub async fn run(
                             .sum::<usize>()
                     );
                     println!("  • Total schemas: {}", parsed.schemas.len());
+                    print_deprecated_operations(&parsed, spec_entry);
                     println!();
                 }
             }
@@ -104,7 +108,7 @@ pub async fn run(
         } else if let Some(name) = spec_name {
             // Inspect specific spec by name
             let spec_entry = get_spec_by_name(&config, &name)?;
-            let spec_path = spec_entry.path;
+            let spec_path = spec_entry.path.clone();
             let parsed = crate::generator::swagger_parser::fetch_and_parse_spec(&spec_path).await?;
 
             if json {
@@ -122,7 +126,8 @@ pub async fn run(
                             "endpoints": ops,
                             "schemas": schemas_count
                         })
-                    }).collect::<Vec<_>>()
+                    }).collect::<Vec<_>>(),
+                    "deprecated_operations": deprecated_operations_json(&parsed, &spec_entry),
                 });
                 println!(
                     "{}",
@@ -150,6 +155,7 @@ pub async fn run(
                         .sum::<usize>()
                 );
                 println!("  • Total schemas: {}", parsed.schemas.len());
+                print_deprecated_operations(&parsed, &spec_entry);
                 println!();
 
                 if let Some(module_name) = module {
@@ -226,6 +232,7 @@ pub async fn run(
                         .sum::<usize>()
                 );
                 println!("  • Total schemas: {}", parsed.schemas.len());
+                print_deprecated_operations(&parsed, spec_entry);
                 println!();
             }
             return Ok(());
@@ -274,7 +281,8 @@ pub async fn run(
                     "endpoints": ops,
                     "schemas": schemas_count
                 })
-            }).collect::<Vec<_>>()
+            }).collect::<Vec<_>>(),
+            "deprecated_operations": deprecated_operations_json(&parsed, &spec_entry),
         });
         println!(
             "{}",
@@ -297,6 +305,7 @@ pub async fn run(
                 .sum::<usize>()
         );
         println!("  • Total schemas: {}", parsed.schemas.len());
+        print_deprecated_operations(&parsed, &spec_entry);
         println!();
 
         if let Some(module_name) = module {
@@ -347,3 +356,58 @@ pub async fn run(
 
     Ok(())
 }
+
+/// Modules a spec generates: its selected modules, or every module not ignored.
+fn generated_modules(parsed: &ParsedSpec, spec_entry: &SpecEntry) -> Vec<String> {
+    if spec_entry.modules.selected.is_empty() {
+        parsed
+            .modules
+            .iter()
+            .filter(|m| !spec_entry.modules.ignore.contains(m))
+            .cloned()
+            .collect()
+    } else {
+        spec_entry.modules.selected.clone()
+    }
+}
+
+fn deprecated_operations_json(parsed: &ParsedSpec, spec_entry: &SpecEntry) -> serde_json::Value {
+    let modules = generated_modules(parsed, spec_entry);
+    deprecated_operations(parsed, &modules)
+        .into_iter()
+        .map(|(module, op)| {
+            serde_json::json!({
+                "module": module,
+                "method": op.method,
+                "path": op.path,
+                "operation_id": op.operation.operation_id,
+                "deprecation": operation_deprecation(&op.operation),
+            })
+        })
+        .collect()
+}
+
+/// List deprecated operations the spec's generated modules still use.
+fn print_deprecated_operations(parsed: &ParsedSpec, spec_entry: &SpecEntry) {
+    let modules = generated_modules(parsed, spec_entry);
+    let deprecated = deprecated_operations(parsed, &modules);
+    if deprecated.is_empty() {
+        return;
+    }
+    println!(
+        "{}",
+        format!("  ⚠️  Deprecated operations in use: {}", deprecated.len()).yellow()
+    );
+    for (module, op) in deprecated {
+        let notice = operation_deprecation(&op.operation)
+            .and_then(|tag| {
+                tag.strip_prefix("@deprecated")
+                    .map(|notice| notice.trim().to_string())
+            })
+            .filter(|notice| !notice.is_empty());
+        match notice {
+            Some(notice) => println!("    - {} {} ({}): {}", op.method, op.path, module, notice),
+            None => println!("    - {} {} ({})", op.method, op.path, module),
+        }
+    }
+}
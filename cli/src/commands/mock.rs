@@ -0,0 +1,87 @@
+use crate::error::{NetworkError, Result};
+use crate::mock::server::serve;
+use crate::mock::{Delay, MockOptions, MockServer};
+use colored::*;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+pub async fn run(
+    spec_name: Option<String>,
+    host: String,
+    port: u16,
+    validate: bool,
+    delay: Option<Delay>,
+    error_rate: f64,
+    error_status: u16,
+) -> Result<()> {
+    use crate::specs::manager::resolve_spec_selection;
+
+    let config = crate::config::loader::load_config()?;
+    crate::config::validator::validate_config(&config)?;
+
+    let spec = resolve_spec_selection(&config, spec_name, false)?.remove(0);
+    let parsed = crate::generator::swagger_parser::fetch_and_parse_spec_with_cache_and_name(
+        &spec.path,
+        config.generation.enable_cache,
+        Some(&spec.name),
+    )
+    .await?;
+
+    let server = Arc::new(MockServer::new(
+        parsed.openapi,
+        MockOptions {
+            validate,
+            delay,
+            error_rate,
+            error_status,
+        },
+    ));
+
+    let address = format!("{}:{}", host, port);
+    let listener =
+        TcpListener::bind(&address)
+            .await
+            .map_err(|source| NetworkError::BindFailed {
+                address: address.clone(),
+                source,
+            })?;
+
+    println!(
+        "{}",
+        format!(
+            "🚀 Mocking '{}' ({} operations) on http://{}",
+            spec.name,
+            server.router().routes().len(),
+            address
+        )
+        .bright_green()
+    );
+    if validate {
+        println!("  Requests are validated against the spec");
+    }
+    if let Some(delay) = delay {
+        println!("  Delay: {}-{} ms", delay.min, delay.max);
+    }
+    if error_rate > 0.0 {
+        println!(
+            "  {}% of requests fail with {}",
+            error_rate * 100.0,
+            error_status
+        );
+    }
+    println!(
+        "{}",
+        "  Send `Prefer: code=404` or `Prefer: example=<name>` to pick a response. Press Ctrl+C to stop."
+            .dimmed()
+    );
+    println!();
+
+    tokio::select! {
+        result = serve(listener, server) => result.map_err(|source| NetworkError::BindFailed {
+            address,
+            source,
+        })?,
+        _ = tokio::signal::ctrl_c() => {}
+    }
+    Ok(())
+}
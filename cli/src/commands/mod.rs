@@ -2,5 +2,6 @@ pub mod add;
 pub mod generate;
 pub mod init;
 pub mod inspect;
+pub mod mock;
 pub mod templates;
 pub mod update;
@@ -4,10 +4,14 @@ use crate::error::{FileSystemError, Result};
 use crate::formatter::FormatterManager;
 use crate::generator::swagger_parser::filter_common_schemas;
 use crate::generator::writer::write_api_client_with_options;
+use crate::specs::runner::{
+    write_module_json_schemas, write_module_test_helpers, write_spec_aggregates, write_spec_files,
+    SpecOutput,
+};
 use colored::*;
 use std::path::{Path, PathBuf};
 
-pub async fn run() -> Result<()> {
+pub async fn run(no_deprecated: bool) -> Result<()> {
     println!("{}", "🔄 Updating generated code...".bright_cyan());
     println!();
 
@@ -65,14 +69,22 @@ pub async fn run() -> Result<()> {
         // Get hooks config (use defaults if not specified)
         let hooks_config = spec.hooks.clone().unwrap_or_default();
 
-        // Ensure runtime client exists at root_dir (shared across all specs)
-        use crate::generator::writer::{ensure_directory, write_runtime_client};
+        // Get force and backup settings from config
+        let use_force = config.generation.conflict_strategy == "force";
+        let use_backup = config.generation.enable_backup;
+
+        // Rewrite the runtime client at root_dir (shared across all specs) so it matches
+        // the functions regenerated below
+        use crate::generator::writer::{ensure_directory, write_runtime_client_with_options};
         let root_dir_path = PathBuf::from(&config.root_dir);
         ensure_directory(&root_dir_path)?;
-        let runtime_dir = root_dir_path.join("runtime");
-        if !runtime_dir.exists() {
-            write_runtime_client(&root_dir_path, None, Some(apis_config))?;
-        }
+        write_runtime_client_with_options(
+            &root_dir_path,
+            None,
+            Some(apis_config),
+            use_backup,
+            use_force,
+        )?;
 
         // Print fetch message only once per unique URL
         if !printed_urls.contains(spec_path) {
@@ -85,12 +97,17 @@ pub async fn run() -> Result<()> {
 
         // Use caching for update command (same as generate)
         let use_cache = config.generation.enable_cache;
-        let parsed = crate::generator::swagger_parser::fetch_and_parse_spec_with_cache_and_name(
-            spec_path,
-            use_cache,
-            Some(&spec.name),
-        )
-        .await?;
+        let mut parsed =
+            crate::generator::swagger_parser::fetch_and_parse_spec_with_cache_and_name(
+                spec_path,
+                use_cache,
+                Some(&spec.name),
+            )
+            .await?;
+        let collisions =
+            crate::specs::runner::prepare_parsed_spec(&mut parsed, spec, no_deprecated)?;
+
+        let default_server = crate::generator::servers::default_server_url(&parsed.openapi);
 
         // Get selected modules from config, or select interactively if empty
         let selected_modules = if modules_config.selected.is_empty() {
@@ -142,6 +159,9 @@ pub async fn run() -> Result<()> {
             "{}",
             format!("✅ Parsed spec with {} modules", parsed.modules.len()).green()
         );
+        for warning in crate::specs::runner::collision_warnings(&collisions, &selected_modules) {
+            println!("{}", format!("⚠️  {}", warning).yellow());
+        }
         println!();
         println!(
             "{}",
@@ -167,13 +187,22 @@ pub async fn run() -> Result<()> {
         let schemas_dir = PathBuf::from(&schemas_config.output);
         let apis_dir = PathBuf::from(&apis_config.output);
 
+        let resolver = crate::specs::runner::factories_resolver(spec, &parsed)?;
+        let output = SpecOutput {
+            config: &config,
+            spec,
+            parsed: &parsed,
+            module_schemas: &filtered_module_schemas,
+            common_schemas: &common_schemas,
+            default_server: default_server.as_deref(),
+            factories_resolver: resolver.as_ref(),
+            backup: use_backup,
+            force: use_force,
+        };
+
         let mut total_files = 0;
         let mut module_summary: Vec<(String, usize)> = Vec::new();
 
-        // Get force and backup settings from config
-        let use_force = config.generation.conflict_strategy == "force";
-        let use_backup = config.generation.enable_backup;
-
         // Generate common module first if there are shared schemas
         if !common_schemas.is_empty() {
             println!("{}", "🔨 Regenerating common schemas...".bright_cyan());
@@ -221,9 +250,14 @@ pub async fn run() -> Result<()> {
                 &common_schemas,
             )?;
             total_files += common_files.len();
+
+            total_files += write_module_json_schemas(&output, "common", &common_schemas)?;
             module_summary.push(("common".to_string(), common_files.len()));
         }
 
+        total_files += write_spec_files(&output)?;
+
+        let mut sdk_modules = Vec::new();
         for module in &selected_modules {
             println!(
                 "{}",
@@ -296,11 +330,11 @@ pub async fn run() -> Result<()> {
                 spec_name: Some(&spec.name),
                 existing_types: &types,
                 existing_zod_schemas: &zod_schemas,
+                include_path_params: apis_config.validate_requests != "off",
             })?;
 
             // Generate API client (using same enum registry as schemas)
-            let api_result =
-            crate::generator::api_client::generate_api_client_with_registry_and_engine_and_spec(
+            let api_result = crate::generator::api_client::generate_api_client_with_config(
                 &parsed.openapi,
                 &operations,
                 module,
@@ -311,6 +345,7 @@ pub async fn run() -> Result<()> {
                 Some(&config.root_dir),
                 Some(&apis_config.output),
                 Some(&schemas_config.output),
+                apis_config,
             )?;
 
             // Response types are written to API files, not schema files
@@ -337,6 +372,10 @@ pub async fn run() -> Result<()> {
             )?;
             total_files += schema_files.len();
 
+            let json_schema_count =
+                write_module_json_schemas(&output, module, &module_schema_names)?;
+            total_files += json_schema_count;
+
             // Write API client (use force if config says so)
             let api_files = write_api_client_with_options(
                 &apis_dir,
@@ -347,8 +386,10 @@ pub async fn run() -> Result<()> {
                 use_force,
             )?;
             total_files += api_files.len();
+            sdk_modules.push(module.clone());
 
-            // Determine hook type from hooks config
+            // Determine hook type from hooks config (Angular services return
+            // Observables, which React hooks cannot wrap)
             use crate::specs::runner::HookType;
             let hook_type = hooks_config
                 .library
@@ -357,7 +398,8 @@ pub async fn run() -> Result<()> {
                     "react-query" => Some(HookType::ReactQuery),
                     "swr" => Some(HookType::Swr),
                     _ => None,
-                });
+                })
+                .filter(|_| apis_config.style != "angular");
 
             // Generate hooks if configured
             let mut hook_files_count = 0;
@@ -369,7 +411,13 @@ pub async fn run() -> Result<()> {
 
                 // Generate query keys first (hooks depend on them)
                 use crate::generator::query_keys::generate_query_keys;
-                let query_keys_context = generate_query_keys(&operations, module, Some(&spec.name));
+                let query_keys_context = generate_query_keys(
+                    &operations,
+                    module,
+                    Some(&spec.name),
+                    Some(&hooks_config.query_keys_output),
+                    apis_config,
+                );
 
                 // Render query keys template
                 let query_keys_content = template_engine.render(
@@ -408,6 +456,7 @@ pub async fn run() -> Result<()> {
                             Some(&schemas_config.output),
                             Some(&hooks_config.output),
                             Some(&hooks_config.query_keys_output),
+                            apis_config,
                         )?
                     }
                     HookType::Swr => {
@@ -424,6 +473,7 @@ pub async fn run() -> Result<()> {
                             Some(&schemas_config.output),
                             Some(&hooks_config.output),
                             Some(&hooks_config.query_keys_output),
+                            apis_config,
                         )?
                     }
                 };
@@ -445,13 +495,20 @@ pub async fn run() -> Result<()> {
                 total_files += hook_files_count;
             }
 
+            // MSW handlers and test data factories, if configured
+            let test_helper_count =
+                write_module_test_helpers(&output, module, &operations, &module_schema_names)?;
+            total_files += test_helper_count;
+
             let module_file_count = schema_files.len()
+                + json_schema_count
                 + api_files.len()
                 + if hook_type.is_some() {
                     1 + hook_files_count
                 } else {
                     0
-                };
+                }
+                + test_helper_count;
             module_summary.push((module.clone(), module_file_count));
             println!(
                 "{}",
@@ -463,6 +520,9 @@ pub async fn run() -> Result<()> {
             );
         }
 
+        // The SDK class, MSW handlers index and shared factories cover the modules generated above
+        total_files += write_spec_aggregates(&output, &sdk_modules)?;
+
         println!();
         println!(
             "{}",
@@ -483,6 +543,12 @@ pub async fn run() -> Result<()> {
             println!("  📁 Hooks: {}", hooks_config.output);
             println!("  📁 Query Keys: {}", hooks_config.query_keys_output);
         }
+        if let Some(ref mocks_config) = spec.mocks {
+            println!("  📁 Mocks: {}", mocks_config.output);
+        }
+        if let Some(ref factories_config) = spec.factories {
+            println!("  📁 Factories: {}", factories_config.output);
+        }
 
         // Store summary for this spec
         all_specs_summary.push((spec.name.clone(), total_files, module_summary.clone()));
@@ -77,6 +77,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
         if save_config(&config).is_ok() {
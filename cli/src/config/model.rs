@@ -18,6 +18,14 @@ pub struct SpecEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hooks: Option<HooksConfig>,
 
+    /// Optional per-spec MSW mock handlers output configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mocks: Option<MocksConfig>,
+
+    /// Optional per-spec test data factories output configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factories: Option<FactoriesConfig>,
+
     /// Required per-spec module selection configuration
     pub modules: ModulesConfig,
 }
@@ -70,6 +78,10 @@ pub struct SchemasConfig {
 
     #[serde(default = "default_naming")]
     pub naming: String,
+
+    /// Also write a JSON Schema (draft 2020-12) file per component schema
+    #[serde(default)]
+    pub emit_json_schema: bool,
 }
 
 fn default_naming() -> String {
@@ -109,9 +121,139 @@ pub struct ApisConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_delay: Option<u32>,
 
+    /// Which requests are retried and how long to wait between attempts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<RetryPolicyConfig>,
+
+    /// Refresh credentials and replay requests rejected with 401.
+    /// Generates `configureTokenRefresh` in the spec's `vika-client.ts` (fetch style only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_refresh: Option<TokenRefreshConfig>,
+
+    /// Create a span per request and send W3C `traceparent` headers.
+    /// Uses the runtime's `createTracer()` until an OpenTelemetry tracer is registered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracing: Option<bool>,
+
+    /// `paginate{Op}` / `fetchAll{Op}` helpers for cursor, page and offset paginated operations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PaginationConfig>,
+
     /// Default headers to include in all requests
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<std::collections::HashMap<String, String>>,
+
+    /// Runtime response validation against generated Zod schemas
+    /// Options: "off", "dev-only" or "always"
+    #[serde(default = "default_validate_responses")]
+    pub validate_responses: String,
+
+    /// Validate request body, path and query params against generated Zod schemas
+    /// before sending. Options: "off", "dev-only" or "always"
+    #[serde(default = "default_validate_requests")]
+    pub validate_requests: String,
+
+    /// Name of the generated SDK class (e.g. "BillingClient") composing every module
+    /// of the spec. Not generated when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_class: Option<String>,
+
+    /// Parameter style of generated functions
+    /// Options: "positional" (`fn(id, body, query?)`) or "object" (`fn({ path, query, body, headers })`)
+    #[serde(default = "default_signature")]
+    pub signature: String,
+
+    /// How generated functions report failures
+    /// Options: "result" (return an `ApiResult`) or "throw" (return the success body and
+    /// throw an `ApiError` subclass per error status)
+    #[serde(default = "default_error_mode")]
+    pub error_mode: String,
+
+    /// Explicit function names for operations, keyed by `operationId` or "METHOD /path".
+    /// Types derived from the name follow (`{Name}Responses`, hooks, query keys, mocks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename: Option<std::collections::HashMap<String, String>>,
+}
+
+/// Retry policy of the runtime client. Unset fields use the runtime defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RetryPolicyConfig {
+    /// HTTP methods that are retried
+    /// Default: idempotent methods ("GET", "HEAD", "OPTIONS", "PUT", "DELETE")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<String>>,
+
+    /// Response statuses that are retried
+    /// Default: 408, 429, 500, 502, 503 and 504
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_codes: Option<Vec<u16>>,
+
+    /// Upper bound of the jittered exponential backoff in milliseconds (default 30000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay: Option<u32>,
+
+    /// Wait for the `Retry-After` response header when present (default true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub respect_retry_after: Option<bool>,
+
+    /// Header carrying the idempotency key of operations marked `x-idempotent`
+    /// Default: "Idempotency-Key"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_header: Option<String>,
+}
+
+/// Pagination detection of the api_client generator.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PaginationConfig {
+    /// Detect pagination from query parameter and response field names (default true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detect: Option<bool>,
+
+    /// Pagination of individual operations keyed by operationId.
+    /// Takes precedence over the `x-pagination` extension and detection.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub operations: std::collections::HashMap<String, PaginationRule>,
+}
+
+/// How an operation is paginated. Also the shape of the `x-pagination` extension.
+/// Unset fields are detected from the operation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PaginationRule {
+    /// "cursor", "page", "offset" or "none" (no helpers)
+    pub style: String,
+
+    /// Query parameter carrying the cursor, page number or offset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+
+    /// Query parameter carrying the page size
+    #[serde(alias = "limitParam", skip_serializing_if = "Option::is_none")]
+    pub limit_param: Option<String>,
+
+    /// Response field with the page items (dot-separated for nested fields)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<String>,
+
+    /// Response field with the next cursor ("cursor" style)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+
+    /// Response field with the total number of items
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<String>,
+
+    /// Response field with the number of pages ("page" style)
+    #[serde(alias = "totalPages", skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<String>,
+}
+
+/// Token refresh of the runtime client. The refresh itself is registered at runtime
+/// with `configureTokenRefresh`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TokenRefreshConfig {
+    /// Response statuses that trigger a refresh (default [401])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_codes: Option<Vec<u16>>,
 }
 
 fn default_header_strategy() -> String {
@@ -126,6 +268,22 @@ fn default_style() -> String {
     "fetch".to_string()
 }
 
+fn default_validate_responses() -> String {
+    "off".to_string()
+}
+
+fn default_validate_requests() -> String {
+    "off".to_string()
+}
+
+fn default_signature() -> String {
+    "positional".to_string()
+}
+
+fn default_error_mode() -> String {
+    "result".to_string()
+}
+
 /// Configuration for hooks generation (React Query, SWR, etc.).
 ///
 /// Controls where hooks and query keys are generated, and which hook library to use.
@@ -153,6 +311,34 @@ pub fn default_query_keys_output() -> String {
     "src/query-keys".to_string()
 }
 
+/// Configuration for MSW (Mock Service Worker) handler generation.
+///
+/// Handlers are generated per module, next to a `handlers.ts` combining all of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MocksConfig {
+    /// Output directory for mock handlers (e.g., "src/mocks")
+    #[serde(default = "default_mocks_output")]
+    pub output: String,
+}
+
+pub fn default_mocks_output() -> String {
+    "src/mocks".to_string()
+}
+
+/// Configuration for test data factory generation.
+///
+/// Factories are generated per module, next to the seeded `random.ts` helpers they share.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactoriesConfig {
+    /// Output directory for factories (e.g., "src/factories")
+    #[serde(default = "default_factories_output")]
+    pub output: String,
+}
+
+pub fn default_factories_output() -> String {
+    "src/factories".to_string()
+}
+
 /// Configuration for module selection and filtering.
 ///
 /// Controls which OpenAPI tags/modules are included or excluded from generation.
@@ -218,6 +404,7 @@ impl Default for SchemasConfig {
         Self {
             output: default_schemas_output(),
             naming: default_naming(),
+            emit_json_schema: false,
         }
     }
 }
@@ -232,7 +419,17 @@ impl Default for ApisConfig {
             timeout: None,
             retries: None,
             retry_delay: None,
+            retry_policy: None,
+            token_refresh: None,
+            tracing: None,
+            pagination: None,
             headers: None,
+            validate_responses: default_validate_responses(),
+            validate_requests: default_validate_requests(),
+            client_class: None,
+            signature: default_signature(),
+            error_mode: default_error_mode(),
+            rename: None,
         }
     }
 }
@@ -247,6 +444,22 @@ impl Default for HooksConfig {
     }
 }
 
+impl Default for MocksConfig {
+    fn default() -> Self {
+        Self {
+            output: default_mocks_output(),
+        }
+    }
+}
+
+impl Default for FactoriesConfig {
+    fn default() -> Self {
+        Self {
+            output: default_factories_output(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,6 +524,7 @@ mod tests {
         let config = SchemasConfig::default();
         assert_eq!(config.output, "src/schemas");
         assert_eq!(config.naming, "PascalCase");
+        assert!(!config.emit_json_schema);
     }
 
     #[test]
@@ -320,6 +534,15 @@ mod tests {
         assert_eq!(config.style, "fetch");
         assert_eq!(config.header_strategy, "consumerInjected");
         assert!(config.base_url.is_none());
+        assert_eq!(config.validate_responses, "off");
+        assert_eq!(config.validate_requests, "off");
+    }
+
+    #[test]
+    fn test_apis_config_validate_responses_deserialization() {
+        let json = r#"{ "output": "src/apis", "validate_responses": "dev-only" }"#;
+        let config: ApisConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.validate_responses, "dev-only");
     }
 
     #[test]
@@ -334,6 +557,8 @@ mod tests {
                 ..ApisConfig::default()
             },
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: ModulesConfig::default(),
         });
 
@@ -441,6 +666,8 @@ mod tests {
             schemas: SchemasConfig::default(),
             apis: ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: ModulesConfig::default(),
         };
         let json = serde_json::to_string(&entry).unwrap();
@@ -1,7 +1,11 @@
 use crate::config::model::Config;
 use crate::error::{ConfigError, Result};
+use crate::generator::symbols::{is_reserved_word, is_valid_identifier};
 use std::path::{Path, PathBuf};
 
+/// API client styles accepted in `apis.style`.
+pub const SUPPORTED_API_STYLES: &[&str] = &["fetch", "axios", "angular"];
+
 pub fn validate_config(config: &Config) -> Result<()> {
     // Validate that at least one spec is defined
     if config.specs.is_empty() {
@@ -60,16 +64,267 @@ pub fn validate_config(config: &Config) -> Result<()> {
             validate_safe_path(&apis_output)?;
         }
 
+        // Validate per-spec mocks output path
+        if let Some(ref mocks) = spec.mocks {
+            let mocks_output = PathBuf::from(&mocks.output);
+            if mocks_output.is_absolute() {
+                validate_safe_path(&mocks_output)?;
+            }
+        }
+
+        // Validate per-spec factories output path
+        if let Some(ref factories) = spec.factories {
+            let factories_output = PathBuf::from(&factories.output);
+            if factories_output.is_absolute() {
+                validate_safe_path(&factories_output)?;
+            }
+        }
+
         // Validate per-spec API style
-        if spec.apis.style != "fetch" {
+        if !SUPPORTED_API_STYLES.contains(&spec.apis.style.as_str()) {
+            return Err(ConfigError::Invalid {
+                message: format!(
+                    "Unsupported API style for spec '{}': {}. Supported styles: {}.",
+                    spec.name,
+                    spec.apis.style,
+                    SUPPORTED_API_STYLES
+                        .iter()
+                        .map(|style| format!("'{}'", style))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+            .into());
+        }
+
+        // Validate per-spec request/response validation modes
+        for (field, mode) in [
+            ("validate_responses", &spec.apis.validate_responses),
+            ("validate_requests", &spec.apis.validate_requests),
+        ] {
+            if !["off", "dev-only", "always"].contains(&mode.as_str()) {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "Invalid {} for spec '{}': {}. Expected 'off', 'dev-only' or 'always'.",
+                        field, spec.name, mode
+                    ),
+                }
+                .into());
+            }
+            // Angular services return Observables from HttpClient, not the runtime's ApiResult
+            if spec.apis.style == "angular" && mode != "off" {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "{} is not supported with the 'angular' API style (spec '{}').",
+                        field, spec.name
+                    ),
+                }
+                .into());
+            }
+        }
+
+        // Validate per-spec function signature style
+        if !["positional", "object"].contains(&spec.apis.signature.as_str()) {
+            return Err(ConfigError::Invalid {
+                message: format!(
+                    "Invalid signature for spec '{}': {}. Expected 'positional' or 'object'.",
+                    spec.name, spec.apis.signature
+                ),
+            }
+            .into());
+        }
+        if spec.apis.style == "angular" && spec.apis.signature != "positional" {
+            return Err(ConfigError::Invalid {
+                message: format!(
+                    "signature '{}' is not supported with the 'angular' API style (spec '{}').",
+                    spec.apis.signature, spec.name
+                ),
+            }
+            .into());
+        }
+
+        // Validate per-spec error mode
+        if !["result", "throw"].contains(&spec.apis.error_mode.as_str()) {
+            return Err(ConfigError::Invalid {
+                message: format!(
+                    "Invalid error_mode for spec '{}': {}. Expected 'result' or 'throw'.",
+                    spec.name, spec.apis.error_mode
+                ),
+            }
+            .into());
+        }
+        if spec.apis.style == "angular" && spec.apis.error_mode != "result" {
             return Err(ConfigError::Invalid {
                 message: format!(
-                    "Unsupported API style for spec '{}': {}. Only 'fetch' is supported.",
+                    "error_mode '{}' is not supported with the 'angular' API style (spec '{}').",
+                    spec.apis.error_mode, spec.name
+                ),
+            }
+            .into());
+        }
+
+        // Retries are implemented by the fetch runtime client only
+        for (field, set) in [
+            ("retries", spec.apis.retries.is_some()),
+            ("retry_delay", spec.apis.retry_delay.is_some()),
+            ("retry_policy", spec.apis.retry_policy.is_some()),
+        ] {
+            if set && spec.apis.style != "fetch" {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "{} requires the 'fetch' API style (spec '{}' uses '{}'). Use Axios or Angular interceptors instead.",
+                        field, spec.name, spec.apis.style
+                    ),
+                }
+                .into());
+            }
+        }
+
+        // Validate the runtime retry policy
+        if let Some(ref policy) = spec.apis.retry_policy {
+            const HTTP_METHODS: [&str; 7] =
+                ["GET", "HEAD", "OPTIONS", "PUT", "DELETE", "POST", "PATCH"];
+            for method in policy.methods.iter().flatten() {
+                if !HTTP_METHODS.contains(&method.to_uppercase().as_str()) {
+                    return Err(ConfigError::Invalid {
+                        message: format!(
+                            "Invalid retry_policy.methods entry for spec '{}': {}. Expected an HTTP method like 'GET'.",
+                            spec.name, method
+                        ),
+                    }
+                    .into());
+                }
+            }
+            for status in policy.status_codes.iter().flatten() {
+                if !(100..=599).contains(status) {
+                    return Err(ConfigError::Invalid {
+                        message: format!(
+                            "Invalid retry_policy.status_codes entry for spec '{}': {}. Expected an HTTP status between 100 and 599.",
+                            spec.name, status
+                        ),
+                    }
+                    .into());
+                }
+            }
+            if policy
+                .idempotency_header
+                .as_ref()
+                .is_some_and(|header| header.trim().is_empty())
+            {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "retry_policy.idempotency_header cannot be empty (spec '{}').",
+                        spec.name
+                    ),
+                }
+                .into());
+            }
+        }
+
+        // Validate the token refresh statuses
+        if let Some(ref token_refresh) = spec.apis.token_refresh {
+            // Refresh and replay are implemented by the fetch runtime client only
+            if spec.apis.style != "fetch" {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "token_refresh requires the 'fetch' API style (spec '{}' uses '{}'). Use Axios or Angular interceptors instead.",
+                        spec.name, spec.apis.style
+                    ),
+                }
+                .into());
+            }
+            for status in token_refresh.status_codes.iter().flatten() {
+                if !(100..=599).contains(status) {
+                    return Err(ConfigError::Invalid {
+                        message: format!(
+                            "Invalid token_refresh.status_codes entry for spec '{}': {}. Expected an HTTP status between 100 and 599.",
+                            spec.name, status
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        // Tracing is implemented by the fetch runtime client only
+        if spec.apis.tracing == Some(true) && spec.apis.style != "fetch" {
+            return Err(ConfigError::Invalid {
+                message: format!(
+                    "tracing requires the 'fetch' API style (spec '{}' uses '{}'). Use Axios or Angular interceptors instead.",
                     spec.name, spec.apis.style
                 ),
             }
             .into());
         }
+
+        // Validate the pagination overrides
+        if let Some(ref pagination) = spec.apis.pagination {
+            for (operation_id, rule) in &pagination.operations {
+                if !["cursor", "page", "offset", "none"].contains(&rule.style.as_str()) {
+                    return Err(ConfigError::Invalid {
+                        message: format!(
+                            "Invalid pagination style for operation '{}' in spec '{}': {}. Must be one of: cursor, page, offset, none",
+                            operation_id, spec.name, rule.style
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        // Validate operation renames (they become function declarations)
+        for (operation, name) in spec.apis.rename.iter().flatten() {
+            if !is_valid_identifier(name) {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "Invalid rename for operation '{}' in spec '{}': {}. Expected a TypeScript function name like 'listPets'.",
+                        operation, spec.name, name
+                    ),
+                }
+                .into());
+            }
+            if is_reserved_word(name) {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "Invalid rename for operation '{}' in spec '{}': '{}' is a reserved word in TypeScript and can't name a function.",
+                        operation, spec.name, name
+                    ),
+                }
+                .into());
+            }
+        }
+
+        // Validate the SDK class name (it becomes a TypeScript class declaration)
+        if let Some(ref class_name) = spec.apis.client_class {
+            if !is_valid_identifier(class_name) {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "Invalid client_class for spec '{}': {}. Expected a TypeScript class name like 'BillingClient'.",
+                        spec.name, class_name
+                    ),
+                }
+                .into());
+            }
+            if is_reserved_word(class_name) {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "Invalid client_class for spec '{}': '{}' is a reserved word in TypeScript and can't name a class.",
+                        spec.name, class_name
+                    ),
+                }
+                .into());
+            }
+            // The SDK class owns a VikaClient, which only the fetch style calls through
+            if spec.apis.style != "fetch" {
+                return Err(ConfigError::Invalid {
+                    message: format!(
+                        "client_class is only supported with the 'fetch' API style (spec '{}').",
+                        spec.name
+                    ),
+                }
+                .into());
+            }
+        }
     }
 
     // Validate root_dir
@@ -109,7 +364,28 @@ fn validate_safe_path(path: &Path) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::model::Config;
+    use crate::config::model::{
+        ApisConfig, Config, PaginationConfig, PaginationRule, RetryPolicyConfig, SpecEntry,
+        TokenRefreshConfig,
+    };
+    use std::collections::HashMap;
+
+    /// Config with a single `test` spec using `apis`.
+    fn spec_with(apis: ApisConfig) -> Config {
+        Config {
+            specs: vec![SpecEntry {
+                name: "test".to_string(),
+                path: "test.yaml".to_string(),
+                schemas: crate::config::model::SchemasConfig::default(),
+                apis,
+                hooks: None,
+                mocks: None,
+                factories: None,
+                modules: crate::config::model::ModulesConfig::default(),
+            }],
+            ..Default::default()
+        }
+    }
 
     #[test]
     fn test_validate_config_valid() {
@@ -120,6 +396,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
         assert!(validate_config(&config).is_ok());
@@ -138,6 +416,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis,
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             }],
             ..Default::default()
@@ -149,6 +429,283 @@ mod tests {
         assert!(error.to_string().contains("Unsupported API style"));
     }
 
+    #[test]
+    fn test_validate_config_axios_style() {
+        let apis = ApisConfig {
+            style: "axios".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(apis)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_config_angular_rejects_validation() {
+        let apis = ApisConfig {
+            style: "angular".to_string(),
+            validate_requests: "always".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(apis))
+            .unwrap_err()
+            .to_string()
+            .contains("validate_requests is not supported with the 'angular' API style"));
+    }
+
+    #[test]
+    fn test_validate_config_retry_policy() {
+        let retry_policy = |policy| ApisConfig {
+            retry_policy: Some(policy),
+            ..Default::default()
+        };
+
+        let valid = RetryPolicyConfig {
+            methods: Some(vec!["get".to_string(), "PUT".to_string()]),
+            status_codes: Some(vec![429, 503]),
+            max_delay: Some(10_000),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(retry_policy(valid))).is_ok());
+
+        let invalid_method = RetryPolicyConfig {
+            methods: Some(vec!["FETCH".to_string()]),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(retry_policy(invalid_method)))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid retry_policy.methods entry for spec 'test': FETCH"));
+
+        let invalid_status = RetryPolicyConfig {
+            status_codes: Some(vec![42]),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(retry_policy(invalid_status)))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid retry_policy.status_codes entry for spec 'test': 42"));
+
+        let axios_policy = ApisConfig {
+            style: "axios".to_string(),
+            retry_policy: Some(RetryPolicyConfig::default()),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(axios_policy))
+            .unwrap_err()
+            .to_string()
+            .contains("retry_policy requires the 'fetch' API style (spec 'test' uses 'axios')"));
+
+        let angular_retries = ApisConfig {
+            style: "angular".to_string(),
+            retries: Some(3),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(angular_retries))
+            .unwrap_err()
+            .to_string()
+            .contains("retries requires the 'fetch' API style (spec 'test' uses 'angular')"));
+    }
+
+    #[test]
+    fn test_validate_config_token_refresh() {
+        let token_refresh = |style: &str, status_codes| ApisConfig {
+            style: style.to_string(),
+            token_refresh: Some(TokenRefreshConfig { status_codes }),
+            ..Default::default()
+        };
+
+        assert!(validate_config(&spec_with(token_refresh("fetch", Some(vec![401, 419])))).is_ok());
+        assert!(
+            validate_config(&spec_with(token_refresh("fetch", Some(vec![1000]))))
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid token_refresh.status_codes entry for spec 'test': 1000")
+        );
+        assert!(validate_config(&spec_with(token_refresh("axios", None)))
+            .unwrap_err()
+            .to_string()
+            .contains("token_refresh requires the 'fetch' API style (spec 'test' uses 'axios')"));
+        assert!(validate_config(&spec_with(token_refresh("angular", None)))
+            .unwrap_err()
+            .to_string()
+            .contains("token_refresh requires the 'fetch' API style (spec 'test' uses 'angular')"));
+    }
+
+    #[test]
+    fn test_validate_config_tracing() {
+        let tracing = |style: &str| ApisConfig {
+            style: style.to_string(),
+            tracing: Some(true),
+            ..Default::default()
+        };
+
+        assert!(validate_config(&spec_with(tracing("fetch"))).is_ok());
+        assert!(validate_config(&spec_with(tracing("axios")))
+            .unwrap_err()
+            .to_string()
+            .contains("tracing requires the 'fetch' API style (spec 'test' uses 'axios')"));
+    }
+
+    #[test]
+    fn test_validate_config_pagination() {
+        let pagination = |style: &str| ApisConfig {
+            pagination: Some(PaginationConfig {
+                detect: None,
+                operations: HashMap::from([(
+                    "listOrders".to_string(),
+                    PaginationRule {
+                        style: style.to_string(),
+                        ..Default::default()
+                    },
+                )]),
+            }),
+            ..Default::default()
+        };
+
+        assert!(validate_config(&spec_with(pagination("cursor"))).is_ok());
+        assert!(validate_config(&spec_with(pagination("none"))).is_ok());
+        assert!(validate_config(&spec_with(pagination("keyset")))
+            .unwrap_err()
+            .to_string()
+            .contains(
+                "Invalid pagination style for operation 'listOrders' in spec 'test': keyset"
+            ));
+    }
+
+    #[test]
+    fn test_validate_config_client_class() {
+        let valid = ApisConfig {
+            client_class: Some("BillingClient".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(valid)).is_ok());
+
+        let invalid_name = ApisConfig {
+            client_class: Some("billing-client".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(invalid_name))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid client_class for spec 'test'"));
+
+        let reserved = ApisConfig {
+            client_class: Some("default".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(reserved))
+            .unwrap_err()
+            .to_string()
+            .contains("'default' is a reserved word in TypeScript"));
+
+        let axios = ApisConfig {
+            style: "axios".to_string(),
+            client_class: Some("BillingClient".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(axios))
+            .unwrap_err()
+            .to_string()
+            .contains("client_class is only supported with the 'fetch' API style"));
+    }
+
+    #[test]
+    fn test_validate_config_signature() {
+        let object = ApisConfig {
+            signature: "object".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(object)).is_ok());
+
+        let named = ApisConfig {
+            signature: "named".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(named))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid signature for spec 'test': named"));
+
+        let angular = ApisConfig {
+            style: "angular".to_string(),
+            signature: "object".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(angular))
+            .unwrap_err()
+            .to_string()
+            .contains("signature 'object' is not supported with the 'angular' API style"));
+    }
+
+    #[test]
+    fn test_validate_config_error_mode() {
+        let throw = ApisConfig {
+            error_mode: "throw".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(throw)).is_ok());
+
+        let panic = ApisConfig {
+            error_mode: "panic".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(panic))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid error_mode for spec 'test': panic"));
+
+        let angular = ApisConfig {
+            style: "angular".to_string(),
+            error_mode: "throw".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(angular))
+            .unwrap_err()
+            .to_string()
+            .contains("error_mode 'throw' is not supported with the 'angular' API style"));
+    }
+
+    #[test]
+    fn test_validate_config_rename() {
+        let rename = |name: &str| ApisConfig {
+            rename: Some(HashMap::from([("GET /pets".to_string(), name.to_string())])),
+            ..Default::default()
+        };
+
+        assert!(validate_config(&spec_with(rename("listAllPets"))).is_ok());
+        assert!(validate_config(&spec_with(rename("list-pets")))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid rename for operation 'GET /pets' in spec 'test': list-pets"));
+        assert!(validate_config(&spec_with(rename("delete")))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid rename for operation 'GET /pets' in spec 'test': 'delete' is a reserved word"));
+    }
+
+    #[test]
+    fn test_validate_config_invalid_validate_responses() {
+        let apis = ApisConfig {
+            validate_responses: "sometimes".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(apis))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid validate_responses"));
+    }
+
+    #[test]
+    fn test_validate_config_invalid_validate_requests() {
+        let apis = ApisConfig {
+            validate_requests: "never".to_string(),
+            ..Default::default()
+        };
+        assert!(validate_config(&spec_with(apis))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid validate_requests"));
+    }
+
     #[test]
     fn test_validate_safe_path_etc() {
         let path = PathBuf::from("/etc/test");
@@ -201,6 +758,8 @@ mod tests {
                 schemas,
                 apis,
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             }],
             ..Default::default()
@@ -223,6 +782,8 @@ mod tests {
                 schemas,
                 apis: crate::config::model::ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             }],
             ..Default::default()
@@ -245,6 +806,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis,
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             }],
             ..Default::default()
@@ -287,6 +850,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis: crate::config::model::ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             },
             crate::config::model::SpecEntry {
@@ -295,6 +860,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis: crate::config::model::ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             },
         ];
@@ -314,6 +881,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
 
@@ -332,6 +901,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
 
@@ -350,6 +921,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
 
@@ -369,6 +942,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis: crate::config::model::ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             },
             crate::config::model::SpecEntry {
@@ -377,6 +952,8 @@ mod tests {
                 schemas: crate::config::model::SchemasConfig::default(),
                 apis: crate::config::model::ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: crate::config::model::ModulesConfig::default(),
             },
         ];
@@ -394,6 +971,8 @@ mod tests {
             schemas: crate::config::model::SchemasConfig::default(),
             apis: crate::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: crate::config::model::ModulesConfig::default(),
         }];
 
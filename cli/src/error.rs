@@ -119,6 +119,13 @@ pub enum NetworkError {
 
     #[error("Invalid URL: {url}")]
     InvalidUrl { url: String },
+
+    #[error("Failed to listen on {address}: {source}")]
+    BindFailed {
+        address: String,
+        #[source]
+        source: std::io::Error,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -1,18 +1,32 @@
+use crate::config::model::ApisConfig;
 use crate::error::Result;
+use crate::generator::deprecation::{operation_deprecation, parameter_deprecation};
+use crate::generator::pagination::{
+    detect_pagination, generate_pagination_helpers, PaginatedFunction,
+};
+use crate::generator::security::{
+    collect_security_schemes, operation_security, requirements_literal,
+};
+use crate::generator::servers::operation_server_url;
+use crate::generator::spec_client::spec_client_import_path;
+use crate::generator::streaming::{detect_stream, StreamFormat};
 use crate::generator::swagger_parser::resolve_ref;
 use crate::generator::swagger_parser::{
     get_schema_name_from_ref, resolve_parameter_ref, resolve_request_body_ref,
     resolve_response_ref, OperationInfo,
 };
 use crate::generator::ts_typings::TypeScriptType;
-use crate::generator::utils::{sanitize_module_name, to_camel_case, to_pascal_case};
+use crate::generator::utils::{
+    parameter_identifier, property_access, sanitize_module_name, sanitize_property_name,
+    string_literal, to_camel_case, to_pascal_case,
+};
 use crate::templates::context::{
     ApiContext, Parameter as ApiParameter, RequestBody, Response as ApiResponse,
 };
 use crate::templates::engine::TemplateEngine;
 use crate::templates::registry::TemplateId;
 use openapiv3::OpenAPI;
-use openapiv3::{Operation, Parameter, ReferenceOr, SchemaKind, Type};
+use openapiv3::{Operation, Parameter, ReferenceOr, Schema, SchemaKind, Type};
 
 /// Find the common prefix of two paths
 fn find_common_prefix(path1: &str, path2: &str) -> String {
@@ -53,6 +67,8 @@ pub struct ParameterInfo {
     pub style: Option<String>,
     pub explode: Option<bool>,
     pub description: Option<String>,
+    /// `@deprecated` JSDoc tag when the parameter is deprecated
+    pub deprecated: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -67,11 +83,62 @@ pub enum ParameterType {
 
 #[derive(Clone, Debug)]
 pub struct ResponseInfo {
+    /// Declared status; the first status of a range (`4XX` -> 400), 0 for `default`
     pub status_code: u16,
+    /// Declared as a status range (`2XX`, `4XX`, ...)
+    pub range: bool,
     pub body_type: String,
+    /// JSON body schema as declared, used to build the runtime validator
+    pub body_schema: Option<ReferenceOr<Schema>>,
+    pub description: Option<String>,
+    /// Headers declared by the response
+    pub headers: Vec<ResponseHeader>,
+}
+
+/// Header declared in `responses.*.headers`.
+#[derive(Clone, Debug)]
+pub struct ResponseHeader {
+    pub name: String,
+    /// `string`, `number`, `integer` or `boolean`, used by the runtime to coerce the value
+    pub kind: &'static str,
+    pub ts_type: String,
+    pub required: bool,
     pub description: Option<String>,
 }
 
+impl ResponseInfo {
+    /// Key of the response in the `{Op}Responses`/`{Op}Errors` maps: `404`, `"4XX"` or `default`.
+    pub fn map_key(&self) -> String {
+        if self.status_code == 0 {
+            "default".to_string()
+        } else if self.range {
+            format!("\"{}XX\"", self.status_code / 100)
+        } else {
+            self.status_code.to_string()
+        }
+    }
+
+    /// Suffix of the response's generated names (`GetUser404Error`, `GetUser4XXError`,
+    /// `GetUserDefaultError`).
+    pub fn name_suffix(&self) -> String {
+        if self.status_code == 0 {
+            "Default".to_string()
+        } else {
+            self.map_key().trim_matches('"').to_string()
+        }
+    }
+}
+
+/// Primary success response: the first exact 2xx status with a named body (e.g. the
+/// 201 of a create-only endpoint), falling back to a `2XX` range.
+pub fn primary_success_response(success_responses: &[ResponseInfo]) -> Option<&ResponseInfo> {
+    let typed = || success_responses.iter().filter(|r| r.body_type != "any");
+    typed()
+        .filter(|r| !r.range)
+        .min_by_key(|r| r.status_code)
+        .or_else(|| typed().find(|r| r.range))
+}
+
 #[derive(Clone, Debug)]
 pub struct ErrorResponse {
     pub status_code: u16,
@@ -144,9 +211,42 @@ pub fn generate_api_client_with_registry_and_engine_and_spec(
     root_dir: Option<&str>,
     apis_dir: Option<&str>,
     schemas_dir: Option<&str>,
+) -> Result<ApiGenerationResult> {
+    generate_api_client_with_config(
+        openapi,
+        operations,
+        module_name,
+        common_schemas,
+        enum_registry,
+        template_engine,
+        spec_name,
+        root_dir,
+        apis_dir,
+        schemas_dir,
+        &ApisConfig::default(),
+    )
+}
+
+/// Generate API client functions honoring the per-spec `apis` configuration
+/// (e.g. response validation mode).
+#[allow(clippy::too_many_arguments)]
+pub fn generate_api_client_with_config(
+    openapi: &OpenAPI,
+    operations: &[OperationInfo],
+    module_name: &str,
+    common_schemas: &[String],
+    enum_registry: &mut std::collections::HashMap<String, String>,
+    template_engine: Option<&TemplateEngine>,
+    spec_name: Option<&str>,
+    root_dir: Option<&str>,
+    apis_dir: Option<&str>,
+    schemas_dir: Option<&str>,
+    apis_config: &ApisConfig,
 ) -> Result<ApiGenerationResult> {
     let mut functions = Vec::new();
     let mut response_types = Vec::new();
+    let mut service_methods = Vec::new();
+    let mut sdk_methods = Vec::new();
 
     for op_info in operations {
         let result = generate_function_for_operation(
@@ -160,9 +260,27 @@ pub fn generate_api_client_with_registry_and_engine_and_spec(
             root_dir,
             apis_dir,
             schemas_dir,
+            apis_config,
         )?;
         functions.push(result.function);
+        functions.extend(result.error_classes);
+        functions.extend(result.pagination_helpers);
         response_types.extend(result.response_types);
+        service_methods.extend(result.service_method);
+        sdk_methods.extend(result.sdk_method);
+    }
+
+    if !service_methods.is_empty() {
+        functions.push(generate_angular_service(module_name, &service_methods));
+    }
+    if !sdk_methods.is_empty() {
+        let module_depth = module_name.matches('/').count() + 1;
+        let runtime_import = runtime_import_path(root_dir, apis_dir, module_depth);
+        functions.push(generate_sdk_module_factory(
+            module_name,
+            &sdk_methods,
+            &runtime_import,
+        ));
     }
 
     Ok(ApiGenerationResult {
@@ -174,6 +292,14 @@ pub fn generate_api_client_with_registry_and_engine_and_spec(
 struct FunctionGenerationResult {
     function: ApiFunction,
     response_types: Vec<TypeScriptType>,
+    /// Service method for the `angular` style (the function entry then only carries types)
+    service_method: Option<String>,
+    /// Method of the module's SDK factory, when `apis.client_class` is set
+    sdk_method: Option<String>,
+    /// `{Op}{Status}Error` classes of an operation in the `"throw"` error mode
+    error_classes: Vec<ApiFunction>,
+    /// `paginate{Op}` and `fetchAll{Op}` helpers of a paginated operation
+    pagination_helpers: Vec<ApiFunction>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -188,16 +314,12 @@ fn generate_function_for_operation(
     root_dir: Option<&str>,
     apis_dir: Option<&str>,
     schemas_dir: Option<&str>,
+    apis_config: &ApisConfig,
 ) -> Result<FunctionGenerationResult> {
     let operation = &op_info.operation;
     let method = op_info.method.to_lowercase();
 
-    // Generate function name from operation ID or path
-    let func_name = if let Some(operation_id) = &operation.operation_id {
-        to_camel_case(operation_id)
-    } else {
-        generate_function_name_from_path(&op_info.path, &op_info.method)
-    };
+    let func_name = op_info.function_name.clone();
 
     // Extract path parameters
     let path_params = extract_path_parameters(openapi, operation, enum_registry)?;
@@ -224,9 +346,7 @@ fn generate_function_for_operation(
         .collect();
 
     // Get primary success response type (for backward compatibility)
-    let response_type = success_responses
-        .iter()
-        .find(|r| r.status_code == 200)
+    let response_type = primary_success_response(&success_responses)
         .map(|r| r.body_type.clone())
         .unwrap_or_else(|| "any".to_string());
 
@@ -238,6 +358,10 @@ fn generate_function_for_operation(
     let mut params = Vec::new();
     let mut path_template = op_info.path.clone();
     let mut enum_types = Vec::new();
+    // Fields of the `{Operation}Params` object used by the "object" signature
+    let mut path_fields = Vec::new();
+    let mut object_fields = Vec::new();
+    let mut object_names = Vec::new();
 
     // Add path parameters
     for param in &path_params {
@@ -255,13 +379,24 @@ fn generate_function_for_operation(
             ParameterType::Boolean => "boolean".to_string(),
             ParameterType::Array(_) => "string".to_string(), // Arrays in path are serialized as strings
         };
-        params.push(format!("{}: {}", param.name, param_type));
+        path_fields.push(format!(
+            "{}: {}",
+            sanitize_property_name(&param.name),
+            param_type
+        ));
+        let identifier = parameter_identifier(&param.name);
+        params.push(format!("{}: {}", identifier, param_type));
         path_template = path_template.replace(
             &format!("{{{}}}", param.name),
-            &format!("${{{}}}", param.name),
+            &format!("${{{}}}", identifier),
         );
     }
 
+    if !path_fields.is_empty() {
+        object_fields.push(format!("path: {{ {} }}", path_fields.join("; ")));
+        object_names.push("path");
+    }
+
     // Add request body (check if it's in common schemas)
     if let Some((body_type, _)) = &request_body_info {
         // Don't qualify "any" type with namespace
@@ -275,6 +410,8 @@ fn generate_function_for_operation(
             };
             params.push(format!("body: {}", qualified_body_type));
         }
+        object_fields.push(params[params.len() - 1].clone());
+        object_names.push("body");
     }
 
     // Add query parameters (optional) AFTER any required parameters like body,
@@ -286,19 +423,86 @@ fn generate_function_for_operation(
 
         // Reference query params type from schemas (namespace-qualified)
         params.push(format!("query?: {}.{}", namespace_name, query_type_name));
+        object_fields.push(params[params.len() - 1].clone());
+        object_names.push("query");
+    }
+
+    // Object signature: a single `{Operation}Params` argument replaces the positional
+    // params, so adding or reordering path params does not break callers
+    let object_signature = apis_config.signature == "object";
+    let header_params = if object_signature {
+        extract_header_parameters(openapi, operation, enum_registry)?
+    } else {
+        Vec::new()
+    };
+    if !header_params.is_empty() {
+        let fields: Vec<String> = header_params
+            .iter()
+            .map(|p| {
+                format!(
+                    "{}?: {}",
+                    sanitize_property_name(&p.name),
+                    header_param_type(&p.param_type)
+                )
+            })
+            .collect();
+        object_fields.push(format!("headers?: {{ {} }}", fields.join("; ")));
+        object_names.push("headers");
+    }
+    let params_type_name = format!("{}Params", to_pascal_case(&func_name));
+    if object_signature && !object_fields.is_empty() {
+        // Without path params or a body every field is optional, so the argument is too
+        let default_value = if path_fields.is_empty() && request_body_info.is_none() {
+            " = {}"
+        } else {
+            ""
+        };
+        params = vec![format!(
+            "{{ {} }}: {}{}",
+            object_names.join(", "),
+            params_type_name,
+            default_value
+        )];
+    } else if object_signature {
+        params.clear();
+    }
+
+    // Axios style forwards per-call Axios config (cancellation, upload progress, ...)
+    let is_axios = apis_config.style == "axios";
+    let is_angular = apis_config.style == "angular";
+    // `text/event-stream` and NDJSON operations stream their events (fetch style only)
+    let stream = if is_axios || is_angular {
+        None
+    } else {
+        detect_stream(openapi, operation)
+    };
+    // `error_mode: "throw"` unwraps results, throwing an `ApiError` subclass per error status
+    let throws = apis_config.error_mode == "throw" && stream.is_none() && !is_angular;
+    let type_name_base = to_pascal_case(&func_name);
+    if is_axios {
+        params.push("config?: AxiosRequestConfig".to_string());
+    } else if stream.is_some() {
+        params.push("options?: StreamOptions".to_string());
+    } else if !is_angular {
+        // Per-call signal, headers, timeout and base URL forwarded to the runtime
+        params.push("options?: CallOptions".to_string());
     }
 
     let params_str = params.join(", ");
 
     // Build function body
     let mut body_lines = Vec::new();
+    if object_signature && !path_params.is_empty() {
+        let bindings: Vec<String> = path_params.iter().map(path_param_binding).collect();
+        body_lines.push(format!("    const {{ {} }} = path;", bindings.join(", ")));
+    }
 
     // Build URL with path parameters
     let mut url_template = op_info.path.clone();
     for param in &path_params {
         url_template = url_template.replace(
             &format!("{{{}}}", param.name),
-            &format!("${{{}}}", param.name),
+            &format!("${{{}}}", parameter_identifier(&param.name)),
         );
     }
 
@@ -306,27 +510,29 @@ fn generate_function_for_operation(
     if !query_params.is_empty() {
         body_lines.push("    const queryString = new URLSearchParams();".to_string());
         for param in &query_params {
+            let value = property_access("query", &param.name, false);
+            let optional_value = property_access("query", &param.name, true);
             if param.is_array {
                 let explode = param.explode.unwrap_or(true);
                 if explode {
                     // explode: true -> tags=one&tags=two
-                    body_lines.push(format!("    if (query?.{}) {{", param.name));
+                    body_lines.push(format!("    if ({}) {{", optional_value));
                     body_lines.push(format!(
-                        "      query.{}.forEach((item) => queryString.append(\"{}\", String(item)));",
-                        param.name, param.name
+                        "      {}.forEach((item) => queryString.append(\"{}\", String(item)));",
+                        value, param.name
                     ));
                     body_lines.push("    }".to_string());
                 } else {
                     // explode: false -> tags=one,two
                     body_lines.push(format!(
-                        "    if (query?.{}) queryString.append(\"{}\", query.{}.join(\",\"));",
-                        param.name, param.name, param.name
+                        "    if ({}) queryString.append(\"{}\", {}.join(\",\"));",
+                        optional_value, param.name, value
                     ));
                 }
             } else {
                 body_lines.push(format!(
-                    "    if (query?.{}) queryString.append(\"{}\", String(query.{}));",
-                    param.name, param.name, param.name
+                    "    if ({}) queryString.append(\"{}\", String({}));",
+                    optional_value, param.name, value
                 ));
             }
         }
@@ -339,6 +545,18 @@ fn generate_function_for_operation(
         body_lines.push(format!("    const url = `{}`;", url_template));
     }
 
+    // Header params are only part of the object signature
+    if !header_params.is_empty() {
+        body_lines.push("    const requestHeaders: Record<string, string> = {};".to_string());
+        for param in &header_params {
+            let accessor = format!("headers?.[\"{}\"]", param.name);
+            body_lines.push(format!(
+                "    if ({} !== undefined) requestHeaders[\"{}\"] = String({});",
+                accessor, param.name, accessor
+            ));
+        }
+    }
+
     // Build HTTP call using VikaClient
     let http_method = match method.to_uppercase().as_str() {
         "GET" => "get",
@@ -352,51 +570,343 @@ fn generate_function_for_operation(
     };
 
     // Generate type names for success and error maps
-    let type_name_base = to_pascal_case(&func_name);
     let success_map_type = format!("{}Responses", type_name_base);
     let error_map_type = format!("{}Errors", type_name_base);
 
+    // Validate path, query and body against generated Zod schemas before sending
+    let mut request_validation_entries = Vec::new();
+    let mut request_validation_values = Vec::new();
+    let mut validator_refs = ValidatorRefs::default();
+    if apis_config.validate_requests != "off" {
+        if !path_params.is_empty() {
+            if object_signature {
+                request_validation_values.push("path".to_string());
+            } else {
+                let bindings: Vec<String> = path_params.iter().map(path_param_binding).collect();
+                request_validation_values.push(format!("path: {{ {} }}", bindings.join(", ")));
+            }
+            validator_refs.namespace = true;
+            request_validation_entries.push(format!(
+                "path: {}.{}PathParamsSchema",
+                namespace_name, type_name_base
+            ));
+        }
+        if !query_params.is_empty() {
+            request_validation_values.push("query".to_string());
+            validator_refs.namespace = true;
+            request_validation_entries.push(format!(
+                "query: {}.{}QueryParamsSchema",
+                namespace_name, type_name_base
+            ));
+        }
+        if let Some(schema_ref) = request_body_schema(openapi, operation) {
+            if let Some(validator) = body_validator(
+                openapi,
+                &schema_ref,
+                &namespace_name,
+                common_schemas,
+                &mut validator_refs,
+            ) {
+                request_validation_values.push("body".to_string());
+                request_validation_entries.push(format!("body: {}", validator));
+            }
+        }
+    }
+
+    // Validation runs first so invalid input never builds a URL or hits the network. Like
+    // response validation, the mode is the client's (configured from
+    // `apis.validate_requests`) unless the caller overrides it per call.
+    // Rendered per client so SDK methods validate through their own VikaClient instance.
+    let request_validation = |client: &str| -> Vec<String> {
+        if request_validation_entries.is_empty() {
+            return Vec::new();
+        }
+        let mut lines = vec![
+            format!(
+                "    const validation = {}.validateRequest<{}, {}>(",
+                client, success_map_type, error_map_type
+            ),
+            format!("      {{ {} }},", request_validation_values.join(", ")),
+            "      {".to_string(),
+        ];
+        for entry in &request_validation_entries {
+            lines.push(format!("        {},", entry));
+        }
+        if is_axios {
+            lines.push("      }".to_string());
+        } else {
+            lines.push("      },".to_string());
+            lines.push("      options?.validateRequests".to_string());
+        }
+        lines.push("    );".to_string());
+        // Streaming functions return a generator, which throws the failure when iterated
+        lines.push(if stream.is_some() {
+            "    if (validation) return failedStream(validation);".to_string()
+        } else if throws {
+            "    if (validation) return unwrapResult(validation);".to_string()
+        } else {
+            "    if (validation) return validation;".to_string()
+        });
+        lines
+    };
+
+    // Build status code -> Zod schema map for runtime response validation
+    let mut response_schema_entries = Vec::new();
+    if apis_config.validate_responses != "off" {
+        // Exact statuses first, then ranges, then `default` (the runtime matches in that order)
+        let mut responses: Vec<&ResponseInfo> = success_responses
+            .iter()
+            .chain(error_responses.iter())
+            .collect();
+        responses.sort_by_key(|r| (r.status_code == 0, r.range, r.status_code));
+        for response in responses {
+            let validator = response.body_schema.as_ref().and_then(|schema_ref| {
+                body_validator(
+                    openapi,
+                    schema_ref,
+                    &namespace_name,
+                    common_schemas,
+                    &mut validator_refs,
+                )
+            });
+            if let Some(validator) = validator {
+                response_schema_entries.push((response.map_key(), validator));
+            }
+        }
+    }
+
     // Build VikaClient call with generic types
-    if let Some((_body_type, _)) = &request_body_info {
-        body_lines.push(format!(
-            "    return vikaClient.{}<{}, {}>(url, {{ body }});",
-            http_method, success_map_type, error_map_type
+    let mut request_options = Vec::new();
+    let forwards_options = !is_axios && !is_angular;
+    if forwards_options {
+        request_options.push("...options".to_string());
+    }
+    if request_body_info.is_some() {
+        request_options.push("body".to_string());
+    }
+    if !header_params.is_empty() {
+        if forwards_options {
+            request_options.push("headers: { ...requestHeaders, ...options?.headers }".to_string());
+        } else {
+            request_options.push("headers: requestHeaders".to_string());
+        }
+    }
+    // The validation mode is left to the client (configured from `apis.validate_responses`)
+    // so callers can still override it per call
+    if !response_schema_entries.is_empty() {
+        let schema_lines: Vec<String> = response_schema_entries
+            .iter()
+            .map(|(status, schema)| format!("        {}: {},", status, schema))
+            .collect();
+        request_options.push(format!(
+            "responseSchemas: {{\n{}\n      }}",
+            schema_lines.join("\n")
+        ));
+    }
+
+    // Declared response headers are read and coerced by the runtime (`{Op}Headers` map)
+    let header_responses: Vec<&ResponseInfo> = success_responses
+        .iter()
+        .chain(error_responses.iter())
+        .filter(|r| !r.headers.is_empty())
+        .collect();
+    let reads_headers = !header_responses.is_empty() && stream.is_none() && !is_angular;
+    if reads_headers {
+        let header_lines: Vec<String> = header_responses
+            .iter()
+            .map(|response| {
+                let kinds: Vec<String> = response
+                    .headers
+                    .iter()
+                    .map(|h| format!("{}: \"{}\"", sanitize_property_name(&h.name), h.kind))
+                    .collect();
+                format!(
+                    "        {}: {{ {} }},",
+                    response.map_key(),
+                    kinds.join(", ")
+                )
+            })
+            .collect();
+        request_options.push(format!(
+            "responseHeaders: {{\n{}\n      }}",
+            header_lines.join("\n")
         ));
+    }
+
+    // Attach the credentials this operation requires (`security: []` means anonymous)
+    let has_security_module = !collect_security_schemes(openapi).is_empty();
+    let mut uses_security_module = false;
+    let security_requirements = if is_angular {
+        // Angular apps attach credentials through HttpInterceptors
+        None
     } else {
-        body_lines.push(format!(
-            "    return vikaClient.{}<{}, {}>(url);",
-            http_method, success_map_type, error_map_type
+        operation_security(openapi, operation)
+    };
+    if let Some(requirements) = security_requirements {
+        if requirements.is_empty() {
+            request_options.push("security: { requirements: [] }".to_string());
+        } else if has_security_module {
+            uses_security_module = true;
+            request_options.push(format!(
+                "security: {{\n        schemes: securitySchemes,\n        requirements: {},\n      }}",
+                requirements_literal(requirements)
+            ));
+        } else {
+            request_options.push(format!(
+                "security: {{ requirements: {} }}",
+                requirements_literal(requirements)
+            ));
+        }
+    }
+
+    // Operation metadata exposed to middleware contexts (Angular apps use HttpInterceptors)
+    if !is_angular {
+        let operation_id = operation.operation_id.as_deref().unwrap_or(&func_name);
+        // Spec-provided strings may contain quotes or backslashes
+        let tags: Vec<String> = operation
+            .tags
+            .iter()
+            .map(|tag| string_literal(tag))
+            .collect();
+        let mut fields = vec![
+            format!("operationId: {}", string_literal(operation_id)),
+            format!("module: {}", string_literal(module_name)),
+        ];
+        if let Some(spec) = spec_name {
+            fields.push(format!("spec: {}", string_literal(spec)));
+        }
+        fields.push(format!("path: {}", string_literal(&op_info.path)));
+        fields.push(format!("tags: [{}]", tags.join(", ")));
+        request_options.push(format!(
+            "operation: {{\n{}\n      }}",
+            fields
+                .iter()
+                .map(|field| format!("        {},", field))
+                .collect::<Vec<_>>()
+                .join("\n")
         ));
     }
 
-    // Runtime client is at {root_dir}/runtime/index.ts
-    // Calculate relative path from {apis_dir}/{module}/index.ts to {root_dir}/runtime/index.ts
-    // For example: src/apis/ecommerce/addresses/index.ts -> src/runtime/index.ts
-    // Calculate module depth (module_name can have nested modules like "tenant/auth")
-    let module_depth = module_name.matches('/').count() + 1; // +1 for the module directory itself
+    // `x-idempotent` operations send an idempotency key and may be retried whatever their method
+    let is_idempotent = operation
+        .extensions
+        .get("x-idempotent")
+        .is_some_and(|value| value.as_bool() == Some(true));
+    if is_idempotent && forwards_options {
+        request_options.push("idempotent: true".to_string());
+    }
 
-    let runtime_import = if let (Some(root), Some(apis)) = (root_dir, apis_dir) {
-        // Calculate depth: number of path segments from apis_dir to root_dir
-        // e.g., if root_dir = "src" and apis_dir = "src/apis/ecommerce", depth = 2
-        let root_normalized = root.trim_end_matches('/');
-        let apis_normalized = apis
-            .trim_start_matches(root_normalized)
-            .trim_start_matches('/');
+    // Operation/path-level `servers` replace the client's base URL (per-call options still win)
+    let server_override = operation_server_url(openapi, &op_info.path, operation);
+    if let Some(ref server_url) = server_override {
+        if forwards_options {
+            request_options.insert(0, format!("baseUrl: {}", string_literal(server_url)));
+        }
+    }
+    if is_axios {
+        match server_override {
+            Some(ref server_url) => request_options.push(format!(
+                "config: {{ baseURL: {}, ...config }}",
+                string_literal(server_url)
+            )),
+            None => request_options.push("config".to_string()),
+        }
+    }
 
-        // Count path segments in apis_dir relative to root_dir
-        let apis_depth = if apis_normalized.is_empty() {
-            0
+    let client_name = if is_axios {
+        "axiosClient"
+    } else {
+        "vikaClient"
+    };
+    // Streaming calls are typed by the event and take the HTTP method as first argument
+    let event_type_name = format!("{}Event", to_pascal_case(&func_name));
+    let (call_method, call_generic, call_url) = match &stream {
+        Some(stream) => (
+            stream.format.client_method(),
+            &event_type_name,
+            format!("\"{}\", url", method.to_uppercase()),
+        ),
+        None => (http_method, &success_map_type, "url".to_string()),
+    };
+    // Error map type argument, followed by the headers map when headers are read
+    let error_generics = if reads_headers {
+        format!("{}, {}Headers", error_map_type, type_name_base)
+    } else {
+        error_map_type.clone()
+    };
+    // Call arguments after the URL: nothing, the caller's options, or an inline options object
+    let call_arguments = if request_options.is_empty() {
+        call_url
+    } else if request_options == ["...options"] {
+        format!("{}, options", call_url)
+    } else if request_options
+        .iter()
+        .all(|option| option == "body" || option == "config" || option == "...options")
+    {
+        format!("{}, {{ {} }}", call_url, request_options.join(", "))
+    } else {
+        let fields: Vec<String> = request_options
+            .iter()
+            .map(|option| format!("      {},", option))
+            .collect();
+        format!("{}, {{\n{}\n    }}", call_url, fields.join("\n"))
+    };
+    // Throwing functions unwrap the result, mapping error statuses to their generated classes
+    let (result_open, result_close) = if throws {
+        let error_classes = if error_responses.is_empty() {
+            String::new()
         } else {
-            apis_normalized.matches('/').count() + 1
+            let classes: Vec<String> = error_responses
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{}: {}{}Error",
+                        r.map_key(),
+                        type_name_base,
+                        r.name_suffix()
+                    )
+                })
+                .collect();
+            format!(", {{ {} }}", classes.join(", "))
         };
-
-        let total_depth = apis_depth + module_depth;
-
-        format!("{}runtime", "../".repeat(total_depth))
+        ("unwrapResult(await ", format!("{})", error_classes))
     } else {
-        // Fallback: assume runtime is at same level as apis (backward compatibility)
-        format!("{}runtime", "../".repeat(module_depth))
+        ("", String::new())
     };
+    // Rendered per client so SDK methods can call their own VikaClient instance
+    let client_call = |client: &str| -> Vec<String> {
+        vec![format!(
+            "    return {}{}.{}<{}, {}>({}){};",
+            result_open,
+            client,
+            call_method,
+            call_generic,
+            error_generics,
+            call_arguments,
+            result_close
+        )]
+    };
+
+    // SDK methods live one object level deeper inside the module's `create{Module}Api` factory
+    let sdk_body = (apis_config.client_class.is_some() && !is_axios && !is_angular).then(|| {
+        request_validation("client")
+            .into_iter()
+            .chain(body_lines.iter().cloned())
+            .chain(client_call("client"))
+            .flat_map(|line| line.lines().map(|l| format!("  {}", l)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+    body_lines.splice(0..0, request_validation(client_name));
+    body_lines.extend(client_call(client_name));
+
+    // Runtime client is at {root_dir}/runtime/index.ts
+    // Calculate relative path from {apis_dir}/{module}/index.ts to {root_dir}/runtime/index.ts
+    // For example: src/apis/ecommerce/addresses/index.ts -> src/runtime/index.ts
+    // Calculate module depth (module_name can have nested modules like "tenant/auth")
+    let module_depth = module_name.matches('/').count() + 1; // +1 for the module directory itself
+
+    let runtime_import = runtime_import_path(root_dir, apis_dir, module_depth);
 
     // Determine if response type is in common schemas or module-specific
     // We still need schema imports for request/response body types
@@ -414,6 +924,21 @@ fn generate_function_for_operation(
         }
     }
 
+    // Streamed event type (a component schema or an inline primitive)
+    let event_type = stream.as_ref().map(|stream| match &stream.event_schema {
+        Some(schema) => {
+            let type_name = to_pascal_case(schema);
+            if common_schemas.contains(&type_name) {
+                needs_common_import = true;
+                format!("Common.{}", type_name)
+            } else {
+                needs_namespace_import = true;
+                format!("{}.{}", namespace_name, type_name)
+            }
+        }
+        None => stream.inline_type.to_string(),
+    });
+
     // Check if request body type needs import
     if let Some((body_type, _)) = &request_body_info {
         if body_type != "any" {
@@ -433,6 +958,10 @@ fn generate_function_for_operation(
         }
     }
 
+    // Validation schemas are referenced through the same namespaces
+    needs_common_import |= validator_refs.common;
+    needs_namespace_import |= validator_refs.namespace;
+
     // Add imports
     // Calculate relative path from {apis_dir}/{module}/index.ts to {schemas_dir}/{module}/index.ts
     // Use actual schemas_dir path from config instead of hardcoded "schemas"
@@ -533,7 +1062,7 @@ fn generate_function_for_operation(
 
     // Generate response types (Errors, Error union, Responses)
     // Query params types are now generated in schema files, not here
-    let response_types = generate_response_types(
+    let mut response_types = generate_response_types(
         &func_name,
         &success_responses,
         &error_responses,
@@ -541,11 +1070,30 @@ fn generate_function_for_operation(
         common_schemas,
         &enum_types,
     );
+    if let Some(ref event_type) = event_type {
+        response_types.push(TypeScriptType {
+            content: format!("export type {} = {};", event_type_name, event_type),
+        });
+    }
+    if object_signature && !object_fields.is_empty() {
+        let fields: Vec<String> = object_fields
+            .iter()
+            .map(|field| format!("    {};", field))
+            .collect();
+        response_types.insert(
+            0,
+            TypeScriptType {
+                content: format!(
+                    "export type {} = {{\n{}\n}};",
+                    params_type_name,
+                    fields.join("\n")
+                ),
+            },
+        );
+    }
 
     // Always generate Responses and Errors types (even if empty, they'll be Record<never, never>)
-    let type_name_base = to_pascal_case(&func_name);
     let success_map_type = format!("{}Responses", type_name_base);
-    let error_map_type = format!("{}Errors", type_name_base);
 
     // Response/Error types are generated and exported in the API file itself
     // They should NOT be imported from schemas - they're defined locally
@@ -559,14 +1107,82 @@ fn generate_function_for_operation(
         type_imports.push('\n');
     }
 
+    // Array and primitive bodies are validated with inline Zod expressions
+    if validator_refs.zod {
+        type_imports.insert_str(0, "import { z } from \"zod\";\n");
+    }
+
+    if throws {
+        type_imports.insert_str(
+            0,
+            &format!("import {{ unwrapResult }} from \"{}\";\n", runtime_import),
+        );
+    }
+
+    if stream.is_some() && !request_validation_entries.is_empty() {
+        type_imports.insert_str(
+            0,
+            &format!("import {{ failedStream }} from \"{}\";\n", runtime_import),
+        );
+    }
+
+    if is_axios {
+        type_imports.insert_str(0, "import type { AxiosRequestConfig } from \"axios\";\n");
+    }
+
+    if let Some(ref stream) = stream {
+        let types = match stream.format {
+            StreamFormat::Sse => "ServerSentEvent, StreamOptions",
+            StreamFormat::Ndjson => "StreamOptions",
+        };
+        type_imports.insert_str(
+            0,
+            &format!("import type {{ {} }} from \"{}\";\n", types, runtime_import),
+        );
+    }
+
+    // Scheme definitions live in the spec-level security module at {apis_dir}/security.ts
+    if uses_security_module {
+        type_imports.insert_str(
+            0,
+            &format!(
+                "import {{ securitySchemes }} from \"{}security\";\n",
+                "../".repeat(module_depth)
+            ),
+        );
+    }
+
     // Determine return type - use ApiResult with Responses and Errors maps
-    let return_type = format!(
-        ": Promise<ApiResult<{}, {}>>",
-        success_map_type, error_map_type
-    );
+    let return_type = match stream.as_ref().map(|stream| stream.format) {
+        Some(StreamFormat::Sse) => format!(
+            ": AsyncGenerator<ServerSentEvent<{}>, void, undefined>",
+            event_type_name
+        ),
+        Some(StreamFormat::Ndjson) => {
+            format!(": AsyncGenerator<{}, void, undefined>", event_type_name)
+        }
+        None if throws => {
+            if success_responses.iter().any(|r| r.body_type != "any") {
+                format!(
+                    ": Promise<{}[keyof {}]>",
+                    success_map_type, success_map_type
+                )
+            } else {
+                ": Promise<unknown>".to_string()
+            }
+        }
+        None => format!(
+            ": Promise<ApiResult<{}, {}>>",
+            success_map_type, error_generics
+        ),
+    };
+    let async_keyword = if stream.is_some() { "" } else { "async " };
 
     let function_body = body_lines.join("\n");
 
+    // Functions call the spec's own client instance from {apis_dir}/vika-client.ts
+    let http_import = spec_client_import_path(module_depth);
+
     // Build API context for template
     let api_path_params: Vec<ApiParameter> = path_params
         .iter()
@@ -579,7 +1195,12 @@ fn generate_function_for_operation(
                 ParameterType::Integer => "number".to_string(),
                 ParameterType::Boolean => "boolean".to_string(),
             };
-            ApiParameter::new(p.name.clone(), param_type, false, p.description.clone())
+            ApiParameter::new(
+                parameter_identifier(&p.name),
+                param_type,
+                false,
+                p.description.clone(),
+            )
         })
         .collect();
 
@@ -623,8 +1244,150 @@ fn generate_function_for_operation(
         .collect::<Vec<_>>()
         .join("\n\n");
 
+    // Angular style: the operation becomes a method on the module's @Injectable service,
+    // the function entry only carries imports and response types
+    if is_angular {
+        let header_params = extract_header_parameters(openapi, operation, enum_registry)?;
+        let mut method_params = params.clone();
+        if !header_params.is_empty() {
+            let fields: Vec<String> = header_params
+                .iter()
+                .map(|p| {
+                    format!(
+                        "{}?: {}",
+                        sanitize_property_name(&p.name),
+                        header_param_type(&p.param_type)
+                    )
+                })
+                .collect();
+            method_params.push(format!("headers?: {{ {} }}", fields.join("; ")));
+        }
+
+        let has_success_body = success_responses
+            .iter()
+            .any(|r| r.status_code >= 200 && r.status_code < 300 && r.body_type != "any");
+        let observable_type = if has_success_body {
+            format!("{}[keyof {}]", success_map_type, success_map_type)
+        } else {
+            "void".to_string()
+        };
+
+        let base_url = server_override
+            .clone()
+            .unwrap_or_else(|| "${this.baseUrl}".to_string());
+        let mut method_lines = vec![format!("    const url = `{}{}`;", base_url, url_template)];
+        let mut http_options = Vec::new();
+        if !query_params.is_empty() {
+            method_lines.push("    let params = new HttpParams();".to_string());
+            for param in &query_params {
+                let value = property_access("query", &param.name, false);
+                let optional_value = property_access("query", &param.name, true);
+                if param.is_array && param.explode.unwrap_or(true) {
+                    // explode: true -> tags=one&tags=two
+                    method_lines.push(format!(
+                        "    {}?.forEach((item) => {{\n      params = params.append(\"{}\", String(item));\n    }});",
+                        optional_value, param.name
+                    ));
+                } else if param.is_array {
+                    // explode: false -> tags=one,two
+                    method_lines.push(format!(
+                        "    if ({} !== undefined) params = params.set(\"{}\", {}.join(\",\"));",
+                        optional_value, param.name, value
+                    ));
+                } else {
+                    method_lines.push(format!(
+                        "    if ({} !== undefined) params = params.set(\"{}\", String({}));",
+                        optional_value, param.name, value
+                    ));
+                }
+            }
+            http_options.push("params".to_string());
+        }
+        if !header_params.is_empty() {
+            method_lines.push("    let httpHeaders = new HttpHeaders();".to_string());
+            for param in &header_params {
+                let accessor = format!("headers?.[\"{}\"]", param.name);
+                method_lines.push(format!(
+                    "    if ({} !== undefined) httpHeaders = httpHeaders.set(\"{}\", String({}));",
+                    accessor, param.name, accessor
+                ));
+            }
+            http_options.push("headers: httpHeaders".to_string());
+        }
+
+        let body_arg = if request_body_info.is_some() {
+            "body"
+        } else {
+            "null"
+        };
+        let mut call_args = vec!["url".to_string()];
+        match http_method {
+            "post" | "put" | "patch" => call_args.push(body_arg.to_string()),
+            _ if request_body_info.is_some() => http_options.push("body".to_string()),
+            _ => {}
+        }
+        if !http_options.is_empty() {
+            call_args.push(format!("{{ {} }}", http_options.join(", ")));
+        }
+        method_lines.push(format!(
+            "    return this.http.{}<{}>({});",
+            http_method,
+            observable_type,
+            call_args.join(", ")
+        ));
+
+        let method_params_str = method_params.join(", ");
+        let method_return_type = format!(": Observable<{}>", observable_type);
+        let method_body = method_lines.join("\n");
+        let service_method = if let Some(engine) = template_engine {
+            let mut context = ApiContext::new(
+                func_name.clone(),
+                operation.operation_id.clone(),
+                method.clone(),
+                op_info.path.clone(),
+                api_path_params,
+                api_query_params,
+                api_request_body,
+                api_responses,
+                type_imports.clone(),
+                "@angular/common/http".to_string(),
+                method_return_type,
+                method_body,
+                response_types_content.clone(),
+                module_name.to_string(),
+                method_params_str,
+                operation_description.clone(),
+                spec_name.map(|s| s.to_string()),
+            );
+            context.deprecated = operation_deprecation(operation);
+            engine.render(TemplateId::ApiClientAngular, &context)?
+        } else {
+            let jsdoc = if !operation_description.is_empty() {
+                format!("  /**\n   * {}\n   */\n", operation_description)
+            } else {
+                String::new()
+            };
+            format!(
+                "{}  {}({}){} {{\n{}\n  }}",
+                jsdoc, func_name, method_params_str, method_return_type, method_body
+            )
+        };
+
+        return Ok(FunctionGenerationResult {
+            function: ApiFunction {
+                content: format!("{}\n{}", type_imports, response_types_content),
+            },
+            response_types,
+            service_method: Some(service_method),
+            sdk_method: None,
+            error_classes: Vec::new(),
+            pagination_helpers: Vec::new(),
+        });
+    }
+
+    let mut sdk_method = None;
     let content = if let Some(engine) = template_engine {
-        let context = ApiContext::new(
+        let mut context = ApiContext::new(
             func_name.clone(),
             operation.operation_id.clone(),
             method.clone(),
@@ -634,7 +1397,7 @@ fn generate_function_for_operation(
             api_request_body,
             api_responses,
             type_imports.clone(),
-            runtime_import.to_string(),
+            http_import.clone(),
             return_type.clone(),
             function_body.clone(),
             response_types_content.clone(),
@@ -643,8 +1406,22 @@ fn generate_function_for_operation(
             operation_description.clone(),
             spec_name.map(|s| s.to_string()),
         );
+        context.streaming = stream.is_some();
+        context.deprecated = operation_deprecation(operation);
+
+        let template_id = if is_axios {
+            TemplateId::ApiClientAxios
+        } else {
+            TemplateId::ApiClientFetch
+        };
+        let content = engine.render(template_id, &context)?;
 
-        engine.render(TemplateId::ApiClientFetch, &context)?
+        // The SDK method is rendered from the same context, calling the factory's client
+        if let Some(body) = sdk_body {
+            context.function_body = body;
+            sdk_method = Some(engine.render(TemplateId::ApiClientSdkMethod, &context)?);
+        }
+        content
     } else {
         // Fallback to string formatting
         let jsdoc = if !operation_description.is_empty() {
@@ -652,6 +1429,22 @@ fn generate_function_for_operation(
         } else {
             String::new()
         };
+        if let Some(body) = sdk_body {
+            let method_jsdoc = if !operation_description.is_empty() {
+                format!("    /**\n     * {}\n     */\n", operation_description)
+            } else {
+                String::new()
+            };
+            sdk_method = Some(format!(
+                "{}    {}: {}({}){} => {{\n{}\n    }},",
+                method_jsdoc, func_name, async_keyword, params_str, return_type, body
+            ));
+        }
+        let runtime_types = if forwards_options {
+            "type ApiResult, type CallOptions"
+        } else {
+            "type ApiResult"
+        };
         if params_str.is_empty() {
             let types_section = if !response_types_content.is_empty() {
                 format!("{}\n\n", response_types_content)
@@ -659,13 +1452,16 @@ fn generate_function_for_operation(
                 String::new()
             };
             format!(
-                "import {{ vikaClient, type ApiResult }} from \"{}\";\n{}{}{}{}export const {} = async (){} => {{\n{}\n}};",
-                runtime_import,
+                "import {{ {}, {} }} from \"{}\";\n{}{}{}{}export const {} = {}(){} => {{\n{}\n}};",
+                client_name,
+                runtime_types,
+                http_import,
                 type_imports,
                 if !type_imports.is_empty() { "\n" } else { "" },
                 types_section,
                 jsdoc,
                 func_name,
+                async_keyword,
                 return_type,
                 function_body
             )
@@ -676,13 +1472,16 @@ fn generate_function_for_operation(
                 String::new()
             };
             format!(
-                "import {{ vikaClient, type ApiResult }} from \"{}\";\n{}{}{}{}export const {} = async ({}){} => {{\n{}\n}};",
-                runtime_import,
+                "import {{ {}, {} }} from \"{}\";\n{}{}{}{}export const {} = {}({}){} => {{\n{}\n}};",
+                client_name,
+                runtime_types,
+                http_import,
                 type_imports,
                 if !type_imports.is_empty() { "\n" } else { "" },
                 types_section,
                 jsdoc,
                 func_name,
+                async_keyword,
                 params_str,
                 return_type,
                 function_body
@@ -690,12 +1489,251 @@ fn generate_function_for_operation(
         }
     };
 
+    // Cursor, page and offset paginated operations get async iterator helpers
+    let mut pagination_helpers = Vec::new();
+    if request_body_info.is_none() {
+        let query_names: Vec<String> = query_params.iter().map(|p| p.name.clone()).collect();
+        let pagination = detect_pagination(
+            openapi,
+            operation,
+            &method,
+            &query_names,
+            apis_config.pagination.as_ref(),
+        );
+        let typed_response = pagination.as_ref().is_some_and(|pagination| {
+            success_responses
+                .iter()
+                .any(|r| r.status_code == pagination.status && !r.range && r.body_type != "any")
+        });
+        let function = PaginatedFunction {
+            func_name: &func_name,
+            type_name_base: &type_name_base,
+            params: &params,
+            object_signature,
+            typed_response,
+            throws,
+            runtime_import: &runtime_import,
+        };
+        if let Some((paginate, fetch_all)) = pagination
+            .as_ref()
+            .and_then(|pagination| generate_pagination_helpers(pagination, &function))
+        {
+            pagination_helpers.push(ApiFunction { content: paginate });
+            pagination_helpers.push(ApiFunction { content: fetch_all });
+        }
+    }
+
+    // One `ApiError` subclass per declared error status, typed by the `{Op}Errors` map
+    let error_classes = if throws {
+        error_responses
+            .iter()
+            .map(|response| {
+                let (when, status_field) = if response.status_code == 0 {
+                    (
+                        "a status without a declared response".to_string(),
+                        String::new(),
+                    )
+                } else if response.range {
+                    (
+                        format!("a {} status", response.name_suffix()),
+                        String::new(),
+                    )
+                } else {
+                    (
+                        format!("status {}", response.status_code),
+                        format!("\n  declare readonly status: {};\n", response.status_code),
+                    )
+                };
+                ApiFunction {
+                    content: format!(
+                        r#"import {{ ApiError }} from "{runtime_import}";
+
+/**
+ * Thrown by `{func_name}` when the server responds with {when}.
+ */
+export class {base}{suffix}Error extends ApiError<{base}Errors[{key}]> {{{status_field}}}"#,
+                        runtime_import = runtime_import,
+                        func_name = func_name,
+                        when = when,
+                        base = type_name_base,
+                        suffix = response.name_suffix(),
+                        key = if response.status_code == 0 {
+                            "\"default\"".to_string()
+                        } else {
+                            response.map_key()
+                        },
+                        status_field = status_field,
+                    ),
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     Ok(FunctionGenerationResult {
         function: ApiFunction { content },
         response_types,
+        service_method: None,
+        sdk_method,
+        error_classes,
+        pagination_helpers,
     })
 }
 
+/// Destructuring binding for a path parameter: `petId`, or `"pet-id": petId` when the
+/// spec name is not a valid identifier.
+fn path_param_binding(param: &ParameterInfo) -> String {
+    let identifier = parameter_identifier(&param.name);
+    if identifier == param.name {
+        identifier
+    } else {
+        format!("{}: {}", sanitize_property_name(&param.name), identifier)
+    }
+}
+
+/// TypeScript type of a header parameter (header values are always sent as strings).
+fn header_param_type(param_type: &ParameterType) -> &'static str {
+    match param_type {
+        ParameterType::Number | ParameterType::Integer => "number",
+        ParameterType::Boolean => "boolean",
+        _ => "string",
+    }
+}
+
+/// Assemble the module's `@Injectable` service class from the generated methods (angular style).
+pub fn generate_angular_service(module_name: &str, methods: &[String]) -> ApiFunction {
+    let service_name = format!("{}Service", to_pascal_case(&module_name.replace('/', "_")));
+    let module_depth = module_name.matches('/').count() + 1;
+
+    let content = format!(
+        r#"import {{ Injectable, inject }} from "@angular/core";
+import {{ HttpClient, HttpHeaders, HttpParams }} from "@angular/common/http";
+import type {{ Observable }} from "rxjs";
+import {{ API_BASE_URL }} from "{}api-config";
+
+/**
+ * Angular service for the `{}` module.
+ */
+@Injectable({{ providedIn: "root" }})
+export class {} {{
+  private readonly http = inject(HttpClient);
+  private readonly baseUrl = inject(API_BASE_URL);
+
+{}
+}}"#,
+        "../".repeat(module_depth),
+        module_name,
+        service_name,
+        methods
+            .iter()
+            .map(|method| method.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    );
+
+    ApiFunction { content }
+}
+
+/// Name of the factory that binds a module's operations to a VikaClient, e.g. `createUsersApi`.
+pub fn sdk_factory_name(module_name: &str) -> String {
+    format!(
+        "create{}Api",
+        to_pascal_case(&module_name.replace('/', "_"))
+    )
+}
+
+/// Assemble the module's `create{Module}Api(client)` factory from the generated SDK methods.
+/// The free functions stay untouched, so bundles that never use the SDK class drop it.
+pub fn generate_sdk_module_factory(
+    module_name: &str,
+    methods: &[String],
+    runtime_import: &str,
+) -> ApiFunction {
+    let factory_name = sdk_factory_name(module_name);
+    let content = format!(
+        r#"import type {{ VikaClient }} from "{runtime_import}";
+
+export type {api_type} = ReturnType<typeof {factory_name}>;
+
+/**
+ * Operations of the `{module_name}` module bound to a VikaClient instance.
+ * Used by the generated SDK class; the exported functions above use the shared `vikaClient`.
+ */
+export function {factory_name}(client: VikaClient) {{
+  return {{
+{methods}
+  }};
+}}"#,
+        runtime_import = runtime_import,
+        api_type = factory_name.trim_start_matches("create"),
+        factory_name = factory_name,
+        module_name = module_name,
+        methods = methods
+            .iter()
+            .map(|method| method.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    );
+
+    ApiFunction { content }
+}
+
+/// Generate the spec-level `api-config.ts` holding the base URL `InjectionToken` used by
+/// angular services.
+pub fn generate_angular_api_config(spec_name: Option<&str>, base_url: Option<&str>) -> String {
+    let token_description = match spec_name {
+        Some(spec) => format!("{} API_BASE_URL", spec),
+        None => "API_BASE_URL".to_string(),
+    };
+    format!(
+        r#"import {{ InjectionToken }} from "@angular/core";
+
+/**
+ * Base URL prepended to every request made by the generated services.
+ *
+ * @example
+ * ```typescript
+ * providers: [{{ provide: API_BASE_URL, useValue: "https://api.example.com" }}]
+ * ```
+ */
+export const API_BASE_URL = new InjectionToken<string>("{}", {{
+  providedIn: "root",
+  factory: () => "{}",
+}});
+"#,
+        token_description,
+        base_url.unwrap_or_default()
+    )
+}
+
+/// Relative import path from a file `depth` directories below `apis_dir` to the
+/// runtime client at `{root_dir}/runtime`.
+pub fn runtime_import_path(root_dir: Option<&str>, apis_dir: Option<&str>, depth: usize) -> String {
+    if let (Some(root), Some(apis)) = (root_dir, apis_dir) {
+        // Calculate depth: number of path segments from apis_dir to root_dir
+        // e.g., if root_dir = "src" and apis_dir = "src/apis/ecommerce", depth = 2
+        let root_normalized = root.trim_end_matches('/');
+        let apis_normalized = apis
+            .trim_start_matches(root_normalized)
+            .trim_start_matches('/');
+
+        // Count path segments in apis_dir relative to root_dir
+        let apis_depth = if apis_normalized.is_empty() {
+            0
+        } else {
+            apis_normalized.matches('/').count() + 1
+        };
+
+        let total_depth = apis_depth + depth;
+
+        format!("{}runtime", "../".repeat(total_depth))
+    } else {
+        // Fallback: assume runtime is at same level as apis (backward compatibility)
+        format!("{}runtime", "../".repeat(depth))
+    }
+}
+
 pub fn extract_path_parameters(
     openapi: &OpenAPI,
     operation: &Operation,
@@ -827,6 +1865,40 @@ pub fn extract_query_parameters(
     Ok(params)
 }
 
+pub fn extract_header_parameters(
+    openapi: &OpenAPI,
+    operation: &Operation,
+    enum_registry: &mut std::collections::HashMap<String, String>,
+) -> Result<Vec<ParameterInfo>> {
+    let mut params = Vec::new();
+
+    for param_ref in &operation.parameters {
+        // Resolve parameter reference (with support for nested references up to 3 levels)
+        let mut param = param_ref.clone();
+        let mut depth = 0;
+        while let ReferenceOr::Reference { reference } = &param {
+            if depth > 3 {
+                break; // Prevent infinite loops
+            }
+            match resolve_parameter_ref(openapi, reference) {
+                Ok(resolved) => param = resolved,
+                Err(_) => break, // Reference resolution failed - skip
+            }
+            depth += 1;
+        }
+
+        if let ReferenceOr::Item(Parameter::Header { parameter_data, .. }) = &param {
+            if let Some(param_info) =
+                extract_parameter_info(openapi, parameter_data, enum_registry)?
+            {
+                params.push(param_info);
+            }
+        }
+    }
+
+    Ok(params)
+}
+
 fn extract_parameter_info(
     openapi: &OpenAPI,
     parameter_data: &openapiv3::ParameterData,
@@ -834,6 +1906,7 @@ fn extract_parameter_info(
 ) -> Result<Option<ParameterInfo>> {
     let name = parameter_data.name.clone();
     let description = parameter_data.description.clone();
+    let deprecated = parameter_deprecation(parameter_data);
 
     // Get schema from parameter
     let schema = match &parameter_data.format {
@@ -890,6 +1963,7 @@ fn extract_parameter_info(
                                 style: Some("simple".to_string()), // default for path
                                 explode: Some(false),              // default for path
                                 description: description.clone(),
+                                deprecated: deprecated.clone(),
                             }))
                         } else {
                             Ok(Some(ParameterInfo {
@@ -902,6 +1976,7 @@ fn extract_parameter_info(
                                 style: Some("simple".to_string()),
                                 explode: Some(false),
                                 description: description.clone(),
+                                deprecated: deprecated.clone(),
                             }))
                         }
                     }
@@ -915,6 +1990,7 @@ fn extract_parameter_info(
                         style: Some("simple".to_string()),
                         explode: Some(false),
                         description: description.clone(),
+                        deprecated: deprecated.clone(),
                     })),
                     Type::Integer(_) => Ok(Some(ParameterInfo {
                         name,
@@ -926,6 +2002,7 @@ fn extract_parameter_info(
                         style: Some("simple".to_string()),
                         explode: Some(false),
                         description: description.clone(),
+                        deprecated: deprecated.clone(),
                     })),
                     Type::Boolean(_) => Ok(Some(ParameterInfo {
                         name,
@@ -937,6 +2014,7 @@ fn extract_parameter_info(
                         style: Some("simple".to_string()),
                         explode: Some(false),
                         description: description.clone(),
+                        deprecated: deprecated.clone(),
                     })),
                     Type::Object(_) => Ok(Some(ParameterInfo {
                         name,
@@ -948,6 +2026,7 @@ fn extract_parameter_info(
                         style: Some("simple".to_string()),
                         explode: Some(false),
                         description: description.clone(),
+                        deprecated: deprecated.clone(),
                     })),
                     Type::Array(array) => {
                         let item_type = if let Some(items) = &array.items {
@@ -987,6 +2066,7 @@ fn extract_parameter_info(
                             style: Some("form".to_string()), // default for query arrays
                             explode: Some(true),             // default for query arrays
                             description: description.clone(),
+                            deprecated: deprecated.clone(),
                         }))
                     }
                 }
@@ -1001,6 +2081,7 @@ fn extract_parameter_info(
                 style: Some("simple".to_string()),
                 explode: Some(false),
                 description: description.clone(),
+                deprecated: deprecated.clone(),
             })),
         }
     } else {
@@ -1015,6 +2096,7 @@ fn extract_parameter_info(
             style: Some("simple".to_string()),
             explode: Some(false),
             description: description.clone(),
+            deprecated: deprecated.clone(),
         }))
     }
 }
@@ -1177,46 +2259,123 @@ pub fn extract_all_responses(
     let mut responses = Vec::new();
 
     for (status_code, response_ref) in &operation.responses.responses {
-        let status_num = match status_code {
-            openapiv3::StatusCode::Code(code) => *code,
-            openapiv3::StatusCode::Range(range) => {
-                // For ranges like 4xx, 5xx, extract the range value
-                // Range is an enum, check its variant
-                match format!("{:?}", range).as_str() {
-                    s if s.contains("4") => 400,
-                    s if s.contains("5") => 500,
-                    _ => 0,
-                }
-            }
+        let (status_num, range) = match status_code {
+            openapiv3::StatusCode::Code(code) => (*code, false),
+            // `Range(4)` is `4XX`
+            openapiv3::StatusCode::Range(range) => (range * 100, true),
         };
+        responses.push(response_info(openapi, status_num, range, response_ref));
+    }
+    // `default` covers every undeclared status
+    if let Some(response_ref) = &operation.responses.default {
+        responses.push(response_info(openapi, 0, false, response_ref));
+    }
 
-        // Extract response info (description and body type)
-        let (description, body_type) = match response_ref {
-            ReferenceOr::Reference { reference } => {
-                match resolve_response_ref(openapi, reference) {
-                    Ok(ReferenceOr::Item(response)) => {
-                        let desc = response.description.clone();
-                        let body = extract_response_body_type(openapi, &response);
-                        (Some(desc), body)
-                    }
-                    _ => (None, "any".to_string()),
-                }
-            }
-            ReferenceOr::Item(response) => {
+    Ok(responses)
+}
+
+fn response_info(
+    openapi: &OpenAPI,
+    status_code: u16,
+    range: bool,
+    response_ref: &ReferenceOr<openapiv3::Response>,
+) -> ResponseInfo {
+    // Extract response info (description, body type and headers)
+    let (description, body_type, body_schema, headers) = match response_ref {
+        ReferenceOr::Reference { reference } => match resolve_response_ref(openapi, reference) {
+            Ok(ReferenceOr::Item(response)) => {
                 let desc = response.description.clone();
-                let body = extract_response_body_type(openapi, response);
-                (Some(desc), body)
+                let body = extract_response_body_type(openapi, &response);
+                (
+                    Some(desc),
+                    body,
+                    json_body_schema(&response.content),
+                    extract_response_headers(openapi, &response),
+                )
             }
-        };
+            _ => (None, "any".to_string(), None, Vec::new()),
+        },
+        ReferenceOr::Item(response) => {
+            let desc = response.description.clone();
+            let body = extract_response_body_type(openapi, response);
+            (
+                Some(desc),
+                body,
+                json_body_schema(&response.content),
+                extract_response_headers(openapi, response),
+            )
+        }
+    };
 
-        responses.push(ResponseInfo {
-            status_code: status_num,
-            body_type,
-            description,
-        });
+    ResponseInfo {
+        status_code,
+        range,
+        body_type,
+        body_schema,
+        description,
+        headers,
     }
+}
 
-    Ok(responses)
+/// Headers declared by a response (`Content-Type` is ignored, as the spec requires).
+fn extract_response_headers(
+    openapi: &OpenAPI,
+    response: &openapiv3::Response,
+) -> Vec<ResponseHeader> {
+    let components = openapi.components.as_ref();
+    response
+        .headers
+        .iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+        .filter_map(|(name, header_ref)| {
+            let header = match header_ref {
+                ReferenceOr::Item(header) => header,
+                ReferenceOr::Reference { reference } => {
+                    let header_name = reference.strip_prefix("#/components/headers/")?;
+                    match components?.headers.get(header_name)? {
+                        ReferenceOr::Item(header) => header,
+                        ReferenceOr::Reference { .. } => return None,
+                    }
+                }
+            };
+            let schema = match &header.format {
+                openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+                    Some(schema.clone())
+                }
+                openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Reference {
+                    reference,
+                }) => match resolve_ref(openapi, reference) {
+                    Ok(ReferenceOr::Item(schema)) => Some(schema),
+                    _ => None,
+                },
+                openapiv3::ParameterSchemaOrContent::Content(_) => None,
+            };
+            let (kind, ts_type) = match schema.map(|schema| schema.schema_kind) {
+                Some(SchemaKind::Type(Type::Integer(_))) => ("integer", "number".to_string()),
+                Some(SchemaKind::Type(Type::Number(_))) => ("number", "number".to_string()),
+                Some(SchemaKind::Type(Type::Boolean(_))) => ("boolean", "boolean".to_string()),
+                Some(SchemaKind::Type(Type::String(string_type)))
+                    if !string_type.enumeration.is_empty() =>
+                {
+                    let values: Vec<String> = string_type
+                        .enumeration
+                        .iter()
+                        .flatten()
+                        .map(|value| string_literal(value))
+                        .collect();
+                    ("string", values.join(" | "))
+                }
+                _ => ("string", "string".to_string()),
+            };
+            Some(ResponseHeader {
+                name: name.clone(),
+                kind,
+                ts_type,
+                required: header.required,
+                description: header.description.clone(),
+            })
+        })
+        .collect()
 }
 
 #[allow(dead_code)]
@@ -1260,31 +2419,31 @@ fn generate_response_types(
     if !error_responses.is_empty() {
         let mut error_fields = Vec::new();
         for error in error_responses {
-            if error.status_code > 0 {
-                // For common types, use Common.TypeName
-                // For module-specific types, use namespace.TypeName (e.g., Addresses.TypeName)
-                let qualified_type = if error.body_type != "any" {
-                    if common_schemas.contains(&error.body_type) {
-                        format!("Common.{}", error.body_type)
-                    } else {
-                        // Type is in schemas, use namespace qualification
-                        format!("{}.{}", namespace_name, error.body_type)
-                    }
+            // For common types, use Common.TypeName
+            // For module-specific types, use namespace.TypeName (e.g., Addresses.TypeName)
+            let qualified_type = if error.body_type != "any" {
+                if common_schemas.contains(&error.body_type) {
+                    format!("Common.{}", error.body_type)
                 } else {
-                    "any".to_string()
-                };
+                    // Type is in schemas, use namespace qualification
+                    format!("{}.{}", namespace_name, error.body_type)
+                }
+            } else {
+                "any".to_string()
+            };
 
-                let description = error
-                    .description
-                    .as_ref()
-                    .map(|d| format!("    /**\n     * {}\n     */", d))
-                    .unwrap_or_default();
+            let description = error
+                .description
+                .as_ref()
+                .map(|d| format!("    /**\n     * {}\n     */", d))
+                .unwrap_or_default();
 
-                error_fields.push(format!(
-                    "{}\n    {}: {};",
-                    description, error.status_code, qualified_type
-                ));
-            }
+            error_fields.push(format!(
+                "{}\n    {}: {};",
+                description,
+                error.map_key(),
+                qualified_type
+            ));
         }
 
         if !error_fields.is_empty() {
@@ -1343,7 +2502,9 @@ fn generate_response_types(
 
             response_fields.push(format!(
                 "{}\n    {}: {};",
-                description, response.status_code, qualified_type
+                description,
+                response.map_key(),
+                qualified_type
             ));
         }
 
@@ -1377,6 +2538,47 @@ fn generate_response_types(
         });
     }
 
+    // Generate Headers type for responses declaring headers
+    let header_fields: Vec<String> = success_responses
+        .iter()
+        .chain(error_responses.iter())
+        .filter(|response| !response.headers.is_empty())
+        .map(|response| {
+            let fields: Vec<String> = response
+                .headers
+                .iter()
+                .map(|header| {
+                    let description = header
+                        .description
+                        .as_ref()
+                        .map(|d| format!("        /**\n         * {}\n         */\n", d))
+                        .unwrap_or_default();
+                    format!(
+                        "{}        {}{}: {};",
+                        description,
+                        sanitize_property_name(&header.name),
+                        if header.required { "" } else { "?" },
+                        header.ts_type
+                    )
+                })
+                .collect();
+            format!(
+                "    {}: {{\n{}\n    }};",
+                response.map_key(),
+                fields.join("\n")
+            )
+        })
+        .collect();
+    if !header_fields.is_empty() {
+        types.push(TypeScriptType {
+            content: format!(
+                "export type {}Headers = {{\n{}\n}};",
+                type_name_base,
+                header_fields.join("\n")
+            ),
+        });
+    }
+
     types
 }
 
@@ -1401,7 +2603,153 @@ fn extract_response_body_type(_openapi: &OpenAPI, response: &openapiv3::Response
     }
 }
 
-fn generate_function_name_from_path(path: &str, method: &str) -> String {
+/// JSON schema of an `application/json` request or response body.
+fn json_body_schema<'a>(
+    content: impl IntoIterator<Item = (&'a String, &'a openapiv3::MediaType)>,
+) -> Option<ReferenceOr<Schema>> {
+    content
+        .into_iter()
+        .find(|(media_type, _)| media_type.as_str() == "application/json")
+        .and_then(|(_, media)| media.schema.clone())
+}
+
+/// JSON schema of the operation's request body, resolving a `$ref`'d request body.
+fn request_body_schema(openapi: &OpenAPI, operation: &Operation) -> Option<ReferenceOr<Schema>> {
+    match operation.request_body.as_ref()? {
+        ReferenceOr::Reference { reference } => {
+            match resolve_request_body_ref(openapi, reference).ok()? {
+                ReferenceOr::Item(body) => json_body_schema(&body.content),
+                ReferenceOr::Reference { .. } => None,
+            }
+        }
+        ReferenceOr::Item(body) => json_body_schema(&body.content),
+    }
+}
+
+/// Schema namespaces and libraries a generated validator expression refers to.
+#[derive(Default)]
+struct ValidatorRefs {
+    common: bool,
+    namespace: bool,
+    zod: bool,
+}
+
+/// Build the Zod expression validating a body schema.
+///
+/// Component objects and string enums use their exported schemas; arrays and primitives
+/// (inline or as components) are built with `z.array(...)`, `z.string()` and friends.
+/// Inline objects and compositions have no standalone schema and are left unvalidated.
+fn body_validator(
+    openapi: &OpenAPI,
+    schema_ref: &ReferenceOr<Schema>,
+    namespace_name: &str,
+    common_schemas: &[String],
+    refs: &mut ValidatorRefs,
+) -> Option<String> {
+    let mut found = ValidatorRefs::default();
+    let expr = body_validator_expr(
+        openapi,
+        schema_ref,
+        namespace_name,
+        common_schemas,
+        &mut found,
+        0,
+    )?;
+    refs.common |= found.common;
+    refs.namespace |= found.namespace;
+    refs.zod |= found.zod;
+    Some(expr)
+}
+
+fn body_validator_expr(
+    openapi: &OpenAPI,
+    schema_ref: &ReferenceOr<Schema>,
+    namespace_name: &str,
+    common_schemas: &[String],
+    refs: &mut ValidatorRefs,
+    depth: usize,
+) -> Option<String> {
+    // Component aliases can chain; stop rather than recurse forever on a cycle
+    if depth > 8 {
+        return None;
+    }
+    let schema = match schema_ref {
+        ReferenceOr::Reference { reference } => {
+            let type_name = to_pascal_case(&get_schema_name_from_ref(reference)?);
+            if let Some(schema_name) = find_zod_schema_name(openapi, &type_name) {
+                return Some(if common_schemas.contains(&type_name) {
+                    refs.common = true;
+                    format!("Common.{}", schema_name)
+                } else {
+                    refs.namespace = true;
+                    format!("{}.{}", namespace_name, schema_name)
+                });
+            }
+            match resolve_ref(openapi, reference).ok()? {
+                ReferenceOr::Item(schema) => schema,
+                ReferenceOr::Reference { .. } => return None,
+            }
+        }
+        ReferenceOr::Item(schema) => schema.clone(),
+    };
+    let expr = match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => {
+            let items = array.items.as_ref()?.clone().unbox();
+            let item_expr = body_validator_expr(
+                openapi,
+                &items,
+                namespace_name,
+                common_schemas,
+                refs,
+                depth + 1,
+            )?;
+            format!("z.array({})", item_expr)
+        }
+        SchemaKind::Type(Type::String(_)) => "z.string()".to_string(),
+        SchemaKind::Type(Type::Number(_)) | SchemaKind::Type(Type::Integer(_)) => {
+            "z.number()".to_string()
+        }
+        SchemaKind::Type(Type::Boolean(_)) => "z.boolean()".to_string(),
+        _ => return None,
+    };
+    refs.zod = true;
+    Some(if schema.schema_data.nullable {
+        format!("{}.nullable()", expr)
+    } else {
+        expr
+    })
+}
+
+/// Find the exported Zod schema name for a (PascalCase) component type name.
+///
+/// Object components are exported as `{Name}Schema` and top-level string enums as
+/// `{Name}EnumSchema`; other component kinds have no standalone Zod schema.
+fn find_zod_schema_name(openapi: &OpenAPI, type_name: &str) -> Option<String> {
+    if type_name == "any" {
+        return None;
+    }
+    let components = openapi.components.as_ref()?;
+    let (_, schema_ref) = components
+        .schemas
+        .iter()
+        .find(|(name, _)| to_pascal_case(name) == type_name)?;
+    let schema = match schema_ref {
+        ReferenceOr::Item(schema) => schema.clone(),
+        ReferenceOr::Reference { reference } => match resolve_ref(openapi, reference).ok()? {
+            ReferenceOr::Item(schema) => schema,
+            ReferenceOr::Reference { .. } => return None,
+        },
+    };
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(_)) => Some(format!("{}Schema", type_name)),
+        SchemaKind::Type(Type::String(string_type)) if !string_type.enumeration.is_empty() => {
+            Some(format!("{}EnumSchema", type_name))
+        }
+        _ => None,
+    }
+}
+
+pub fn generate_function_name_from_path(path: &str, method: &str) -> String {
     let path_parts: Vec<&str> = path
         .trim_start_matches('/')
         .split('/')
@@ -0,0 +1,152 @@
+use crate::error::Result;
+use crate::generator::swagger_parser::{map_modules_to_schemas, OperationInfo, ParsedSpec};
+use crate::generator::utils::doc_comment_text;
+use openapiv3::{Operation, ParameterData, SchemaData};
+use serde_json::Value;
+
+/// Extension carrying a human-readable deprecation notice (e.g. "Use getOrderV2 instead.")
+const MESSAGE_EXTENSION: &str = "x-deprecated-message";
+/// Extension carrying the date after which the deprecated element is removed
+const SUNSET_EXTENSION: &str = "x-sunset";
+
+/// `@deprecated` JSDoc tag for a deprecated operation, `None` when it is not deprecated.
+pub fn operation_deprecation(operation: &Operation) -> Option<String> {
+    deprecation_tag(
+        operation.deprecated,
+        operation.extensions.get(MESSAGE_EXTENSION),
+        operation.extensions.get(SUNSET_EXTENSION),
+    )
+}
+
+/// `@deprecated` JSDoc tag for a deprecated parameter, `None` when it is not deprecated.
+pub fn parameter_deprecation(parameter: &ParameterData) -> Option<String> {
+    deprecation_tag(
+        parameter.deprecated.unwrap_or(false),
+        parameter.extensions.get(MESSAGE_EXTENSION),
+        parameter.extensions.get(SUNSET_EXTENSION),
+    )
+}
+
+/// `@deprecated` JSDoc tag for a deprecated schema or property, `None` when it is not deprecated.
+pub fn schema_deprecation(schema: &SchemaData) -> Option<String> {
+    deprecation_tag(
+        schema.deprecated,
+        schema.extensions.get(MESSAGE_EXTENSION),
+        schema.extensions.get(SUNSET_EXTENSION),
+    )
+}
+
+fn deprecation_tag(
+    deprecated: bool,
+    message: Option<&Value>,
+    sunset: Option<&Value>,
+) -> Option<String> {
+    if !deprecated {
+        return None;
+    }
+    let mut tag = "@deprecated".to_string();
+    if let Some(message) = message.and_then(Value::as_str) {
+        tag.push(' ');
+        tag.push_str(message.trim());
+    }
+    if let Some(sunset) = sunset.and_then(Value::as_str) {
+        tag.push_str(&format!(" Sunset: {}.", sunset.trim()));
+    }
+    // Spec text must not end the comment, and each of its lines continues the JSDoc block
+    let lines: Vec<String> = doc_comment_text(&tag)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some(lines.join("\n * "))
+}
+
+/// Drop deprecated operations from the parsed spec (`--no-deprecated`), recomputing which
+/// schemas each module still needs. Modules whose operations were all deprecated are
+/// dropped too, so they get no empty API file or index export.
+pub fn drop_deprecated_operations(parsed: &mut ParsedSpec) -> Result<()> {
+    let mut emptied = Vec::new();
+    for (module, operations) in parsed.operations_by_tag.iter_mut() {
+        let had_operations = !operations.is_empty();
+        operations.retain(|op| !op.operation.deprecated);
+        if had_operations && operations.is_empty() {
+            emptied.push(module.clone());
+        }
+    }
+    for module in &emptied {
+        parsed.operations_by_tag.remove(module);
+    }
+    parsed.modules.retain(|module| !emptied.contains(module));
+    let (module_schemas, _) =
+        map_modules_to_schemas(&parsed.openapi, &parsed.operations_by_tag, &parsed.schemas)?;
+    parsed.module_schemas = module_schemas;
+    Ok(())
+}
+
+/// Deprecated operations of the given modules, in module order.
+pub fn deprecated_operations<'a>(
+    parsed: &'a ParsedSpec,
+    modules: &'a [String],
+) -> Vec<(&'a str, &'a OperationInfo)> {
+    modules
+        .iter()
+        .flat_map(|module| {
+            parsed
+                .operations_by_tag
+                .get(module)
+                .into_iter()
+                .flatten()
+                .filter(|op| op.operation.deprecated)
+                .map(move |op| (module.as_str(), op))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_operation(operation: &str) -> Operation {
+        serde_json::from_str(operation).unwrap()
+    }
+
+    #[test]
+    fn test_operation_not_deprecated() {
+        let operation = parse_operation(r#"{"responses": {}}"#);
+        assert_eq!(operation_deprecation(&operation), None);
+    }
+
+    #[test]
+    fn test_operation_deprecation_with_message_and_sunset() {
+        let operation = parse_operation(
+            r#"{"deprecated": true, "responses": {},
+                "x-deprecated-message": "Use getOrderV2 instead.", "x-sunset": "2026-01-01"}"#,
+        );
+        assert_eq!(
+            operation_deprecation(&operation).as_deref(),
+            Some("@deprecated Use getOrderV2 instead. Sunset: 2026-01-01.")
+        );
+    }
+
+    #[test]
+    fn test_operation_deprecation_without_extensions() {
+        let operation = parse_operation(r#"{"deprecated": true, "responses": {}}"#);
+        assert_eq!(
+            operation_deprecation(&operation).as_deref(),
+            Some("@deprecated")
+        );
+    }
+
+    #[test]
+    fn test_operation_deprecation_escapes_message() {
+        let operation = parse_operation(
+            r#"{"deprecated": true, "responses": {},
+                "x-deprecated-message": "Use v2 */ now.\nSee the migration guide.", "x-sunset": "2026-01-01"}"#,
+        );
+        assert_eq!(
+            operation_deprecation(&operation).as_deref(),
+            Some("@deprecated Use v2 *\\/ now.\n * See the migration guide. Sunset: 2026-01-01.")
+        );
+    }
+}
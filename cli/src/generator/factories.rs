@@ -0,0 +1,692 @@
+use crate::generator::schema_resolver::SchemaResolver;
+use crate::generator::swagger_parser::{get_schema_name_from_ref, resolve_ref};
+use crate::generator::utils::{sanitize_module_name, sanitize_property_name, to_pascal_case};
+use openapiv3::{
+    ArrayType, IntegerType, NumberType, ObjectType, OpenAPI, ReferenceOr, Schema, SchemaKind,
+    StringFormat, StringType, Type, VariantOrUnknownOrEmpty,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Spec-level module with the seeded random helpers used by every factories file.
+pub const FACTORIES_RANDOM_FILE: &str = "random.ts";
+/// Module name of the factories for schemas shared by several modules.
+pub const FACTORIES_COMMON_MODULE: &str = "common";
+
+/// Seeded random helpers. Every factory draws from one mulberry32 generator, so the same
+/// seed produces the same objects across runs.
+pub const FACTORIES_RANDOM: &str = r#"let state = 1;
+
+/**
+ * Reset the generator used by every factory. Call it (e.g. in `beforeEach`) to get the
+ * same objects on every run.
+ */
+export function seedFactories(seed: number): void {
+  state = seed >>> 0;
+}
+
+/**
+ * Next number in [0, 1) (mulberry32).
+ */
+export function random(): number {
+  state = (state + 0x6d2b79f5) >>> 0;
+  let t = state;
+  t = Math.imul(t ^ (t >>> 15), t | 1);
+  t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+  return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+}
+
+export function randomInt(min: number, max: number): number {
+  const low = Math.ceil(min);
+  return low + Math.floor(random() * (Math.floor(max) - low + 1));
+}
+
+export function randomNumber(min: number, max: number): number {
+  return min + random() * (max - min);
+}
+
+export function randomBoolean(): boolean {
+  return random() < 0.5;
+}
+
+export function pick<T>(values: readonly T[]): T {
+  return values[Math.floor(random() * values.length)];
+}
+
+const ALPHABET = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+export function randomString(prefix: string, minLength = 0, maxLength = Infinity): string {
+  let value = `${prefix}-`;
+  for (let i = 0; i < 6; i++) {
+    value += ALPHABET[randomInt(0, ALPHABET.length - 1)];
+  }
+  while (value.length < minLength) {
+    value += ALPHABET[randomInt(0, ALPHABET.length - 1)];
+  }
+  return value.slice(0, maxLength);
+}
+
+export function randomUuid(): string {
+  const hex = (length: number) =>
+    Array.from({ length }, () => randomInt(0, 15).toString(16)).join("");
+  const variant = "89ab"[randomInt(0, 3)];
+  return `${hex(8)}-${hex(4)}-4${hex(3)}-${variant}${hex(3)}-${hex(12)}`;
+}
+
+export function randomEmail(): string {
+  return `${randomString("user").toLowerCase()}@example.com`;
+}
+
+export function randomUrl(): string {
+  return `https://example.com/${randomString("resource")}`;
+}
+
+const START = Date.UTC(2020, 0, 1);
+const FIVE_YEARS = 5 * 365 * 24 * 60 * 60;
+
+export function randomDateTime(): string {
+  return new Date(START + randomInt(0, FIVE_YEARS) * 1000).toISOString();
+}
+
+export function randomDate(): string {
+  return randomDateTime().slice(0, 10);
+}
+"#;
+
+/// Upper bound of numbers without `minimum`/`maximum`.
+const DEFAULT_RANGE: i64 = 1000;
+/// Array length when the schema has no `maxItems`.
+const DEFAULT_MAX_ITEMS: usize = 3;
+
+/// Schemas of one factories file and how to reach the rest of the spec.
+pub struct FactoriesContext<'a> {
+    pub openapi: &'a OpenAPI,
+    /// Resolver with its dependency graph built, used to break reference cycles
+    pub resolver: &'a SchemaResolver,
+    /// Module the file is generated for (`common` for shared schemas)
+    pub module_name: &'a str,
+    pub schema_names: &'a [String],
+    pub common_schemas: &'a [String],
+    /// Schemas owned by each module, to import factories of other modules
+    pub module_schemas: &'a HashMap<String, Vec<String>>,
+    /// Import path of the module's schemas from the factories file
+    pub schemas_import: &'a str,
+}
+
+/// Generate `{module}.ts` with a `build{Schema}(overrides?)` function per schema. Values
+/// come from the schema's `example` or `default`, otherwise are drawn from the seeded
+/// helpers within its enum, format and min/max constraints. References that would lead
+/// back to the schema being built are left out when optional, and end the recursion with
+/// `[]` or `null` otherwise.
+pub fn generate_factories(context: &FactoriesContext) -> String {
+    let mut builder = FactoryBuilder {
+        context,
+        current: String::new(),
+        helpers: BTreeSet::new(),
+        imports: BTreeMap::new(),
+        uses_types: false,
+    };
+
+    let mut schema_names: Vec<&String> = context.schema_names.iter().collect();
+    schema_names.sort();
+    schema_names.dedup();
+
+    let mut functions = Vec::new();
+    for name in schema_names {
+        let schema = match context
+            .openapi
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.get(name))
+        {
+            Some(ReferenceOr::Item(schema)) => schema.clone(),
+            Some(ReferenceOr::Reference { reference }) => {
+                match resolve_ref(context.openapi, reference)
+                    .ok()
+                    .and_then(|schema| schema.into_item())
+                {
+                    Some(schema) => schema,
+                    None => continue,
+                }
+            }
+            None => continue,
+        };
+        builder.current = name.clone();
+        functions.push(builder.function(name, &schema));
+    }
+
+    let mut content = String::new();
+    if builder.uses_types {
+        content.push_str(&format!(
+            "import type * as {} from \"{}\";\n",
+            namespace(context.module_name),
+            context.schemas_import
+        ));
+    }
+    for (module, names) in &builder.imports {
+        content.push_str(&format!(
+            "import {{ {} }} from \"{}\";\n",
+            names.iter().cloned().collect::<Vec<_>>().join(", "),
+            relative_module(context.module_name, module)
+        ));
+    }
+    if !builder.helpers.is_empty() {
+        content.push_str(&format!(
+            "import {{ {} }} from \"{}\";\n",
+            builder
+                .helpers
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", "),
+            relative_module(context.module_name, "random")
+        ));
+    }
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content.push_str(&functions.join("\n\n"));
+    content.push('\n');
+    content
+}
+
+/// Name of the factory function of a component schema.
+pub fn factory_name(schema_name: &str) -> String {
+    format!("build{}", to_pascal_case(schema_name))
+}
+
+/// Import path of a module's schemas from its factories file
+/// (`{factories_dir}/{module}.ts` -> `{schemas_dir}/{module}`).
+pub fn schemas_import_path(factories_dir: &str, schemas_dir: &str, module_name: &str) -> String {
+    let module = sanitize_module_name(module_name);
+    let segments = |path: &str| -> Vec<String> {
+        path.split('/')
+            .filter(|part| !part.is_empty() && *part != ".")
+            .map(|part| part.to_string())
+            .collect()
+    };
+    let mut from = segments(factories_dir);
+    let mut module_parts = segments(&module);
+    module_parts.pop();
+    from.extend(module_parts);
+    let mut to = segments(schemas_dir);
+    to.extend(segments(&module));
+    relative_path(&from, &to)
+}
+
+/// Import path of the factories file of `to_module` from the one of `from_module`.
+fn relative_module(from_module: &str, to_module: &str) -> String {
+    let mut from: Vec<String> = sanitize_module_name(from_module)
+        .split('/')
+        .map(|part| part.to_string())
+        .collect();
+    from.pop();
+    let to: Vec<String> = sanitize_module_name(to_module)
+        .split('/')
+        .map(|part| part.to_string())
+        .collect();
+    relative_path(&from, &to)
+}
+
+fn relative_path(from: &[String], to: &[String]) -> String {
+    let common = from
+        .iter()
+        .zip(to)
+        .take_while(|(from, to)| from == to)
+        .count();
+    let ups = from.len() - common;
+    let prefix = if ups == 0 {
+        "./".to_string()
+    } else {
+        "../".repeat(ups)
+    };
+    format!("{}{}", prefix, to[common..].join("/"))
+}
+
+/// Namespace the module's schemas are imported under (matches the API clients).
+fn namespace(module_name: &str) -> String {
+    if module_name == FACTORIES_COMMON_MODULE {
+        "Common".to_string()
+    } else {
+        to_pascal_case(&module_name.replace('/', "_"))
+    }
+}
+
+struct FactoryBuilder<'a> {
+    context: &'a FactoriesContext<'a>,
+    /// Component whose factory is being generated
+    current: String,
+    /// Helpers imported from `random.ts`
+    helpers: BTreeSet<&'static str>,
+    /// Factories imported from other modules, by module
+    imports: BTreeMap<String, BTreeSet<String>>,
+    /// Whether a factory is annotated with the module's types
+    uses_types: bool,
+}
+
+impl FactoryBuilder<'_> {
+    fn function(&mut self, name: &str, schema: &Schema) -> String {
+        let function_name = factory_name(name);
+        if let SchemaKind::Type(Type::Object(object)) = &schema.schema_kind {
+            if !object.properties.is_empty() {
+                self.uses_types = true;
+                let type_name = format!(
+                    "{}.{}",
+                    namespace(self.context.module_name),
+                    to_pascal_case(name)
+                );
+                let mut entries = match example_of(schema) {
+                    Some(Value::Object(example)) => example
+                        .iter()
+                        .map(|(key, value)| {
+                            format!("{}: {}", sanitize_property_name(key), literal(value, 2))
+                        })
+                        .collect(),
+                    _ => self.object_entries(object, 2),
+                };
+                entries.push("...overrides".to_string());
+                return format!(
+                    "/**\n * Build a valid `{}`; `overrides` replace the generated fields.\n */\nexport function {}(overrides: Partial<{}> = {{}}): {} {{\n  return {};\n}}",
+                    to_pascal_case(name),
+                    function_name,
+                    type_name,
+                    type_name,
+                    object_literal(&entries, 1)
+                );
+            }
+        }
+
+        let value = self
+            .schema(schema, name, 1)
+            .unwrap_or_else(|| "null".to_string());
+        format!(
+            "/**\n * Build a valid `{}` value.\n */\nexport function {}() {{\n  return {};\n}}",
+            to_pascal_case(name),
+            function_name,
+            value
+        )
+    }
+
+    /// Expression building a value of `schema`. `None` when it refers back to the schema
+    /// whose factory is being generated.
+    fn value(&mut self, schema: &ReferenceOr<Schema>, name: &str, indent: usize) -> Option<String> {
+        match schema {
+            ReferenceOr::Reference { reference } => self.reference(reference),
+            ReferenceOr::Item(schema) => self.schema(schema, name, indent),
+        }
+    }
+
+    fn boxed(
+        &mut self,
+        schema: &ReferenceOr<Box<Schema>>,
+        name: &str,
+        indent: usize,
+    ) -> Option<String> {
+        match schema {
+            ReferenceOr::Reference { reference } => self.reference(reference),
+            ReferenceOr::Item(schema) => self.schema(schema, name, indent),
+        }
+    }
+
+    fn reference(&mut self, reference: &str) -> Option<String> {
+        let target = get_schema_name_from_ref(reference)?;
+        if self.context.resolver.closes_cycle(&self.current, &target) {
+            return None;
+        }
+        let module = self.module_of(&target);
+        if module != sanitize_module_name(self.context.module_name) {
+            self.imports
+                .entry(module)
+                .or_default()
+                .insert(factory_name(&target));
+        }
+        Some(format!("{}()", factory_name(&target)))
+    }
+
+    /// Factories file defining a schema's factory: common, the current module, or the
+    /// first module owning it.
+    fn module_of(&self, schema_name: &str) -> String {
+        if self.context.common_schemas.iter().any(|s| s == schema_name) {
+            return FACTORIES_COMMON_MODULE.to_string();
+        }
+        let owns = |module: &str| {
+            self.context
+                .module_schemas
+                .get(module)
+                .map(|names| names.iter().any(|s| s == schema_name))
+                .unwrap_or(false)
+        };
+        if owns(self.context.module_name) {
+            return sanitize_module_name(self.context.module_name);
+        }
+        let mut modules: Vec<&String> = self.context.module_schemas.keys().collect();
+        modules.sort();
+        modules
+            .into_iter()
+            .find(|module| owns(module))
+            .map(|module| sanitize_module_name(module))
+            .unwrap_or_else(|| sanitize_module_name(self.context.module_name))
+    }
+
+    fn schema(&mut self, schema: &Schema, name: &str, indent: usize) -> Option<String> {
+        if let Some(example) = example_of(schema) {
+            return Some(literal(example, indent));
+        }
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) => Some(self.string(string, name)),
+            SchemaKind::Type(Type::Integer(integer)) => Some(self.integer(integer)),
+            SchemaKind::Type(Type::Number(number)) => Some(self.number(number)),
+            SchemaKind::Type(Type::Boolean(_)) => {
+                self.helpers.insert("randomBoolean");
+                Some("randomBoolean()".to_string())
+            }
+            SchemaKind::Type(Type::Array(array)) => Some(self.array(array, name, indent)),
+            SchemaKind::Type(Type::Object(object)) => {
+                let entries = self.object_entries(object, indent + 1);
+                Some(object_literal(&entries, indent))
+            }
+            SchemaKind::OneOf { one_of: variants } | SchemaKind::AnyOf { any_of: variants } => {
+                variants
+                    .iter()
+                    .find_map(|variant| self.value(variant, name, indent))
+            }
+            SchemaKind::AllOf { all_of } => {
+                if all_of.len() == 1 {
+                    return self.value(&all_of[0], name, indent);
+                }
+                let mut entries = Vec::new();
+                for part in all_of {
+                    match part {
+                        ReferenceOr::Reference { reference } => {
+                            if let Some(value) = self.reference(reference) {
+                                entries.push(format!("...{}", value));
+                            }
+                        }
+                        ReferenceOr::Item(part) => match &part.schema_kind {
+                            SchemaKind::Type(Type::Object(object)) => {
+                                entries.extend(self.object_entries(object, indent + 1));
+                            }
+                            _ => {
+                                if let Some(value) = self.schema(part, name, indent + 1) {
+                                    entries.push(format!("...{}", value));
+                                }
+                            }
+                        },
+                    }
+                }
+                Some(object_literal(&entries, indent))
+            }
+            SchemaKind::Not { .. } => Some("null".to_string()),
+            SchemaKind::Any(any) => {
+                if !any.properties.is_empty() {
+                    let object = ObjectType {
+                        properties: any.properties.clone(),
+                        required: any.required.clone(),
+                        ..Default::default()
+                    };
+                    let entries = self.object_entries(&object, indent + 1);
+                    Some(object_literal(&entries, indent))
+                } else if let Some(variant) = any.one_of.first().or(any.any_of.first()) {
+                    self.value(variant, name, indent)
+                } else {
+                    Some("{}".to_string())
+                }
+            }
+        }
+    }
+
+    fn object_entries(&mut self, object: &ObjectType, indent: usize) -> Vec<String> {
+        let mut entries = Vec::new();
+        for (property, property_schema) in &object.properties {
+            let required = object.required.contains(property);
+            let value = match self.boxed(property_schema, property, indent) {
+                Some(value) => value,
+                // Optional back-references are left out to end the recursion
+                None if !required => continue,
+                None if self.nullable(property_schema) => "null".to_string(),
+                None => "undefined as never".to_string(),
+            };
+            entries.push(format!("{}: {}", sanitize_property_name(property), value));
+        }
+        entries
+    }
+
+    fn nullable(&self, schema: &ReferenceOr<Box<Schema>>) -> bool {
+        match schema {
+            ReferenceOr::Item(schema) => schema.schema_data.nullable,
+            ReferenceOr::Reference { reference } => resolve_ref(self.context.openapi, reference)
+                .ok()
+                .and_then(|schema| schema.into_item())
+                .map(|schema| schema.schema_data.nullable)
+                .unwrap_or(false),
+        }
+    }
+
+    fn string(&mut self, string: &StringType, name: &str) -> String {
+        let values: Vec<&String> = string.enumeration.iter().flatten().collect();
+        if !values.is_empty() {
+            return self.pick(values.iter().map(|value| format!("{:?}", value)).collect());
+        }
+        let helper = match &string.format {
+            VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => Some("randomDateTime"),
+            VariantOrUnknownOrEmpty::Item(StringFormat::Date) => Some("randomDate"),
+            VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+                "uuid" => Some("randomUuid"),
+                "email" => Some("randomEmail"),
+                "uri" | "url" => Some("randomUrl"),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(helper) = helper {
+            self.helpers.insert(helper);
+            return format!("{}()", helper);
+        }
+
+        self.helpers.insert("randomString");
+        let prefix = if name.is_empty() { "value" } else { name };
+        let mut arguments = vec![format!("{:?}", prefix)];
+        match (string.min_length, string.max_length) {
+            (Some(min), Some(max)) => arguments.extend([min.to_string(), max.to_string()]),
+            (Some(min), None) => arguments.push(min.to_string()),
+            (None, Some(max)) => arguments.extend(["0".to_string(), max.to_string()]),
+            (None, None) => {}
+        }
+        format!("randomString({})", arguments.join(", "))
+    }
+
+    fn integer(&mut self, integer: &IntegerType) -> String {
+        let values: Vec<String> = integer
+            .enumeration
+            .iter()
+            .flatten()
+            .map(|value| value.to_string())
+            .collect();
+        if !values.is_empty() {
+            return self.pick(values);
+        }
+        let min = integer.minimum.map(|min| {
+            if integer.exclusive_minimum {
+                min + 1
+            } else {
+                min
+            }
+        });
+        let max = integer.maximum.map(|max| {
+            if integer.exclusive_maximum {
+                max - 1
+            } else {
+                max
+            }
+        });
+        let (min, max) = range(min, max);
+        self.helpers.insert("randomInt");
+        format!("randomInt({}, {})", min, max)
+    }
+
+    fn number(&mut self, number: &NumberType) -> String {
+        let values: Vec<String> = number
+            .enumeration
+            .iter()
+            .flatten()
+            .map(|value| format_number(*value))
+            .collect();
+        if !values.is_empty() {
+            return self.pick(values);
+        }
+        let (min, max) = match (number.minimum, number.maximum) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min + DEFAULT_RANGE as f64),
+            (None, Some(max)) => (max.min(1.0) - DEFAULT_RANGE as f64, max),
+            (None, None) => (1.0, DEFAULT_RANGE as f64),
+        };
+        self.helpers.insert("randomNumber");
+        format!(
+            "randomNumber({}, {})",
+            format_number(min),
+            format_number(max)
+        )
+    }
+
+    fn array(&mut self, array: &ArrayType, name: &str, indent: usize) -> String {
+        let Some(item) = array
+            .items
+            .as_ref()
+            .and_then(|items| self.boxed(items, name, indent))
+        else {
+            return "[]".to_string();
+        };
+        let min = array.min_items.unwrap_or(1);
+        let max = array
+            .max_items
+            .unwrap_or(min.max(DEFAULT_MAX_ITEMS))
+            .max(min);
+        let length = if min == max {
+            min.to_string()
+        } else {
+            self.helpers.insert("randomInt");
+            format!("randomInt({}, {})", min, max)
+        };
+        // Object literals returned by arrow functions need parentheses
+        let item = if item.starts_with('{') {
+            format!("({})", item)
+        } else {
+            item
+        };
+        format!("Array.from({{ length: {} }}, () => {})", length, item)
+    }
+
+    fn pick(&mut self, values: Vec<String>) -> String {
+        if values.len() == 1 {
+            return values[0].clone();
+        }
+        self.helpers.insert("pick");
+        format!("pick([{}] as const)", values.join(", "))
+    }
+}
+
+/// Inclusive integer range from optional bounds.
+fn range(min: Option<i64>, max: Option<i64>) -> (i64, i64) {
+    match (min, max) {
+        (Some(min), Some(max)) => (min, max.max(min)),
+        (Some(min), None) => (min, min + DEFAULT_RANGE),
+        (None, Some(max)) => (max.min(1) - DEFAULT_RANGE, max),
+        (None, None) => (1, DEFAULT_RANGE),
+    }
+}
+
+fn example_of(schema: &Schema) -> Option<&Value> {
+    schema
+        .schema_data
+        .example
+        .as_ref()
+        .or(schema.schema_data.default.as_ref())
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
+
+/// TypeScript literal of a JSON value, with objects spread over lines at `indent`.
+fn literal(value: &Value, indent: usize) -> String {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "{}: {}",
+                        sanitize_property_name(key),
+                        literal(value, indent + 1)
+                    )
+                })
+                .collect();
+            object_literal(&entries, indent)
+        }
+        Value::Array(items) if !items.is_empty() => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(|item| literal(item, indent))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+fn object_literal(entries: &[String], indent: usize) -> String {
+    if entries.is_empty() {
+        return "{}".to_string();
+    }
+    let padding = "  ".repeat(indent + 1);
+    format!(
+        "{{\n{}\n{}}}",
+        entries
+            .iter()
+            .map(|entry| format!("{}{},", padding, entry))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "  ".repeat(indent)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemas_import_path() {
+        assert_eq!(
+            schemas_import_path("src/factories", "src/schemas", "orders"),
+            "../schemas/orders"
+        );
+        assert_eq!(
+            schemas_import_path("./src/factories", "src/schemas", "tenant/auth"),
+            "../../schemas/tenant/auth"
+        );
+        assert_eq!(
+            schemas_import_path("src/schemas/factories", "src/schemas", "orders"),
+            "../orders"
+        );
+    }
+
+    #[test]
+    fn test_relative_module() {
+        assert_eq!(relative_module("orders", "random"), "./random");
+        assert_eq!(relative_module("tenant/auth", "common"), "../common");
+        assert_eq!(relative_module("tenant/auth", "tenant/users"), "./users");
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range(None, None), (1, 1000));
+        assert_eq!(range(Some(5), None), (5, 1005));
+        assert_eq!(range(None, Some(-3)), (-1003, -3));
+        assert_eq!(range(Some(10), Some(2)), (10, 10));
+    }
+}
@@ -20,11 +20,13 @@ pub struct HookContext {
     pub query_keys_import_path: String,
     pub param_list: String, // Full parameter list with types: "id: string, query?: { page?: number }"
     pub param_names: String, // Just parameter names for function calls: "id, query"
+    pub params_type: String, // "{Operation}Params" with the object signature, empty otherwise
     pub path_param_names: String, // Just path parameter names: "id"
     pub schema_imports: String, // Schema import statements
     pub description: String,
-    pub success_map_type: String, // e.g., "OrdersControllerCreateResponses"
-    pub error_map_type: String,   // e.g., "OrdersControllerCreateErrors"
+    pub deprecated: Option<String>, // "@deprecated ..." JSDoc tag of a deprecated operation
+    pub success_map_type: String,   // e.g., "OrdersControllerCreateResponses"
+    pub error_map_type: String,     // e.g., "OrdersControllerCreateErrors"
     pub generic_result_type: String, // e.g., "ApiResult<OrdersControllerCreateResponses, OrdersControllerCreateErrors>"
     pub import_runtime_path: String, // Path to runtime types/client
 }
@@ -1,12 +1,15 @@
+use crate::config::model::ApisConfig;
 use crate::error::Result;
 use crate::generator::api_client::{
-    extract_all_responses, extract_path_parameters, extract_query_parameters, extract_request_body,
-    ResponseInfo,
+    extract_all_responses, extract_header_parameters, extract_path_parameters,
+    extract_query_parameters, extract_request_body, primary_success_response, ResponseInfo,
 };
+use crate::generator::deprecation::operation_deprecation;
 use crate::generator::hooks::context::HookContext;
 use crate::generator::hooks::HookFile;
+use crate::generator::streaming::detect_stream;
 use crate::generator::swagger_parser::OperationInfo;
-use crate::generator::utils::{to_camel_case, to_pascal_case};
+use crate::generator::utils::{parameter_identifier, sanitize_property_name, to_pascal_case};
 use crate::templates::context::Parameter as ApiParameter;
 use crate::templates::engine::TemplateEngine;
 use crate::templates::registry::TemplateId;
@@ -26,6 +29,7 @@ pub fn generate_react_query_hooks(
     schemas_dir: Option<&str>,
     hooks_dir: Option<&str>,
     query_keys_dir: Option<&str>,
+    apis_config: &ApisConfig,
 ) -> Result<Vec<HookFile>> {
     let mut hooks = Vec::new();
 
@@ -41,12 +45,13 @@ pub fn generate_react_query_hooks(
             continue; // Skip unsupported methods
         }
 
-        // Generate operation ID (function name)
-        let operation_id = if let Some(op_id) = &operation.operation_id {
-            to_camel_case(op_id)
-        } else {
-            generate_hook_name_from_path(&op_info.path, &op_info.method)
-        };
+        // Streaming functions return async generators, which query hooks cannot cache
+        if apis_config.style == "fetch" && detect_stream(openapi, operation).is_some() {
+            continue;
+        }
+
+        // Name of the API function the hook calls
+        let operation_id = op_info.function_name.clone();
 
         // Generate hook name
         let hook_name = format!("use{}", to_pascal_case(&operation_id));
@@ -71,9 +76,7 @@ pub fn generate_react_query_hooks(
             .filter(|r| r.status_code < 200 || r.status_code >= 300)
             .cloned()
             .collect();
-        let response_type = success_responses
-            .iter()
-            .find(|r| r.status_code == 200)
+        let response_type = primary_success_response(&success_responses)
             .map(|r| r.body_type.clone())
             .unwrap_or_else(|| "any".to_string());
 
@@ -81,7 +84,14 @@ pub fn generate_react_query_hooks(
         let type_name_base = to_pascal_case(&operation_id);
         let success_map_type = format!("{}Responses", type_name_base);
         let error_map_type = format!("{}Errors", type_name_base);
-        let generic_result_type = format!("ApiResult<{}, {}>", success_map_type, error_map_type);
+        // Throwing functions resolve to the success body itself
+        let generic_result_type = if apis_config.error_mode != "throw" {
+            format!("ApiResult<{}, {}>", success_map_type, error_map_type)
+        } else if success_responses.iter().any(|r| r.body_type != "any") {
+            format!("{}[keyof {}]", success_map_type, success_map_type)
+        } else {
+            "unknown".to_string()
+        };
 
         // Build parameter list for hook
         let mut param_list_parts = Vec::new();
@@ -97,8 +107,9 @@ pub fn generate_react_query_hooks(
                 crate::generator::api_client::ParameterType::Boolean => "boolean".to_string(),
                 crate::generator::api_client::ParameterType::Array(_) => "string".to_string(),
             };
-            param_list_parts.push(format!("{}: {}", param.name, param_type));
-            param_names_parts.push(param.name.clone());
+            let identifier = parameter_identifier(&param.name);
+            param_list_parts.push(format!("{}: {}", identifier, param_type));
+            param_names_parts.push(identifier);
         }
 
         // Collect enum types from query parameters for imports
@@ -124,7 +135,11 @@ pub fn generate_react_query_hooks(
                     crate::generator::api_client::ParameterType::Integer => "number".to_string(),
                     crate::generator::api_client::ParameterType::Boolean => "boolean".to_string(),
                 };
-                query_fields.push(format!("{}?: {}", param.name, param_type));
+                query_fields.push(format!(
+                    "{}?: {}",
+                    sanitize_property_name(&param.name),
+                    param_type
+                ));
             }
             let query_type = format!("{{ {} }}", query_fields.join(", "));
             param_list_parts.push(format!("query?: {}", query_type));
@@ -135,8 +150,34 @@ pub fn generate_react_query_hooks(
         // Body parameter is passed via mutate(data) call, not as hook parameter
         // Only path parameters should be in the hook signature
 
-        let param_list = param_list_parts.join(", ");
-        let param_names = param_names_parts.join(", ");
+        let mut param_list = param_list_parts.join(", ");
+        let mut param_names = param_names_parts.join(", ");
+
+        // Object signature: API functions take one `{Operation}Params` object. Query hooks
+        // take the same object, mutation hooks receive it as the mutation variables.
+        let mut params_type = String::new();
+        if apis_config.signature == "object" {
+            let has_headers =
+                !extract_header_parameters(openapi, operation, enum_registry)?.is_empty();
+            if !path_params_info.is_empty()
+                || !query_params_info.is_empty()
+                || request_body_info.is_some()
+                || has_headers
+            {
+                params_type = format!("{}Params", type_name_base);
+            }
+            param_list = String::new();
+            param_names = String::new();
+            if is_query && !params_type.is_empty() {
+                let default_value = if path_params_info.is_empty() && request_body_info.is_none() {
+                    " = {}"
+                } else {
+                    ""
+                };
+                param_list = format!("params: {}{}", params_type, default_value);
+                param_names = "params".to_string();
+            }
+        }
 
         // Convert parameters to ApiParameter format
         let path_params: Vec<ApiParameter> = path_params_info
@@ -154,7 +195,12 @@ pub fn generate_react_query_hooks(
                     crate::generator::api_client::ParameterType::Integer => "number".to_string(),
                     crate::generator::api_client::ParameterType::Boolean => "boolean".to_string(),
                 };
-                ApiParameter::new(p.name.clone(), param_type, false, p.description.clone())
+                ApiParameter::new(
+                    parameter_identifier(&p.name),
+                    param_type,
+                    false,
+                    p.description.clone(),
+                )
             })
             .collect();
 
@@ -198,8 +244,10 @@ pub fn generate_react_query_hooks(
             .unwrap_or_default();
 
         // Build path parameter names (for mutations)
-        let path_param_names: Vec<String> =
-            path_params_info.iter().map(|p| p.name.clone()).collect();
+        let path_param_names: Vec<String> = path_params_info
+            .iter()
+            .map(|p| parameter_identifier(&p.name))
+            .collect();
         let path_param_names_str = path_param_names.join(", ");
 
         // Generate schema imports
@@ -370,9 +418,11 @@ pub fn generate_react_query_hooks(
             ),
             param_list,
             param_names,
+            params_type,
             path_param_names: path_param_names_str,
             schema_imports,
             description,
+            deprecated: operation_deprecation(&op_info.operation),
             success_map_type,
             error_map_type,
             generic_result_type,
@@ -396,45 +446,3 @@ pub fn generate_react_query_hooks(
 
     Ok(hooks)
 }
-
-/// Generate hook name from path and method (fallback when operation_id is missing).
-fn generate_hook_name_from_path(path: &str, method: &str) -> String {
-    let path_parts: Vec<&str> = path
-        .trim_start_matches('/')
-        .split('/')
-        .filter(|p| !p.starts_with('{'))
-        .collect();
-
-    let method_upper = method.to_uppercase();
-    let method_prefix = match method_upper.as_str() {
-        "GET" => "get",
-        "POST" => "create",
-        "PUT" => "update",
-        "DELETE" => "delete",
-        "PATCH" => "patch",
-        _ => {
-            return to_camel_case(&method.to_lowercase());
-        }
-    };
-
-    let base_name = if path_parts.is_empty() {
-        method_prefix.to_string()
-    } else {
-        let resource_name = if path_parts.len() > 1 {
-            path_parts.last().unwrap_or(&"")
-        } else {
-            path_parts.first().unwrap_or(&"")
-        };
-
-        if resource_name.ends_with('s') && path.contains('{') {
-            let singular = &resource_name[..resource_name.len() - 1];
-            format!("{}{}ById", method_prefix, to_pascal_case(singular))
-        } else if path.contains('{') {
-            format!("{}{}ById", method_prefix, to_pascal_case(resource_name))
-        } else {
-            format!("{}{}", method_prefix, to_pascal_case(resource_name))
-        }
-    };
-
-    to_camel_case(&base_name)
-}
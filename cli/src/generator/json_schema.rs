@@ -0,0 +1,330 @@
+use crate::error::{GenerationError, Result};
+use crate::generator::swagger_parser::get_schema_name_from_ref;
+use crate::generator::utils::{sanitize_module_name, to_pascal_case};
+use openapiv3::OpenAPI;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A JSON Schema document for a single component schema.
+pub struct JsonSchemaFile {
+    /// File name relative to the module directory (e.g. `User.schema.json`)
+    pub file_name: String,
+    pub content: Value,
+}
+
+/// Returns the file name used for a component's JSON Schema document.
+pub fn json_schema_file_name(schema_name: &str) -> String {
+    format!("{}.schema.json", to_pascal_case(schema_name))
+}
+
+/// Generate JSON Schema (draft 2020-12) documents for the given component schemas.
+///
+/// `$ref`s to other components are rewritten to relative file paths. Schemas listed in
+/// `common_schemas` live in the shared `common` folder, module schemas are looked up in
+/// `module_schemas` (falling back to the current module).
+pub fn generate_json_schemas(
+    openapi: &OpenAPI,
+    module_name: &str,
+    schema_names: &[String],
+    common_schemas: &[String],
+    module_schemas: &HashMap<String, Vec<String>>,
+) -> Result<Vec<JsonSchemaFile>> {
+    let components = match &openapi.components {
+        Some(components) => components,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut sorted_names: Vec<&String> = schema_names.iter().collect();
+    sorted_names.sort();
+    sorted_names.dedup();
+
+    let mut files = Vec::new();
+    for schema_name in sorted_names {
+        let Some(schema_ref) = components.schemas.get(schema_name) else {
+            continue;
+        };
+        let raw =
+            serde_json::to_value(schema_ref).map_err(|e| GenerationError::InvalidOperation {
+                message: format!(
+                    "Failed to serialize schema '{}' to JSON Schema: {}",
+                    schema_name, e
+                ),
+            })?;
+
+        let resolver = RefResolver {
+            module_name,
+            common_schemas,
+            module_schemas,
+        };
+        let mut normalized = normalize(raw, &resolver);
+
+        let mut document = Map::new();
+        document.insert(
+            "$schema".to_string(),
+            Value::String(JSON_SCHEMA_DIALECT.to_string()),
+        );
+        document.insert(
+            "$id".to_string(),
+            Value::String(json_schema_file_name(schema_name)),
+        );
+        if let Value::Object(ref mut object) = normalized {
+            if !object.contains_key("title") {
+                document.insert("title".to_string(), Value::String(schema_name.clone()));
+            }
+            document.append(object);
+        } else {
+            document.insert("allOf".to_string(), Value::Array(vec![normalized]));
+        }
+
+        files.push(JsonSchemaFile {
+            file_name: json_schema_file_name(schema_name),
+            content: Value::Object(document),
+        });
+    }
+
+    Ok(files)
+}
+
+struct RefResolver<'a> {
+    module_name: &'a str,
+    common_schemas: &'a [String],
+    module_schemas: &'a HashMap<String, Vec<String>>,
+}
+
+impl RefResolver<'_> {
+    /// Directory (relative to the schemas output) holding the given component.
+    fn directory_for(&self, schema_name: &str) -> String {
+        if self.common_schemas.iter().any(|s| s == schema_name) {
+            return "common".to_string();
+        }
+        let owns = |module: &str| {
+            self.module_schemas
+                .get(module)
+                .map(|names| names.iter().any(|s| s == schema_name))
+                .unwrap_or(false)
+        };
+        if owns(self.module_name) {
+            return sanitize_module_name(self.module_name);
+        }
+        let mut modules: Vec<&String> = self.module_schemas.keys().collect();
+        modules.sort();
+        modules
+            .into_iter()
+            .find(|module| owns(module))
+            .map(|module| sanitize_module_name(module))
+            .unwrap_or_else(|| sanitize_module_name(self.module_name))
+    }
+
+    fn relative_ref(&self, schema_name: &str) -> String {
+        let current_dir = sanitize_module_name(self.module_name);
+        let target_dir = self.directory_for(schema_name);
+        let file_name = json_schema_file_name(schema_name);
+        if target_dir == current_dir {
+            format!("./{}", file_name)
+        } else {
+            let depth = current_dir.matches('/').count() + 1;
+            format!("{}{}/{}", "../".repeat(depth), target_dir, file_name)
+        }
+    }
+}
+
+/// Convert OpenAPI 3.0 schema keywords into their JSON Schema 2020-12 equivalents.
+fn normalize(value: Value, resolver: &RefResolver) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| normalize(item, resolver))
+                .collect(),
+        ),
+        Value::Object(object) => normalize_object(object, resolver),
+        other => other,
+    }
+}
+
+fn normalize_object(object: Map<String, Value>, resolver: &RefResolver) -> Value {
+    let mut result = Map::new();
+    for (key, value) in object {
+        match key.as_str() {
+            // `properties` / `patternProperties` keys are property names, not keywords
+            "properties" | "patternProperties" | "$defs" => {
+                let normalized = match value {
+                    Value::Object(entries) => Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(name, schema)| (name, normalize(schema, resolver)))
+                            .collect(),
+                    ),
+                    other => other,
+                };
+                result.insert(key, normalized);
+            }
+            "$ref" => {
+                let rewritten = match value.as_str().and_then(get_schema_name_from_ref) {
+                    Some(schema_name) => Value::String(resolver.relative_ref(&schema_name)),
+                    None => value,
+                };
+                result.insert(key, rewritten);
+            }
+            "example" => {
+                result.insert("examples".to_string(), Value::Array(vec![value]));
+            }
+            // Instance data, not subschemas
+            "enum" | "default" | "const" | "examples" => {
+                result.insert(key, value);
+            }
+            // OpenAPI-only keywords without a JSON Schema equivalent
+            "discriminator" | "xml" | "externalDocs" => {}
+            _ => {
+                result.insert(key, normalize(value, resolver));
+            }
+        }
+    }
+
+    // exclusiveMinimum/exclusiveMaximum are booleans in OpenAPI 3.0 and numbers in 2020-12
+    for (exclusive, bound) in [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(Value::Bool(is_exclusive)) = result.get(exclusive).cloned() {
+            result.remove(exclusive);
+            if is_exclusive {
+                if let Some(limit) = result.remove(bound) {
+                    result.insert(exclusive.to_string(), limit);
+                }
+            }
+        }
+    }
+
+    // nullable: true -> add "null" to the type (or allow null alongside the schema)
+    if let Some(Value::Bool(nullable)) = result.remove("nullable") {
+        if nullable {
+            if let Some(Value::Array(values)) = result.get_mut("enum") {
+                if !values.contains(&Value::Null) {
+                    values.push(Value::Null);
+                }
+            }
+            match result.remove("type") {
+                Some(Value::String(type_name)) => {
+                    result.insert(
+                        "type".to_string(),
+                        Value::Array(vec![
+                            Value::String(type_name),
+                            Value::String("null".to_string()),
+                        ]),
+                    );
+                }
+                Some(other) => {
+                    result.insert("type".to_string(), other);
+                }
+                None => {
+                    let mut null_schema = Map::new();
+                    null_schema.insert("type".to_string(), Value::String("null".to_string()));
+                    let inner = std::mem::take(&mut result);
+                    result.insert(
+                        "anyOf".to_string(),
+                        Value::Array(vec![Value::Object(inner), Value::Object(null_schema)]),
+                    );
+                }
+            }
+        }
+    }
+
+    Value::Object(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_openapi(json: &str) -> OpenAPI {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const SPEC: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "paths": {},
+        "components": {
+            "schemas": {
+                "User": {
+                    "type": "object",
+                    "required": ["id"],
+                    "properties": {
+                        "id": {"type": "integer", "minimum": 0, "exclusiveMinimum": true},
+                        "nickname": {"type": "string", "nullable": true, "example": "neo"},
+                        "address": {"$ref": "#/components/schemas/Address"},
+                        "error": {"$ref": "#/components/schemas/Error"}
+                    }
+                },
+                "Address": {
+                    "type": "object",
+                    "properties": {"city": {"type": "string"}}
+                },
+                "Error": {
+                    "type": "object",
+                    "properties": {"message": {"type": "string"}}
+                }
+            }
+        }
+    }"##;
+
+    #[test]
+    fn test_generate_json_schema_normalizes_keywords() {
+        let openapi = parse_openapi(SPEC);
+        let mut module_schemas = HashMap::new();
+        module_schemas.insert(
+            "users".to_string(),
+            vec!["User".to_string(), "Address".to_string()],
+        );
+        let files = generate_json_schemas(
+            &openapi,
+            "users",
+            &["User".to_string()],
+            &["Error".to_string()],
+            &module_schemas,
+        )
+        .unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name, "User.schema.json");
+        let schema = &files[0].content;
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(schema["title"], "User");
+        let properties = &schema["properties"];
+        assert_eq!(properties["id"]["exclusiveMinimum"], 0);
+        assert!(properties["id"].get("minimum").is_none());
+        assert_eq!(
+            properties["nickname"]["type"],
+            serde_json::json!(["string", "null"])
+        );
+        assert_eq!(
+            properties["nickname"]["examples"],
+            serde_json::json!(["neo"])
+        );
+        assert_eq!(properties["address"]["$ref"], "./Address.schema.json");
+        assert_eq!(properties["error"]["$ref"], "../common/Error.schema.json");
+    }
+
+    #[test]
+    fn test_generate_json_schema_refs_from_common() {
+        let openapi = parse_openapi(SPEC);
+        let files = generate_json_schemas(
+            &openapi,
+            "common",
+            &["User".to_string()],
+            &[
+                "User".to_string(),
+                "Address".to_string(),
+                "Error".to_string(),
+            ],
+            &HashMap::new(),
+        )
+        .unwrap();
+
+        let properties = &files[0].content["properties"];
+        assert_eq!(properties["address"]["$ref"], "./Address.schema.json");
+    }
+}
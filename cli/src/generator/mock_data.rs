@@ -0,0 +1,332 @@
+use crate::generator::swagger_parser::{get_schema_name_from_ref, resolve_ref};
+use openapiv3::{
+    AdditionalProperties, Example, MediaType, OpenAPI, ReferenceOr, Schema, SchemaKind,
+    StringFormat, Type, VariantOrUnknownOrEmpty,
+};
+use serde_json::{json, Map, Value};
+
+/// Nesting depth after which objects and arrays are left empty.
+const MAX_DEPTH: usize = 6;
+
+/// Example value of a media type: its `example`, the first of its `examples`, or a
+/// value derived from its schema. Mock data is deterministic so generated handlers and
+/// snapshots stay stable between runs.
+pub fn media_type_value(openapi: &OpenAPI, media: &MediaType) -> Option<Value> {
+    if let Some(example) = &media.example {
+        return Some(example.clone());
+    }
+    let examples = media
+        .examples
+        .values()
+        .find_map(|example| example_value(openapi, example));
+    examples.or_else(|| {
+        media
+            .schema
+            .as_ref()
+            .map(|schema| schema_value(openapi, schema))
+    })
+}
+
+/// Value of the media type example called `name` in its `examples` map.
+pub fn named_example_value(openapi: &OpenAPI, media: &MediaType, name: &str) -> Option<Value> {
+    media
+        .examples
+        .get(name)
+        .and_then(|example| example_value(openapi, example))
+}
+
+fn example_value(openapi: &OpenAPI, example: &ReferenceOr<Example>) -> Option<Value> {
+    match example {
+        ReferenceOr::Item(example) => example.value.clone(),
+        ReferenceOr::Reference { reference } => reference
+            .strip_prefix("#/components/examples/")
+            .and_then(|name| openapi.components.as_ref()?.examples.get(name))
+            .and_then(|example| example.as_item())
+            .and_then(|example| example.value.clone()),
+    }
+}
+
+/// Mock value of a schema, from its `example`, `default` or first `enum` value, otherwise
+/// derived from its type and format. Recursive references end with an empty value.
+pub fn schema_value(openapi: &OpenAPI, schema: &ReferenceOr<Schema>) -> Value {
+    MockBuilder {
+        openapi,
+        stack: Vec::new(),
+    }
+    .value(schema, "")
+    .unwrap_or(Value::Null)
+}
+
+struct MockBuilder<'a> {
+    openapi: &'a OpenAPI,
+    /// Component schemas being built, to stop at recursive references
+    stack: Vec<String>,
+}
+
+impl MockBuilder<'_> {
+    /// `None` when the schema refers back to a component that is being built.
+    fn value(&mut self, schema: &ReferenceOr<Schema>, name: &str) -> Option<Value> {
+        match schema {
+            ReferenceOr::Reference { reference } => {
+                let component = get_schema_name_from_ref(reference)?;
+                if self.stack.contains(&component) || self.stack.len() >= MAX_DEPTH {
+                    return None;
+                }
+                let resolved = resolve_ref(self.openapi, reference).ok()?.into_item()?;
+                self.stack.push(component);
+                let value = self.schema(&resolved, name);
+                self.stack.pop();
+                value
+            }
+            ReferenceOr::Item(schema) => self.schema(schema, name),
+        }
+    }
+
+    fn boxed(&mut self, schema: &ReferenceOr<Box<Schema>>, name: &str) -> Option<Value> {
+        match schema {
+            ReferenceOr::Reference { reference } => self.value(
+                &ReferenceOr::Reference {
+                    reference: reference.clone(),
+                },
+                name,
+            ),
+            ReferenceOr::Item(schema) => self.schema(schema, name),
+        }
+    }
+
+    fn schema(&mut self, schema: &Schema, name: &str) -> Option<Value> {
+        if let Some(example) = schema
+            .schema_data
+            .example
+            .as_ref()
+            .or(schema.schema_data.default.as_ref())
+        {
+            return Some(example.clone());
+        }
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) => {
+                if let Some(Some(first)) = string.enumeration.first() {
+                    return Some(json!(first));
+                }
+                let format = match &string.format {
+                    VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => "date-time",
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Date) => "date",
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Byte) => "byte",
+                    VariantOrUnknownOrEmpty::Unknown(format) => format.as_str(),
+                    _ => "",
+                };
+                Some(json!(string_value(format, name, string.min_length)))
+            }
+            SchemaKind::Type(Type::Integer(integer)) => Some(
+                integer
+                    .enumeration
+                    .first()
+                    .copied()
+                    .flatten()
+                    .or(integer.minimum.map(|min| min.max(1)))
+                    .map(|value| json!(value))
+                    .unwrap_or(json!(1)),
+            ),
+            SchemaKind::Type(Type::Number(number)) => Some(
+                number
+                    .enumeration
+                    .first()
+                    .copied()
+                    .flatten()
+                    .or(number.minimum.map(|min| min.max(1.0)))
+                    .map(|value| json!(value))
+                    .unwrap_or(json!(1.5)),
+            ),
+            SchemaKind::Type(Type::Boolean(_)) => Some(json!(true)),
+            SchemaKind::Type(Type::Array(array)) => {
+                if self.stack.len() >= MAX_DEPTH {
+                    return Some(json!([]));
+                }
+                let count = array.min_items.unwrap_or(1).max(1);
+                let item = array
+                    .items
+                    .as_ref()
+                    .and_then(|items| self.boxed(items, name));
+                Some(Value::Array(match item {
+                    Some(item) => vec![item; count],
+                    None => Vec::new(),
+                }))
+            }
+            SchemaKind::Type(Type::Object(object)) => {
+                let mut map = Map::new();
+                for (property, schema) in &object.properties {
+                    if let Some(value) = self.boxed(schema, property) {
+                        map.insert(property.clone(), value);
+                    }
+                }
+                if map.is_empty() {
+                    if let Some(AdditionalProperties::Schema(schema)) =
+                        &object.additional_properties
+                    {
+                        if let Some(value) = self.value(schema, "key") {
+                            map.insert("key".to_string(), value);
+                        }
+                    }
+                }
+                Some(Value::Object(map))
+            }
+            SchemaKind::OneOf { one_of: variants } | SchemaKind::AnyOf { any_of: variants } => {
+                variants
+                    .iter()
+                    .find_map(|variant| self.value(variant, name))
+            }
+            SchemaKind::AllOf { all_of } => {
+                let mut merged = Map::new();
+                for part in all_of {
+                    match self.value(part, name) {
+                        Some(Value::Object(fields)) => merged.extend(fields),
+                        Some(value) if all_of.len() == 1 => return Some(value),
+                        _ => {}
+                    }
+                }
+                Some(Value::Object(merged))
+            }
+            SchemaKind::Not { .. } => Some(Value::Null),
+            SchemaKind::Any(any) => {
+                if !any.properties.is_empty() {
+                    let mut map = Map::new();
+                    for (property, schema) in &any.properties {
+                        if let Some(value) = self.boxed(schema, property) {
+                            map.insert(property.clone(), value);
+                        }
+                    }
+                    Some(Value::Object(map))
+                } else if let Some(variant) = any.one_of.first().or(any.any_of.first()) {
+                    self.value(variant, name)
+                } else if let Some(value) = any.enumeration.first() {
+                    Some(value.clone())
+                } else {
+                    Some(match any.typ.as_deref() {
+                        Some("string") => {
+                            json!(string_value(
+                                any.format.as_deref().unwrap_or_default(),
+                                name,
+                                None
+                            ))
+                        }
+                        Some("integer") => json!(1),
+                        Some("number") => json!(1.5),
+                        Some("boolean") => json!(true),
+                        Some("array") => json!([]),
+                        _ => json!({}),
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// String value for a format, falling back to the property name.
+fn string_value(format: &str, name: &str, min_length: Option<usize>) -> String {
+    let value = match format {
+        "date-time" => "2024-01-01T00:00:00.000Z".to_string(),
+        "date" => "2024-01-01".to_string(),
+        "time" => "12:00:00".to_string(),
+        "email" => "user@example.com".to_string(),
+        "uuid" => "00000000-0000-4000-8000-000000000000".to_string(),
+        "uri" | "url" => "https://example.com".to_string(),
+        "hostname" => "example.com".to_string(),
+        "ipv4" => "127.0.0.1".to_string(),
+        "ipv6" => "::1".to_string(),
+        "byte" => "ZXhhbXBsZQ==".to_string(),
+        _ if name.is_empty() => "string".to_string(),
+        _ => name.to_string(),
+    };
+    match min_length {
+        Some(min) if value.len() < min => format!("{}{}", value, "x".repeat(min - value.len())),
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_openapi(schemas: &str) -> OpenAPI {
+        serde_json::from_str(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Test", "version": "1.0.0"}}, "paths": {{}},
+                "components": {{"schemas": {}}}}}"#,
+            schemas
+        ))
+        .unwrap()
+    }
+
+    fn component(name: &str) -> ReferenceOr<Schema> {
+        ReferenceOr::Reference {
+            reference: format!("#/components/schemas/{}", name),
+        }
+    }
+
+    #[test]
+    fn test_schema_value_from_types_and_formats() {
+        let openapi = parse_openapi(
+            r##"{
+                "Order": {"type": "object", "properties": {
+                    "id": {"type": "string", "format": "uuid"},
+                    "status": {"type": "string", "enum": ["pending", "paid"]},
+                    "total": {"type": "number", "minimum": 5},
+                    "quantity": {"type": "integer"},
+                    "note": {"type": "string", "example": "Leave at the door"},
+                    "createdAt": {"type": "string", "format": "date-time"},
+                    "tags": {"type": "array", "items": {"type": "string"}},
+                    "customer": {"$ref": "#/components/schemas/Customer"}
+                }},
+                "Customer": {"type": "object", "properties": {"email": {"type": "string", "format": "email"}, "name": {"type": "string"}}}
+            }"##,
+        );
+
+        assert_eq!(
+            schema_value(&openapi, &component("Order")),
+            json!({
+                "id": "00000000-0000-4000-8000-000000000000",
+                "status": "pending",
+                "total": 5.0,
+                "quantity": 1,
+                "note": "Leave at the door",
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "tags": ["tags"],
+                "customer": {"email": "user@example.com", "name": "name"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_schema_value_stops_at_recursive_references() {
+        let openapi = parse_openapi(
+            r##"{
+                "Category": {"type": "object", "properties": {
+                    "name": {"type": "string"},
+                    "parent": {"$ref": "#/components/schemas/Category"},
+                    "children": {"type": "array", "items": {"$ref": "#/components/schemas/Category"}}
+                }}
+            }"##,
+        );
+
+        assert_eq!(
+            schema_value(&openapi, &component("Category")),
+            json!({"name": "name", "children": []})
+        );
+    }
+
+    #[test]
+    fn test_media_type_value_prefers_examples() {
+        let openapi = parse_openapi(r#"{}"#);
+        let media: MediaType = serde_json::from_value(json!({
+            "schema": {"type": "object", "properties": {"id": {"type": "integer"}}},
+            "examples": {"first": {"value": {"id": 42}}}
+        }))
+        .unwrap();
+        assert_eq!(media_type_value(&openapi, &media), Some(json!({"id": 42})));
+
+        let media: MediaType = serde_json::from_value(json!({
+            "schema": {"type": "object", "properties": {"id": {"type": "integer"}}}
+        }))
+        .unwrap();
+        assert_eq!(media_type_value(&openapi, &media), Some(json!({"id": 1})));
+    }
+}
@@ -1,10 +1,22 @@
 pub mod api_client;
+pub mod deprecation;
+pub mod factories;
 pub mod hooks;
+pub mod json_schema;
+pub mod mock_data;
 pub mod module_selector;
+pub mod msw;
+pub mod pagination;
 pub mod query_keys;
 pub mod query_params;
 pub mod schema_resolver;
+pub mod sdk_client;
+pub mod security;
+pub mod servers;
+pub mod spec_client;
+pub mod streaming;
 pub mod swagger_parser;
+pub mod symbols;
 pub mod ts_typings;
 pub mod utils;
 pub mod writer;
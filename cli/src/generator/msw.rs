@@ -0,0 +1,432 @@
+use crate::config::model::ApisConfig;
+use crate::error::Result;
+use crate::generator::api_client::extract_all_responses;
+use crate::generator::mock_data::{media_type_value, schema_value};
+use crate::generator::streaming::{detect_stream, StreamFormat};
+use crate::generator::swagger_parser::{resolve_response_ref, OperationInfo};
+use crate::generator::utils::{sanitize_module_name, to_camel_case, to_pascal_case};
+use openapiv3::{OpenAPI, ReferenceOr, StatusCode};
+
+/// Spec-level module with the helpers shared by every module's handlers.
+pub const MSW_UTILS_FILE: &str = "utils.ts";
+/// Spec-level module combining the handlers of every generated module.
+pub const MSW_HANDLERS_FILE: &str = "handlers.ts";
+
+/// Helpers used by the generated handlers: typed override resolution and response builders.
+pub const MSW_UTILS: &str = r#"import { HttpResponse, type HttpResponseResolver, type JsonBodyType } from "msw";
+
+/**
+ * Request info passed to MSW resolvers (`request`, `params`, `cookies`).
+ */
+export type MockResolverInfo = Parameters<HttpResponseResolver>[0];
+
+/**
+ * Mock response body, or a function computing it from the intercepted request.
+ */
+export type MockResponse<T> = T | ((info: MockResolverInfo) => T | Promise<T>);
+
+export async function resolveMock<T>(response: MockResponse<T>, info: MockResolverInfo): Promise<T> {
+  return typeof response === "function"
+    ? (response as (info: MockResolverInfo) => T | Promise<T>)(info)
+    : response;
+}
+
+/**
+ * JSON response with the given status.
+ */
+export function mockJson(body: unknown, status: number) {
+  return HttpResponse.json(body as JsonBodyType, { status });
+}
+
+/**
+ * Status of an error map key: `"4XX"`-style ranges respond with their first status and
+ * `default` with 500.
+ */
+export function mockStatus(status: number | string): number {
+  if (typeof status === "number") return status;
+  return status === "default" ? 500 : Number(status[0]) * 100;
+}
+
+/**
+ * Response without a body.
+ */
+export function mockEmpty(status: number) {
+  return new HttpResponse(null, { status });
+}
+
+/**
+ * `text/event-stream` or NDJSON response sending `events` in order.
+ */
+export function mockStream(events: unknown[], format: "sse" | "ndjson", status: number) {
+  const body = events
+    .map((event) =>
+      format === "sse"
+        ? `data: ${typeof event === "string" ? event : JSON.stringify(event)}\n\n`
+        : `${JSON.stringify(event)}\n`
+    )
+    .join("");
+  const contentType = format === "sse" ? "text/event-stream" : "application/x-ndjson";
+  return new HttpResponse(body, { status, headers: { "Content-Type": contentType } });
+}
+"#;
+
+/// Mocked success response of an operation.
+enum MockBody {
+    /// JSON body: (TypeScript type, value)
+    Json(String, serde_json::Value),
+    /// Streamed events: (format, event type, first event)
+    Stream(StreamFormat, String, serde_json::Value),
+    /// No content
+    Empty,
+}
+
+/// Name of the array exported by a module's handlers file (e.g. `tenantAuthHandlers`).
+pub fn module_handlers_name(module_name: &str) -> String {
+    format!("{}Handlers", to_camel_case(&module_name.replace('/', "_")))
+}
+
+/// Generate the MSW v2 handlers of a module. Every operation gets a `{operation}Mock` value
+/// (the spec's `example`/`examples`, or data derived from the response schema), a
+/// `{operation}Handler(response?)` factory that overrides it, and, when the operation
+/// declares error responses, an `{operation}ErrorHandler(status, response)` factory.
+/// Bodies are typed with the response maps exported by the generated client.
+pub fn generate_msw_handlers(
+    openapi: &OpenAPI,
+    operations: &[OperationInfo],
+    module_name: &str,
+    api_import: &str,
+    apis_config: &ApisConfig,
+) -> Result<String> {
+    let mut type_imports = Vec::new();
+    let mut util_imports = vec!["resolveMock", "type MockResponse"];
+    let mut sections = Vec::new();
+    let mut handler_calls = Vec::new();
+
+    for op_info in operations {
+        let operation = &op_info.operation;
+        let func_name = op_info.function_name.clone();
+        let type_name_base = to_pascal_case(&func_name);
+        let method = op_info.method.to_lowercase();
+        let route = msw_route(&op_info.path);
+        let summary = format!("`{} {}`", op_info.method.to_uppercase(), op_info.path);
+
+        let responses = extract_all_responses(openapi, operation)?;
+        let Some((status, body)) = success_body(openapi, op_info, &type_name_base, apis_config)?
+        else {
+            continue;
+        };
+        let typed = responses
+            .iter()
+            .any(|r| r.status_code == status && !r.range && r.body_type != "any");
+
+        let mut lines = Vec::new();
+        match body {
+            MockBody::Json(ref ty, ref value) => {
+                let ty = if typed {
+                    type_imports.push(format!("{}Responses", type_name_base));
+                    format!("{}Responses[{}]", type_name_base, status)
+                } else {
+                    ty.clone()
+                };
+                lines.push(format!(
+                    "export const {}Mock: {} = {};",
+                    func_name,
+                    ty,
+                    literal(value)
+                ));
+                lines.push(String::new());
+                lines.push(format!(
+                    "/**\n * {}: responds {} with `{}Mock`, or with `response` when given.\n */",
+                    summary, status, func_name
+                ));
+                lines.push(format!(
+                    "export const {}Handler = (\n  response: MockResponse<{}> = {}Mock,\n  options?: RequestHandlerOptions\n) =>\n  http.{}(\"{}\", async (info) => mockJson(await resolveMock(response, info), {}), options);",
+                    func_name, ty, func_name, method, route, status
+                ));
+                util_imports.push("mockJson");
+            }
+            MockBody::Stream(format, ref event_type, ref value) => {
+                if event_type != "any" {
+                    type_imports.push(event_type.clone());
+                }
+                let format_name = match format {
+                    StreamFormat::Sse => "sse",
+                    StreamFormat::Ndjson => "ndjson",
+                };
+                lines.push(format!(
+                    "export const {}Mock: {}[] = [{}];",
+                    func_name,
+                    event_type,
+                    literal(value)
+                ));
+                lines.push(String::new());
+                lines.push(format!(
+                    "/**\n * {}: streams `{}Mock`, or the events of `response` when given.\n */",
+                    summary, func_name
+                ));
+                lines.push(format!(
+                    "export const {}Handler = (\n  response: MockResponse<{}[]> = {}Mock,\n  options?: RequestHandlerOptions\n) =>\n  http.{}(\"{}\", async (info) => mockStream(await resolveMock(response, info), \"{}\", {}), options);",
+                    func_name, event_type, func_name, method, route, format_name, status
+                ));
+                util_imports.push("mockStream");
+            }
+            MockBody::Empty => {
+                lines.push(format!("/**\n * {}: responds {}.\n */", summary, status));
+                lines.push(format!(
+                    "export const {}Handler = (options?: RequestHandlerOptions) =>\n  http.{}(\"{}\", () => mockEmpty({}), options);",
+                    func_name, method, route, status
+                ));
+                util_imports.push("mockEmpty");
+            }
+        }
+
+        // The client only exports an `{Operation}Errors` map for operations with error responses
+        if responses
+            .iter()
+            .any(|r| !(200..300).contains(&r.status_code))
+        {
+            let errors = format!("{}Errors", type_name_base);
+            lines.push(String::new());
+            lines.push(format!(
+                "/**\n * {}: responds with the error `status` and `response`.\n */",
+                summary
+            ));
+            lines.push(format!(
+                "export const {}ErrorHandler = <Status extends keyof {}>(\n  status: Status,\n  response: MockResponse<{}[Status]>,\n  options?: RequestHandlerOptions\n) =>\n  http.{}(\"{}\", async (info) => mockJson(await resolveMock(response, info), mockStatus(status)), options);",
+                func_name, errors, errors, method, route
+            ));
+            type_imports.push(errors);
+            util_imports.push("mockJson");
+            util_imports.push("mockStatus");
+        }
+
+        sections.push(lines.join("\n"));
+        handler_calls.push(format!("  {}Handler(),", func_name));
+    }
+
+    type_imports.dedup();
+    let mut util_imports: Vec<&str> = util_imports.into_iter().fold(Vec::new(), |mut acc, name| {
+        if !acc.contains(&name) {
+            acc.push(name);
+        }
+        acc
+    });
+    // Values first, then types
+    util_imports.sort_by_key(|name| (name.starts_with("type "), *name));
+
+    let mut content = String::from("import { http, type RequestHandlerOptions } from \"msw\";\n");
+    if !type_imports.is_empty() {
+        content.push_str(&format!(
+            "import type {{ {} }} from \"{}\";\n",
+            type_imports.join(", "),
+            api_import
+        ));
+    }
+    let utils_import = format!(
+        "{}utils",
+        "../".repeat(sanitize_module_name(module_name).matches('/').count() + 1)
+    );
+    content.push_str(&format!(
+        "import {{ {} }} from \"{}\";\n\n",
+        util_imports.join(", "),
+        utils_import
+    ));
+    for section in &sections {
+        content.push_str(section);
+        content.push_str("\n\n");
+    }
+    content.push_str(&format!(
+        "/**\n * Handlers responding with the mock data of every `{}` operation.\n */\nexport const {} = [\n{}\n];\n",
+        module_name,
+        module_handlers_name(module_name),
+        handler_calls.join("\n")
+    ));
+    Ok(content)
+}
+
+/// Import path of a module's API client from its handlers file
+/// (`{mocks_dir}/{module}/index.ts` -> `{apis_dir}/{module}`).
+pub fn api_import_path(mocks_dir: &str, apis_dir: &str, module_name: &str) -> String {
+    let module = sanitize_module_name(module_name);
+    let parts = |dir: &str| -> Vec<String> {
+        dir.split('/')
+            .chain(module.split('/'))
+            .filter(|part| !part.is_empty() && *part != ".")
+            .map(|part| part.to_string())
+            .collect()
+    };
+    let from = parts(mocks_dir);
+    let to = parts(apis_dir);
+    let common = from
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to)
+        .count();
+    let ups = from.len() - common;
+    let prefix = if ups == 0 {
+        "./".to_string()
+    } else {
+        "../".repeat(ups)
+    };
+    format!("{}{}", prefix, to[common..].join("/"))
+}
+
+/// Generate the spec-level `handlers.ts` combining the handlers of `modules`.
+pub fn generate_msw_index(modules: &[String]) -> String {
+    let mut content = String::new();
+    for module in modules {
+        content.push_str(&format!(
+            "import {{ {} }} from \"./{}\";\n",
+            module_handlers_name(module),
+            sanitize_module_name(module)
+        ));
+    }
+    let spreads: Vec<String> = modules
+        .iter()
+        .map(|module| format!("  ...{},", module_handlers_name(module)))
+        .collect();
+    content.push_str(&format!(
+        "\n/**\n * Handlers of every generated module, for `setupWorker(...handlers)` or `setupServer(...handlers)`.\n */\nexport const handlers = [\n{}\n];\n",
+        spreads.join("\n")
+    ));
+    content
+}
+
+/// Status and mocked body of the first 2xx response of an operation.
+fn success_body(
+    openapi: &OpenAPI,
+    op_info: &OperationInfo,
+    type_name_base: &str,
+    apis_config: &ApisConfig,
+) -> Result<Option<(u16, MockBody)>> {
+    let operation = &op_info.operation;
+    if let Some(stream) = detect_stream(openapi, operation) {
+        // `{Operation}Event` is only exported by fetch-style streaming functions
+        let event_type = if apis_config.style == "fetch" {
+            format!("{}Event", type_name_base)
+        } else {
+            "any".to_string()
+        };
+        let value = match &stream.event_schema {
+            Some(schema) => schema_value(
+                openapi,
+                &ReferenceOr::Reference {
+                    reference: format!("#/components/schemas/{}", schema),
+                },
+            ),
+            // Array schemas and examples describe the whole stream; mock its first event
+            None => match response_media(openapi, op_info, stream.status, &stream.media_type)
+                .and_then(|media| media_type_value(openapi, &media))
+            {
+                Some(serde_json::Value::Array(mut events)) if !events.is_empty() => {
+                    events.swap_remove(0)
+                }
+                value => value.unwrap_or(serde_json::Value::Null),
+            },
+        };
+        return Ok(Some((
+            stream.status,
+            MockBody::Stream(stream.format, event_type, value),
+        )));
+    }
+
+    let Some(status) = operation
+        .responses
+        .responses
+        .keys()
+        .find_map(|status| match status {
+            StatusCode::Code(code @ 200..=299) => Some(*code),
+            _ => None,
+        })
+    else {
+        return Ok(None);
+    };
+    Ok(Some(
+        match response_media(openapi, op_info, status, "application/json")
+            .and_then(|media| media_type_value(openapi, &media))
+        {
+            Some(value) => (status, MockBody::Json("any".to_string(), value)),
+            None => (status, MockBody::Empty),
+        },
+    ))
+}
+
+fn response_media(
+    openapi: &OpenAPI,
+    op_info: &OperationInfo,
+    status: u16,
+    media_type: &str,
+) -> Option<openapiv3::MediaType> {
+    let response = op_info
+        .operation
+        .responses
+        .responses
+        .get(&StatusCode::Code(status))?;
+    let response = match response {
+        ReferenceOr::Item(response) => response.clone(),
+        ReferenceOr::Reference { reference } => {
+            resolve_response_ref(openapi, reference).ok()?.into_item()?
+        }
+    };
+    response.content.get(media_type).cloned()
+}
+
+/// MSW route of an OpenAPI path, matching any origin and base path:
+/// `/orders/{order-id}` -> `*/orders/:order_id`.
+fn msw_route(path: &str) -> String {
+    let segments: Vec<String> = path
+        .split('/')
+        .map(
+            |segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(param) => format!(
+                    ":{}",
+                    param
+                        .chars()
+                        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                        .collect::<String>()
+                ),
+                None => segment.to_string(),
+            },
+        )
+        .collect();
+    format!("*{}", segments.join("/"))
+}
+
+/// TypeScript literal of a mock value (pretty-printed JSON).
+fn literal(value: &serde_json::Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| "null".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msw_route() {
+        assert_eq!(msw_route("/orders"), "*/orders");
+        assert_eq!(
+            msw_route("/orders/{id}/items/{item-id}"),
+            "*/orders/:id/items/:item_id"
+        );
+    }
+
+    #[test]
+    fn test_api_import_path() {
+        assert_eq!(
+            api_import_path("src/mocks", "src/apis", "orders"),
+            "../../apis/orders"
+        );
+        assert_eq!(
+            api_import_path("./test/mocks/shop", "src/apis/shop", "tenant/auth"),
+            "../../../../../src/apis/shop/tenant/auth"
+        );
+    }
+
+    #[test]
+    fn test_generate_msw_index() {
+        let content = generate_msw_index(&["orders".to_string(), "tenant/auth".to_string()]);
+        assert!(content.contains("import { ordersHandlers } from \"./orders\";"));
+        assert!(content.contains("import { tenantAuthHandlers } from \"./tenant/auth\";"));
+        assert!(content.contains("  ...tenantAuthHandlers,"));
+    }
+}
@@ -0,0 +1,657 @@
+use crate::config::model::{PaginationConfig, PaginationRule};
+use crate::generator::swagger_parser::{resolve_ref, resolve_response_ref};
+use crate::generator::symbols::is_valid_identifier;
+use openapiv3::{OpenAPI, Operation, ReferenceOr, Schema, SchemaKind, StatusCode, Type};
+
+/// Query parameters recognised as cursors, in order of preference.
+const CURSOR_PARAMS: [&str; 5] = [
+    "cursor",
+    "after",
+    "pageToken",
+    "page_token",
+    "starting_after",
+];
+/// Response fields recognised as the next cursor.
+const NEXT_FIELDS: [&str; 6] = [
+    "nextCursor",
+    "next_cursor",
+    "nextPageToken",
+    "next_page_token",
+    "nextToken",
+    "next_token",
+];
+const PAGE_PARAMS: [&str; 3] = ["page", "pageNumber", "page_number"];
+const OFFSET_PARAMS: [&str; 2] = ["offset", "skip"];
+const LIMIT_PARAMS: [&str; 6] = [
+    "limit",
+    "pageSize",
+    "page_size",
+    "perPage",
+    "per_page",
+    "size",
+];
+/// Response fields recognised as the page items (otherwise the first array field).
+const ITEM_FIELDS: [&str; 7] = [
+    "items", "data", "results", "content", "records", "entries", "nodes",
+];
+const TOTAL_FIELDS: [&str; 6] = [
+    "total",
+    "totalCount",
+    "total_count",
+    "totalItems",
+    "total_items",
+    "count",
+];
+const TOTAL_PAGES_FIELDS: [&str; 4] = ["totalPages", "total_pages", "pageCount", "page_count"];
+/// Nested objects searched for pagination fields (e.g. `{ data: [...], meta: { nextCursor } }`).
+const META_FIELDS: [&str; 5] = ["meta", "pagination", "pageInfo", "page_info", "paging"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationStyle {
+    /// Follow the next cursor returned by each page
+    Cursor,
+    /// Increment a page number
+    Page,
+    /// Advance an offset by the number of items received
+    Offset,
+}
+
+/// Pagination of an operation, resolved from config, `x-pagination` or detection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pagination {
+    pub style: PaginationStyle,
+    /// Query parameter carrying the cursor, page number or offset
+    pub param: String,
+    /// Query parameter carrying the page size
+    pub limit_param: Option<String>,
+    /// Response field with the page items (dot-separated for nested fields)
+    pub items: String,
+    /// Response field with the next cursor
+    pub next: Option<String>,
+    /// Response field with the total number of items
+    pub total: Option<String>,
+    /// Response field with the number of pages
+    pub total_pages: Option<String>,
+    /// Status of the paginated response in the operation's response map
+    pub status: u16,
+}
+
+/// Resolve the pagination of an operation. `apis.pagination.operations` takes precedence
+/// over the `x-pagination` extension (`false` disables helpers), which takes precedence
+/// over detection from parameter and response field names (GET operations only).
+pub fn detect_pagination(
+    openapi: &OpenAPI,
+    operation: &Operation,
+    method: &str,
+    query_params: &[String],
+    config: Option<&PaginationConfig>,
+) -> Option<Pagination> {
+    let configured = operation
+        .operation_id
+        .as_ref()
+        .and_then(|id| config.and_then(|config| config.operations.get(id)))
+        .cloned();
+    let rule = match configured {
+        Some(rule) => Some(rule),
+        None => match operation.extensions.get("x-pagination") {
+            Some(serde_json::Value::Bool(false)) => return None,
+            Some(value) => serde_json::from_value::<PaginationRule>(value.clone()).ok(),
+            None => None,
+        },
+    };
+
+    let (status, fields) = paged_response(openapi, operation)?;
+    let has_param = |name: &str| query_params.iter().any(|param| param == name);
+    let find_param = |candidates: &[&str]| {
+        candidates
+            .iter()
+            .find(|name| has_param(name))
+            .map(|name| name.to_string())
+    };
+
+    let (style, param) = match rule {
+        Some(ref rule) => {
+            let (style, candidates, default) = match rule.style.as_str() {
+                "cursor" => (PaginationStyle::Cursor, &CURSOR_PARAMS[..], "cursor"),
+                "page" => (PaginationStyle::Page, &PAGE_PARAMS[..], "page"),
+                "offset" => (PaginationStyle::Offset, &OFFSET_PARAMS[..], "offset"),
+                _ => return None,
+            };
+            let param = rule
+                .param
+                .clone()
+                .or_else(|| find_param(candidates))
+                .unwrap_or_else(|| default.to_string());
+            if !has_param(&param) {
+                return None;
+            }
+            (style, param)
+        }
+        None => {
+            if !method.eq_ignore_ascii_case("get")
+                || config.and_then(|config| config.detect) == Some(false)
+            {
+                return None;
+            }
+            if let Some(param) = find_param(&CURSOR_PARAMS) {
+                // A cursor parameter alone is not enough: the response must return the next one
+                fields.find(&NEXT_FIELDS)?;
+                (PaginationStyle::Cursor, param)
+            } else if let Some(param) = find_param(&OFFSET_PARAMS) {
+                (PaginationStyle::Offset, param)
+            } else if let Some(param) = find_param(&PAGE_PARAMS) {
+                (PaginationStyle::Page, param)
+            } else {
+                return None;
+            }
+        }
+    };
+
+    let rule = rule.unwrap_or_default();
+    let items = rule.items.clone().or_else(|| fields.items())?;
+    let next = match style {
+        PaginationStyle::Cursor => Some(rule.next.clone().or_else(|| fields.find(&NEXT_FIELDS))?),
+        _ => None,
+    };
+    Some(Pagination {
+        style,
+        param,
+        limit_param: rule
+            .limit_param
+            .clone()
+            .or_else(|| find_param(&LIMIT_PARAMS)),
+        items,
+        next,
+        total: rule.total.clone().or_else(|| fields.find(&TOTAL_FIELDS)),
+        total_pages: match style {
+            PaginationStyle::Page => rule
+                .total_pages
+                .clone()
+                .or_else(|| fields.find(&TOTAL_PAGES_FIELDS)),
+            _ => None,
+        },
+        status,
+    })
+}
+
+/// Top-level and `meta`-style nested fields of a paginated response body.
+struct ResponseFields {
+    /// (name, is array)
+    top: Vec<(String, bool)>,
+    /// (parent.name)
+    nested: Vec<String>,
+}
+
+impl ResponseFields {
+    fn find(&self, candidates: &[&str]) -> Option<String> {
+        candidates
+            .iter()
+            .find_map(|name| self.top.iter().find(|(field, _)| field == name))
+            .map(|(field, _)| field.clone())
+            .or_else(|| {
+                candidates.iter().find_map(|name| {
+                    self.nested
+                        .iter()
+                        .find(|path| path.rsplit('.').next() == Some(name))
+                        .cloned()
+                })
+            })
+    }
+
+    fn items(&self) -> Option<String> {
+        let arrays: Vec<&String> = self
+            .top
+            .iter()
+            .filter(|(_, is_array)| *is_array)
+            .map(|(field, _)| field)
+            .collect();
+        ITEM_FIELDS
+            .iter()
+            .find_map(|name| arrays.iter().find(|field| field == &name))
+            .or_else(|| arrays.first())
+            .map(|field| field.to_string())
+    }
+}
+
+/// Status and fields of the first 2xx JSON response whose body is an object.
+fn paged_response(openapi: &OpenAPI, operation: &Operation) -> Option<(u16, ResponseFields)> {
+    operation
+        .responses
+        .responses
+        .iter()
+        .find_map(|(status, response)| {
+            let StatusCode::Code(code @ 200..=299) = status else {
+                return None;
+            };
+            let response = match response {
+                ReferenceOr::Item(response) => response.clone(),
+                ReferenceOr::Reference { reference } => {
+                    resolve_response_ref(openapi, reference).ok()?.into_item()?
+                }
+            };
+            let schema = response.content.get("application/json")?.schema.clone()?;
+            let properties = object_properties(openapi, &schema)?;
+
+            let mut fields = ResponseFields {
+                top: Vec::new(),
+                nested: Vec::new(),
+            };
+            for (name, property) in &properties {
+                let property = resolve_schema(openapi, property)?;
+                let is_array = matches!(property.schema_kind, SchemaKind::Type(Type::Array(_)));
+                fields.top.push((name.clone(), is_array));
+                if META_FIELDS.contains(&name.as_str()) {
+                    let nested = object_properties(openapi, &ReferenceOr::Item(property))
+                        .unwrap_or_default();
+                    fields.nested.extend(
+                        nested
+                            .into_iter()
+                            .map(|(field, _)| format!("{}.{}", name, field)),
+                    );
+                }
+            }
+            Some((*code, fields))
+        })
+}
+
+fn resolve_schema(openapi: &OpenAPI, schema: &ReferenceOr<Schema>) -> Option<Schema> {
+    match schema {
+        ReferenceOr::Item(schema) => Some(schema.clone()),
+        ReferenceOr::Reference { reference } => resolve_ref(openapi, reference).ok()?.into_item(),
+    }
+}
+
+fn object_properties(
+    openapi: &OpenAPI,
+    schema: &ReferenceOr<Schema>,
+) -> Option<Vec<(String, ReferenceOr<Schema>)>> {
+    let schema = resolve_schema(openapi, schema)?;
+    match schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => Some(
+            object
+                .properties
+                .into_iter()
+                .map(|(name, property)| {
+                    let property = match property {
+                        ReferenceOr::Item(schema) => ReferenceOr::Item(*schema),
+                        ReferenceOr::Reference { reference } => {
+                            ReferenceOr::Reference { reference }
+                        }
+                    };
+                    (name, property)
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Property access on `target` for a dot-separated field (`meta.next` -> `page.meta?.next`).
+fn field_access(target: &str, field: &str) -> String {
+    let mut access = target.to_string();
+    for (index, segment) in field.split('.').enumerate() {
+        let optional = if index == 0 { "" } else { "?" };
+        if is_valid_identifier(segment) {
+            access.push_str(&format!("{}.{}", optional, segment));
+        } else {
+            access.push_str(&format!(
+                "{}[\"{}\"]",
+                if index == 0 { "" } else { "?." },
+                segment
+            ));
+        }
+    }
+    access
+}
+
+/// TypeScript type of a dot-separated field of `base`.
+fn field_type(base: &str, field: &str) -> String {
+    field.split('.').fold(base.to_string(), |ty, segment| {
+        if ty == base {
+            format!("{}[\"{}\"]", ty, segment)
+        } else {
+            format!("NonNullable<{}>[\"{}\"]", ty, segment)
+        }
+    })
+}
+
+/// `key: value` object property, shortened to `value` when both are the same.
+fn property_shorthand(key: &str, value: &str) -> String {
+    if key == value {
+        value.to_string()
+    } else {
+        format!("{}: {}", key, value)
+    }
+}
+
+/// Generated function the pagination helpers wrap.
+pub struct PaginatedFunction<'a> {
+    pub func_name: &'a str,
+    /// PascalCase base of the operation's type names (e.g. `ListOrders`)
+    pub type_name_base: &'a str,
+    /// Parameters of the generated function, ending with its per-call options/config
+    pub params: &'a [String],
+    /// `apis.signature` is "object"
+    pub object_signature: bool,
+    /// The paginated response has a named schema in the `{Op}Responses` map
+    /// (inline bodies are not part of the map, so pages are typed `any`)
+    pub typed_response: bool,
+    /// `apis.error_mode` is "throw": the function returns the page itself
+    pub throws: bool,
+    pub runtime_import: &'a str,
+}
+
+/// Generate the `paginate{Op}` async iterator (with the `{Op}Page`/`{Op}Item` types) and
+/// the `fetchAll{Op}` helper of a paginated operation, as two separate function entries.
+pub fn generate_pagination_helpers(
+    pagination: &Pagination,
+    function: &PaginatedFunction,
+) -> Option<(String, String)> {
+    let (options_param, leading) = function.params.split_last()?;
+    let options_name = options_param.split(['?', ':']).next()?.trim();
+
+    // Helper parameters mirror the function's, with a defaulted query
+    let mut helper_params = Vec::new();
+    let mut call_args = Vec::new();
+    let query;
+    let fetch_args;
+    if function.object_signature {
+        let (fields, params_type) = leading.first()?.split_once("}: ")?;
+        if !fields.split([',', '{', ' ']).any(|field| field == "query") {
+            return None;
+        }
+        helper_params.push(format!("params: {}", params_type));
+        query = "params.query?".to_string();
+        fetch_args = "{ ...params, query: { ...params.query, ...pageParams } }".to_string();
+        call_args.push("params".to_string());
+    } else {
+        let mut has_query = false;
+        let mut args = Vec::new();
+        for param in leading {
+            let name = param.split(['?', ':']).next()?.trim().to_string();
+            if name == "query" {
+                let (_, query_type) = param.split_once(": ")?;
+                helper_params.push(format!("query: {} = {{}}", query_type));
+                args.push("{ ...query, ...pageParams }".to_string());
+                has_query = true;
+            } else {
+                helper_params.push(param.clone());
+                args.push(name.clone());
+            }
+            call_args.push(name);
+        }
+        if !has_query {
+            return None;
+        }
+        query = "query".to_string();
+        fetch_args = args.join(", ");
+    }
+    helper_params.push(options_param.clone());
+    call_args.push(options_name.to_string());
+
+    let query_type = if function.object_signature {
+        format!("NonNullable<{}Params[\"query\"]>", function.type_name_base)
+    } else {
+        leading
+            .iter()
+            .find_map(|param| param.strip_prefix("query?: "))?
+            .to_string()
+    };
+
+    let base = function.type_name_base;
+    let page_type = format!("{}Page", base);
+    let item_type = format!("{}Item", base);
+    let items = field_access("page", &pagination.items);
+    let query_field = |name: &str| field_access(&query, name);
+    let param_key = if is_valid_identifier(&pagination.param) {
+        pagination.param.clone()
+    } else {
+        format!("\"{}\"", pagination.param)
+    };
+
+    let mut next_lines = Vec::new();
+    let mut setup = String::new();
+    let description = match pagination.style {
+        PaginationStyle::Cursor => {
+            let next = pagination.next.as_deref()?;
+            next_lines.push("      (page) => {".to_string());
+            next_lines.push(format!(
+                "        const cursor = {};",
+                field_access("page", next)
+            ));
+            next_lines.push(format!(
+                "        return cursor ? {{ {} }} : undefined;",
+                property_shorthand(&param_key, "cursor")
+            ));
+            format!("following `{}` until it is empty", next)
+        }
+        PaginationStyle::Page => {
+            next_lines.push("      (page, pageParams) => {".to_string());
+            next_lines.push(format!("        const items = {} ?? [];", items));
+            next_lines.push(format!(
+                "        const current = Number({} ?? {} ?? 1);",
+                field_access("pageParams", &pagination.param),
+                query_field(&pagination.param)
+            ));
+            next_lines.push("        if (items.length === 0) return undefined;".to_string());
+            if let Some(ref total_pages) = pagination.total_pages {
+                next_lines.push(format!(
+                    "        if (current >= Number({})) return undefined;",
+                    field_access("page", total_pages)
+                ));
+            }
+            if let Some(ref total) = pagination.total {
+                setup = "    let fetched = 0;\n".to_string();
+                next_lines.insert(2, "        fetched += items.length;".to_string());
+                next_lines.push(format!(
+                    "        if (fetched >= Number({})) return undefined;",
+                    field_access("page", total)
+                ));
+            }
+            format!("incrementing `{}` until a page is empty", pagination.param)
+        }
+        PaginationStyle::Offset => {
+            next_lines.push("      (page, pageParams) => {".to_string());
+            next_lines.push(format!("        const items = {} ?? [];", items));
+            next_lines.push(format!(
+                "        const offset = Number({} ?? {} ?? 0) + items.length;",
+                field_access("pageParams", &pagination.param),
+                query_field(&pagination.param)
+            ));
+            next_lines.push("        if (items.length === 0) return undefined;".to_string());
+            if let Some(ref total) = pagination.total {
+                next_lines.push(format!(
+                    "        if (offset >= Number({})) return undefined;",
+                    field_access("page", total)
+                ));
+            }
+            format!("advancing `{}` until a page is empty", pagination.param)
+        }
+    };
+    if pagination.style != PaginationStyle::Cursor {
+        // A page shorter than the requested page size is the last one
+        if let Some(ref limit) = pagination.limit_param {
+            let limit = query_field(limit);
+            next_lines.push(format!(
+                "        if ({} !== undefined && items.length < Number({})) return undefined;",
+                limit, limit
+            ));
+        }
+        next_lines.push(format!(
+            "        return {{ {} }};",
+            if pagination.style == PaginationStyle::Page {
+                format!("{}: current + 1", param_key)
+            } else {
+                property_shorthand(&param_key, "offset")
+            }
+        ));
+    }
+    next_lines.push("      }".to_string());
+
+    // Throwing functions resolve to the page, which is wrapped back into a result
+    let call = format!("{}({}, {})", function.func_name, fetch_args, options_name);
+    let (fetch_page, failure) = if function.throws {
+        (
+            format!(
+                "async (pageParams) => ({{ ok: true as const, status: {}, data: await {} }})",
+                pagination.status, call
+            ),
+            format!(
+                "A failed page throws the error of `{}`.",
+                function.func_name
+            ),
+        )
+    } else {
+        (
+            format!("(pageParams) => {}", call),
+            "A failed page throws a `PaginationError` carrying its result.".to_string(),
+        )
+    };
+
+    let paginate = format!(
+        r#"import {{ paginate }} from "{runtime_import}";
+export type {page_type} = {page_source};
+export type {item_type} = NonNullable<{items_type}>[number];
+
+/**
+ * Iterate over the pages of `{func_name}`, {description}.
+ * {failure}
+ */
+export function paginate{base}({helper_params}): AsyncGenerator<{page_type}, void, undefined> {{
+{setup}    return paginate<{page_type}, {query_type}>(
+      {fetch_page},
+{next}
+    );
+}}"#,
+        runtime_import = function.runtime_import,
+        page_type = page_type,
+        item_type = item_type,
+        base = base,
+        page_source = if function.typed_response {
+            format!("{}Responses[{}]", base, pagination.status)
+        } else {
+            "any".to_string()
+        },
+        items_type = field_type(&page_type, &pagination.items),
+        func_name = function.func_name,
+        description = description,
+        failure = failure,
+        helper_params = helper_params.join(", "),
+        setup = setup,
+        query_type = query_type,
+        fetch_page = fetch_page,
+        next = next_lines.join("\n"),
+    );
+
+    let fetch_all = format!(
+        r#"/**
+ * Fetch every page of `{func_name}` and return their items.
+ */
+export const fetchAll{base} = async ({helper_params}): Promise<{item_type}[]> => {{
+    const items: {item_type}[] = [];
+    for await (const page of paginate{base}({call_args})) {{
+        items.push(...({items} ?? []));
+    }}
+    return items;
+}};"#,
+        func_name = function.func_name,
+        base = base,
+        helper_params = helper_params.join(", "),
+        item_type = item_type,
+        call_args = call_args.join(", "),
+        items = items,
+    );
+
+    Some((paginate, fetch_all))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_openapi(paths: &str) -> OpenAPI {
+        serde_json::from_str(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Test", "version": "1.0.0"}}, "paths": {}}}"#,
+            paths
+        ))
+        .unwrap()
+    }
+
+    fn get_operation(openapi: &OpenAPI, path: &str) -> Operation {
+        let item = openapi.paths.paths.get(path).unwrap().as_item().unwrap();
+        item.get.clone().or_else(|| item.post.clone()).unwrap()
+    }
+
+    fn names(params: &[&str]) -> Vec<String> {
+        params.iter().map(|p| p.to_string()).collect()
+    }
+
+    const PAGE_BODY: &str = r#"{"200": {"description": "OK", "content": {"application/json": {"schema": {
+        "type": "object",
+        "properties": {
+            "results": {"type": "array", "items": {"type": "string"}},
+            "meta": {"type": "object", "properties": {"nextCursor": {"type": "string"}, "total": {"type": "integer"}}}
+        }
+    }}}}}"#;
+
+    #[test]
+    fn test_detect_cursor_pagination() {
+        let openapi = parse_openapi(&format!(
+            r#"{{"/orders": {{"get": {{"responses": {}}}}}}}"#,
+            PAGE_BODY
+        ));
+        let operation = get_operation(&openapi, "/orders");
+        let pagination = detect_pagination(
+            &openapi,
+            &operation,
+            "get",
+            &names(&["cursor", "limit"]),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(pagination.style, PaginationStyle::Cursor);
+        assert_eq!(pagination.param, "cursor");
+        assert_eq!(pagination.limit_param.as_deref(), Some("limit"));
+        assert_eq!(pagination.items, "results");
+        assert_eq!(pagination.next.as_deref(), Some("meta.nextCursor"));
+        assert_eq!(pagination.total.as_deref(), Some("meta.total"));
+        assert_eq!(pagination.status, 200);
+    }
+
+    #[test]
+    fn test_detect_pagination_requires_get_and_page_fields() {
+        let openapi = parse_openapi(&format!(
+            r#"{{"/orders": {{"post": {{"responses": {}}}}},
+                "/tags": {{"get": {{"responses": {{"200": {{"description": "OK"}}}}}}}}}}"#,
+            PAGE_BODY
+        ));
+
+        let search = get_operation(&openapi, "/orders");
+        assert!(detect_pagination(&openapi, &search, "post", &names(&["page"]), None).is_none());
+        let tags = get_operation(&openapi, "/tags");
+        assert!(detect_pagination(&openapi, &tags, "get", &names(&["page"]), None).is_none());
+
+        // Explicit rules apply to any method
+        let config = PaginationConfig {
+            detect: None,
+            operations: std::collections::HashMap::new(),
+        };
+        let mut rule = search.clone();
+        rule.extensions.insert(
+            "x-pagination".to_string(),
+            serde_json::json!({"style": "offset", "limitParam": "size"}),
+        );
+        let pagination = detect_pagination(
+            &openapi,
+            &rule,
+            "post",
+            &names(&["offset", "size"]),
+            Some(&config),
+        )
+        .unwrap();
+        assert_eq!(pagination.style, PaginationStyle::Offset);
+        assert_eq!(pagination.param, "offset");
+        assert_eq!(pagination.limit_param.as_deref(), Some("size"));
+    }
+}
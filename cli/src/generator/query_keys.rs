@@ -1,5 +1,7 @@
+use crate::config::model::ApisConfig;
+use crate::generator::hooks::context::HookContext;
 use crate::generator::swagger_parser::OperationInfo;
-use crate::generator::utils::to_camel_case;
+use crate::generator::utils::{parameter_identifier, sanitize_module_name, sanitize_property_name};
 use serde::Serialize;
 
 /// Context for query keys generation.
@@ -8,6 +10,8 @@ pub struct QueryKeyContext {
     pub module_name: String,
     pub spec_name: Option<String>,
     pub keys: Vec<QueryKeyEntry>,
+    /// Import of the `{Operation}Params` types used by keys with the object signature
+    pub params_import: String,
 }
 
 /// Entry for a single query key.
@@ -24,16 +28,15 @@ pub fn generate_query_keys(
     operations: &[OperationInfo],
     module_name: &str,
     spec_name: Option<&str>,
+    query_keys_dir: Option<&str>,
+    apis_config: &ApisConfig,
 ) -> QueryKeyContext {
     let mut keys = Vec::new();
+    let mut params_types = Vec::new();
+    let object_signature = apis_config.signature == "object";
 
     for op_info in operations {
-        // Generate key name from operation ID or path/method
-        let key_name = if let Some(operation_id) = &op_info.operation.operation_id {
-            to_camel_case(operation_id)
-        } else {
-            generate_key_name_from_path(&op_info.path, &op_info.method)
-        };
+        let key_name = op_info.function_name.clone();
 
         // Extract parameters for the key
         let mut params = Vec::new();
@@ -46,8 +49,9 @@ pub fn generate_query_keys(
             }) = param_ref
             {
                 let param_type = extract_param_type(parameter_data);
-                params.push(format!("{}: {}", parameter_data.name, param_type));
-                param_names.push(parameter_data.name.clone());
+                let identifier = parameter_identifier(&parameter_data.name);
+                params.push(format!("{}: {}", identifier, param_type));
+                param_names.push(identifier);
             }
         }
 
@@ -62,7 +66,11 @@ pub fn generate_query_keys(
                 }) = param_ref
                 {
                     let param_type = extract_param_type(parameter_data);
-                    query_fields.push(format!("{}?: {}", parameter_data.name, param_type));
+                    query_fields.push(format!(
+                        "{}?: {}",
+                        sanitize_property_name(&parameter_data.name),
+                        param_type
+                    ));
                 }
             }
         }
@@ -75,8 +83,21 @@ pub fn generate_query_keys(
         }
 
         let has_params = !params.is_empty();
-        let param_list = params.join(", ");
-        let param_names_str = param_names.join(", ");
+        let mut param_list = params.join(", ");
+        let mut param_names_str = param_names.join(", ");
+
+        // Object signature: keys take the same `{Operation}Params` object as the API function
+        // (only its `path` for mutations, which never put query params in the key)
+        if object_signature && has_params {
+            let params_type = format!("{}Params", to_pascal_case(&key_name));
+            param_list = if is_query {
+                format!("params: {}", params_type)
+            } else {
+                format!("params: Pick<{}, \"path\">", params_type)
+            };
+            param_names_str = "params".to_string();
+            params_types.push(params_type);
+        }
 
         keys.push(QueryKeyEntry {
             key_name,
@@ -86,53 +107,65 @@ pub fn generate_query_keys(
         });
     }
 
+    let params_import = if params_types.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "import type {{ {} }} from \"{}\";",
+            params_types.join(", "),
+            api_import_path(module_name, query_keys_dir, Some(&apis_config.output))
+        )
+    };
+
     QueryKeyContext {
         module_name: module_name.to_string(),
         spec_name: spec_name.map(|s| s.to_string()),
         keys,
+        params_import,
     }
 }
 
-/// Generate key name from path and method (fallback when operation_id is missing).
-fn generate_key_name_from_path(path: &str, method: &str) -> String {
-    let path_parts: Vec<&str> = path
-        .trim_start_matches('/')
-        .split('/')
-        .filter(|p| !p.starts_with('{'))
-        .collect();
-
-    let method_upper = method.to_uppercase();
-    let method_prefix = match method_upper.as_str() {
-        "GET" => "get",
-        "POST" => "create",
-        "PUT" => "update",
-        "DELETE" => "delete",
-        "PATCH" => "patch",
-        _ => {
-            return to_camel_case(&method.to_lowercase());
-        }
+/// Relative import path from `{query_keys_dir}/{module}.ts` to `{apis_dir}/{module}/index.ts`.
+fn api_import_path(
+    module_name: &str,
+    query_keys_dir: Option<&str>,
+    apis_dir: Option<&str>,
+) -> String {
+    let sanitized_module = sanitize_module_name(module_name);
+    let (Some(query_keys), Some(apis)) = (query_keys_dir, apis_dir) else {
+        // Fallback: assume query-keys and apis are siblings under src/
+        let depth = module_name.matches('/').count() + 1;
+        return format!("{}apis/{}", "../".repeat(depth), sanitized_module);
     };
 
-    let base_name = if path_parts.is_empty() {
-        method_prefix.to_string()
+    // Directory of the query keys file (nested modules live in subdirectories)
+    let query_keys = query_keys.trim_end_matches('/');
+    let from_dir = match sanitized_module.rsplit_once('/') {
+        Some((parent, _)) => format!("{}/{}", query_keys, parent),
+        None => query_keys.to_string(),
+    };
+    let target = format!("{}/{}", apis.trim_end_matches('/'), sanitized_module);
+
+    let common_prefix = HookContext::find_common_prefix(&from_dir, &target);
+    let from_relative = from_dir
+        .strip_prefix(&common_prefix)
+        .unwrap_or(&from_dir)
+        .trim_start_matches('/');
+    let target_relative = target
+        .strip_prefix(&common_prefix)
+        .unwrap_or(&target)
+        .trim_start_matches('/');
+
+    let depth = if from_relative.is_empty() {
+        0
     } else {
-        let resource_name = if path_parts.len() > 1 {
-            path_parts.last().unwrap_or(&"")
-        } else {
-            path_parts.first().unwrap_or(&"")
-        };
-
-        if resource_name.ends_with('s') && path.contains('{') {
-            let singular = &resource_name[..resource_name.len() - 1];
-            format!("{}{}ById", method_prefix, to_pascal_case(singular))
-        } else if path.contains('{') {
-            format!("{}{}ById", method_prefix, to_pascal_case(resource_name))
-        } else {
-            format!("{}{}", method_prefix, to_pascal_case(resource_name))
-        }
+        from_relative.matches('/').count() + 1
     };
-
-    to_camel_case(&base_name)
+    if depth == 0 {
+        format!("./{}", target_relative)
+    } else {
+        format!("{}{}", "../".repeat(depth), target_relative)
+    }
 }
 
 /// Extract parameter type from parameter data.
@@ -1,8 +1,10 @@
 use crate::error::Result;
-use crate::generator::api_client::{extract_query_parameters, ParameterType};
+use crate::generator::api_client::{
+    extract_path_parameters, extract_query_parameters, ParameterInfo, ParameterType,
+};
 use crate::generator::swagger_parser::OperationInfo;
 use crate::generator::ts_typings::TypeScriptType;
-use crate::generator::utils::to_pascal_case;
+use crate::generator::utils::{sanitize_property_name, to_pascal_case};
 use crate::generator::zod_schema::ZodSchema;
 use crate::templates::context::{Field, TypeContext, ZodContext};
 use crate::templates::engine::TemplateEngine;
@@ -24,6 +26,8 @@ pub struct QueryParamsContext<'a> {
     pub spec_name: Option<&'a str>,
     pub existing_types: &'a [TypeScriptType],
     pub existing_zod_schemas: &'a [ZodSchema],
+    /// Also generate `{Operation}PathParams` types and Zod schemas (used for request validation)
+    pub include_path_params: bool,
 }
 
 /// Generate query params types and Zod schemas for all operations in a module
@@ -38,6 +42,7 @@ pub fn generate_query_params_for_module(
         spec_name,
         existing_types,
         existing_zod_schemas,
+        include_path_params,
     } = ctx;
     let mut types = Vec::new();
     let mut zod_schemas = Vec::new();
@@ -82,28 +87,32 @@ pub fn generate_query_params_for_module(
 
     for op_info in operations {
         let operation = &op_info.operation;
+
+        let type_name_base = to_pascal_case(&op_info.function_name);
+
+        if include_path_params {
+            let path_params = extract_path_parameters(openapi, operation, enum_registry)?;
+            let path_type_name = format!("{}PathParams", type_name_base);
+            if !path_params.is_empty()
+                && !existing_schema_names.contains(&format!("{}Schema", path_type_name))
+            {
+                let (path_type, path_zod) = generate_path_params_for_operation(
+                    &path_type_name,
+                    &path_params,
+                    template_engine,
+                    spec_name,
+                )?;
+                types.push(path_type);
+                zod_schemas.push(path_zod);
+            }
+        }
+
         let query_params = extract_query_parameters(openapi, operation, enum_registry)?;
 
         if query_params.is_empty() {
             continue;
         }
 
-        // Extract operation_id from operation
-        let func_name = operation.operation_id.clone().unwrap_or_else(|| {
-            // Fallback: generate from method and path
-            format!(
-                "{}{}",
-                to_pascal_case(&op_info.method),
-                to_pascal_case(
-                    &op_info
-                        .path
-                        .replace("/", "")
-                        .replace("{", "")
-                        .replace("}", "")
-                )
-            )
-        });
-        let type_name_base = to_pascal_case(&func_name);
         let query_type_name = format!("{}QueryParams", type_name_base);
         // Schema name without "Schema" suffix - template will add it
         let schema_name = query_type_name.clone();
@@ -166,6 +175,7 @@ pub fn generate_query_params_for_module(
                     is_enum: true,
                     enum_values: Some(enum_values.clone()),
                     description: None,
+                    deprecated: None,
                     needs_type_annotation: false,
                     spec_name: spec_name.map(|s| s.to_string()),
                 };
@@ -202,10 +212,11 @@ pub fn generate_query_params_for_module(
             };
 
             fields.push(Field {
-                name: param.name.clone(),
+                name: sanitize_property_name(&param.name),
                 type_name: param_type,
                 optional: true,
                 description: param.description.clone(),
+                deprecated: param.deprecated.clone(),
             });
         }
 
@@ -264,7 +275,11 @@ pub fn generate_query_params_for_module(
             };
 
             let optional_zod = format!("{}.optional()", zod_type);
-            zod_field_strings.push(format!("  {}: {},", param.name, optional_zod));
+            zod_field_strings.push(format!(
+                "  {}: {},",
+                sanitize_property_name(&param.name),
+                optional_zod
+            ));
         }
 
         let zod_expr = format!("z.object({{\n{}\n}})", zod_field_strings.join("\n"));
@@ -278,6 +293,7 @@ pub fn generate_query_params_for_module(
                 is_enum: false,
                 enum_values: None,
                 description: None,
+                deprecated: None,
                 needs_type_annotation: false,
                 spec_name: spec_name.map(|s| s.to_string()),
             };
@@ -288,10 +304,114 @@ pub fn generate_query_params_for_module(
         } else {
             // Fallback without template
             zod_schemas.push(ZodSchema {
-                content: format!("export const {} = {};", schema_name, zod_expr),
+                content: format!("export const {}Schema = {};", schema_name, zod_expr),
             });
         }
     }
 
     Ok(QueryParamsGenerationResult { types, zod_schemas })
 }
+
+/// Generate the `{Operation}PathParams` type and Zod schema for an operation.
+///
+/// Path enums are inlined (as literal unions / `z.enum`) because their named types
+/// are emitted in the API file rather than in the schema files.
+fn generate_path_params_for_operation(
+    type_name: &str,
+    path_params: &[ParameterInfo],
+    template_engine: Option<&TemplateEngine>,
+    spec_name: Option<&str>,
+) -> Result<(TypeScriptType, ZodSchema)> {
+    let mut fields = Vec::new();
+    let mut zod_field_strings = Vec::new();
+    for param in path_params {
+        let (ts_type, zod_type) = match &param.param_type {
+            ParameterType::Enum(_) => {
+                let values = param.enum_values.clone().unwrap_or_default();
+                (
+                    values
+                        .iter()
+                        .map(|v| format!("\"{}\"", v))
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                    format!(
+                        "z.enum([{}])",
+                        values
+                            .iter()
+                            .map(|v| format!("\"{}\"", v))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+            }
+            // Arrays in path are serialized as strings
+            ParameterType::String | ParameterType::Array(_) => {
+                ("string".to_string(), "z.string()".to_string())
+            }
+            ParameterType::Number | ParameterType::Integer => {
+                ("number".to_string(), "z.number()".to_string())
+            }
+            ParameterType::Boolean => ("boolean".to_string(), "z.boolean()".to_string()),
+        };
+        fields.push(Field {
+            name: sanitize_property_name(&param.name),
+            type_name: ts_type,
+            optional: false,
+            description: param.description.clone(),
+            deprecated: param.deprecated.clone(),
+        });
+        zod_field_strings.push(format!(
+            "  {}: {},",
+            sanitize_property_name(&param.name),
+            zod_type
+        ));
+    }
+    let zod_expr = format!("z.object({{\n{}\n}})", zod_field_strings.join("\n"));
+
+    if let Some(engine) = template_engine {
+        let context = TypeContext::interface(
+            type_name.to_string(),
+            fields,
+            None,
+            spec_name.map(|s| s.to_string()),
+        );
+        let type_content = engine.render(TemplateId::TypeInterface, &context)?;
+        let zod_context = ZodContext {
+            schema_name: type_name.to_string(),
+            zod_expr,
+            is_enum: false,
+            enum_values: None,
+            description: None,
+            deprecated: None,
+            needs_type_annotation: false,
+            spec_name: spec_name.map(|s| s.to_string()),
+        };
+        let zod_content = engine.render(TemplateId::ZodSchema, &zod_context)?;
+        Ok((
+            TypeScriptType {
+                content: type_content,
+            },
+            ZodSchema {
+                content: zod_content,
+            },
+        ))
+    } else {
+        // Fallback without template
+        let field_strings: Vec<String> = fields
+            .iter()
+            .map(|f| format!("  {}: {};", f.name, f.type_name))
+            .collect();
+        Ok((
+            TypeScriptType {
+                content: format!(
+                    "export interface {} {{\n{}\n}}",
+                    type_name,
+                    field_strings.join("\n")
+                ),
+            },
+            ZodSchema {
+                content: format!("export const {}Schema = {};", type_name, zod_expr),
+            },
+        ))
+    }
+}
@@ -161,6 +161,26 @@ impl SchemaResolver {
                     }
                 }
             }
+            // Objects declared with `properties` but no `type`
+            SchemaKind::Any(any) => {
+                for (_, prop_schema_ref) in any.properties.iter() {
+                    match prop_schema_ref {
+                        ReferenceOr::Reference { reference } => {
+                            if let Some(ref_name) = get_schema_name_from_ref(reference) {
+                                deps.push(ref_name.clone());
+                                if !visited.contains(&ref_name) {
+                                    deps.extend(self.extract_schema_dependencies_from_ref(
+                                        &ref_name, visited,
+                                    )?);
+                                }
+                            }
+                        }
+                        ReferenceOr::Item(prop_schema) => {
+                            deps.extend(self.extract_schema_dependencies(prop_schema, visited)?);
+                        }
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -265,6 +285,20 @@ impl SchemaResolver {
         Ok(())
     }
 
+    /// Schemas referenced by `schema_name`, directly or through other schemas. Empty until
+    /// `build_dependency_graph` has run.
+    pub fn dependencies(&self, schema_name: &str) -> &[String] {
+        self.dependency_graph
+            .get(schema_name)
+            .map(|deps| deps.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether a reference from `from` to `to` leads back to `from`.
+    pub fn closes_cycle(&self, from: &str, to: &str) -> bool {
+        from == to || self.dependencies(to).iter().any(|dep| dep == from)
+    }
+
     pub fn is_circular(&self, schema_name: &str) -> bool {
         self.circular_refs.contains(schema_name)
     }
@@ -0,0 +1,250 @@
+use crate::config::model::ApisConfig;
+use crate::generator::api_client::sdk_factory_name;
+use crate::generator::spec_client::retry_policy_literal;
+use crate::generator::utils::{sanitize_module_name, string_literal, to_camel_case};
+
+/// File name (relative to the spec's `apis` output) of the generated SDK class.
+pub const SDK_CLIENT_FILE: &str = "client.ts";
+
+/// Generate the spec-level SDK class (`apis.client_class`). It owns its own `VikaClient`
+/// and exposes each module's `create{Module}Api` factory as a property, e.g.
+/// `new BillingClient({ baseUrl }).invoices.listInvoices()`.
+pub fn generate_sdk_client(
+    class_name: &str,
+    modules: &[String],
+    runtime_import: &str,
+    apis_config: &ApisConfig,
+    default_server: Option<&str>,
+    has_security: bool,
+) -> String {
+    let tracing = apis_config.tracing == Some(true);
+    let mut imports = vec![format!(
+        "import {{ VikaClient, {}type VikaClientOptions }} from \"{}\";",
+        if tracing { "createTracer, " } else { "" },
+        runtime_import
+    )];
+    if has_security {
+        imports.push(
+            "import { configureSecurity, type SecurityCredentials } from \"./security\";"
+                .to_string(),
+        );
+    }
+
+    let mut modules: Vec<&String> = modules.iter().collect();
+    modules.sort();
+    modules.dedup();
+
+    let mut properties = Vec::new();
+    let mut assignments = Vec::new();
+    for module in &modules {
+        let factory_name = sdk_factory_name(module);
+        let property = to_camel_case(&module.replace('/', "_"));
+        imports.push(format!(
+            "import {{ {} }} from \"./{}\";",
+            factory_name,
+            sanitize_module_name(module)
+        ));
+        properties.push(format!(
+            "  /** Operations of the `{}` module */\n  readonly {}: ReturnType<typeof {}>;",
+            module, property, factory_name
+        ));
+        assignments.push(format!(
+            "    this.{} = {}(this.client);",
+            property, factory_name
+        ));
+    }
+
+    // Defaults from .vika.json (the base URL falls back to the spec's first server),
+    // overridable by the constructor options
+    let mut defaults = Vec::new();
+    if let Some(base_url) = apis_config.base_url.as_deref().or(default_server) {
+        defaults.push(format!("  baseUrl: {},", string_literal(base_url)));
+    }
+    if let Some(timeout) = apis_config.timeout {
+        defaults.push(format!("  timeout: {},", timeout));
+    }
+    if let Some(retries) = apis_config.retries {
+        defaults.push(format!("  retries: {},", retries));
+    }
+    if let Some(retry_delay) = apis_config.retry_delay {
+        defaults.push(format!("  retryDelay: {},", retry_delay));
+    }
+    if let Some(ref policy) = apis_config.retry_policy {
+        defaults.push(format!("  retryPolicy: {},", retry_policy_literal(policy)));
+    }
+    if tracing {
+        defaults.push("  tracer: createTracer(),".to_string());
+    }
+    if let Some(ref headers) = apis_config.headers {
+        let headers_json = serde_json::to_string(headers).unwrap_or_else(|_| "{}".to_string());
+        defaults.push(format!("  headers: {},", headers_json));
+    }
+    if apis_config.validate_responses != "off" {
+        defaults.push(format!(
+            "  validateResponses: \"{}\",",
+            apis_config.validate_responses
+        ));
+    }
+    if apis_config.validate_requests != "off" {
+        defaults.push(format!(
+            "  validateRequests: \"{}\",",
+            apis_config.validate_requests
+        ));
+    }
+    let defaults = if defaults.is_empty() {
+        "const defaults: VikaClientOptions = {};".to_string()
+    } else {
+        format!(
+            "const defaults: VikaClientOptions = {{\n{}\n}};",
+            defaults.join("\n")
+        )
+    };
+
+    let (options_type, auth_field, auth_setup) = if has_security {
+        (
+            format!(
+                "export interface {class_name}Options extends Omit<VikaClientOptions, \"auth\" | \"credentials\"> {{\n  /** Credential providers for the spec's security schemes */\n  auth?: SecurityCredentials;\n}}"
+            ),
+            "const { auth, ...clientOptions } = options;\n    ",
+            "\n    if (auth) {\n      configureSecurity(auth, this.client);\n    }",
+        )
+    } else {
+        (
+            format!("export type {class_name}Options = VikaClientOptions;"),
+            "const clientOptions = options;\n    ",
+            "",
+        )
+    };
+
+    format!(
+        r#"{imports}
+
+{defaults}
+
+/**
+ * Options for {class_name}. Unset fields fall back to the values from .vika.json.
+ */
+{options_type}
+
+/**
+ * SDK client composing every module of the spec. Each instance owns its own
+ * VikaClient, so several clients (e.g. per tenant) can live side by side.
+ *
+ * @example
+ * ```typescript
+ * const api = new {class_name}({{ baseUrl: "https://api.example.com" }});
+ * const result = await api.{example_module}.someOperation();
+ * ```
+ */
+export class {class_name} {{
+  /** Underlying runtime client (register middleware, credentials, ...) */
+  readonly client: VikaClient;
+{properties}
+
+  constructor(options: {class_name}Options = {{}}) {{
+    {auth_field}this.client = new VikaClient({{ ...defaults, ...clientOptions }});{auth_setup}
+{assignments}
+  }}
+}}
+"#,
+        imports = imports.join("\n"),
+        defaults = defaults,
+        class_name = class_name,
+        options_type = options_type,
+        example_module = modules
+            .first()
+            .map(|module| to_camel_case(&module.replace('/', "_")))
+            .unwrap_or_else(|| "module".to_string()),
+        properties = properties.join("\n"),
+        auth_field = auth_field,
+        auth_setup = auth_setup,
+        assignments = assignments.join("\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_sdk_client() {
+        let apis_config = ApisConfig {
+            base_url: Some("https://api.example.com".to_string()),
+            ..ApisConfig::default()
+        };
+        let content = generate_sdk_client(
+            "BillingClient",
+            &["invoices".to_string(), "tenant/auth".to_string()],
+            "../../runtime",
+            &apis_config,
+            None,
+            true,
+        );
+
+        assert!(content
+            .contains("import { VikaClient, type VikaClientOptions } from \"../../runtime\";"));
+        assert!(content.contains("import { createInvoicesApi } from \"./invoices\";"));
+        assert!(content.contains("import { createTenantAuthApi } from \"./tenant/auth\";"));
+        assert!(content.contains("  baseUrl: \"https://api.example.com\","));
+        assert!(content.contains("  readonly tenantAuth: ReturnType<typeof createTenantAuthApi>;"));
+        assert!(content.contains("      configureSecurity(auth, this.client);"));
+        assert!(content.contains("    this.invoices = createInvoicesApi(this.client);"));
+    }
+
+    #[test]
+    fn test_generate_sdk_client_escapes_base_url() {
+        let apis_config = ApisConfig {
+            base_url: Some("https://api.example.com/\"v1\"".to_string()),
+            ..ApisConfig::default()
+        };
+        let content =
+            generate_sdk_client("PetsClient", &[], "../runtime", &apis_config, None, false);
+
+        assert!(content.contains("  baseUrl: \"https://api.example.com/\\\"v1\\\"\","));
+    }
+
+    #[test]
+    fn test_generate_sdk_client_without_security() {
+        let content = generate_sdk_client(
+            "PetsClient",
+            &["pets".to_string()],
+            "../runtime",
+            &ApisConfig::default(),
+            None,
+            false,
+        );
+
+        assert!(content.contains("export type PetsClientOptions = VikaClientOptions;"));
+        assert!(content.contains("const defaults: VikaClientOptions = {};"));
+        assert!(!content.contains("security"));
+    }
+
+    #[test]
+    fn test_generate_sdk_client_default_server() {
+        let content = generate_sdk_client(
+            "ShopClient",
+            &["shop".to_string()],
+            "../runtime",
+            &ApisConfig::default(),
+            Some("https://eu.api.example.com/v1"),
+            false,
+        );
+        assert!(content.contains("  baseUrl: \"https://eu.api.example.com/v1\","));
+
+        // apis.base_url wins over the spec's servers
+        let apis_config = ApisConfig {
+            base_url: Some("https://api.example.com".to_string()),
+            ..ApisConfig::default()
+        };
+        let content = generate_sdk_client(
+            "ShopClient",
+            &["shop".to_string()],
+            "../runtime",
+            &apis_config,
+            Some("https://eu.api.example.com/v1"),
+            false,
+        );
+        assert!(content.contains("  baseUrl: \"https://api.example.com\","));
+        assert!(!content.contains("eu.api.example.com"));
+    }
+}
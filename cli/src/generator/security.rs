@@ -0,0 +1,381 @@
+use crate::generator::utils::{doc_comment_text, sanitize_property_name, string_literal};
+use openapiv3::{
+    APIKeyLocation, OpenAPI, Operation, ReferenceOr, SecurityRequirement, SecurityScheme,
+};
+
+/// File name (relative to the spec's `apis` output) of the generated security module.
+pub const SECURITY_MODULE_FILE: &str = "security.ts";
+
+/// A security scheme from `components.securitySchemes` as understood by the runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecuritySchemeInfo {
+    pub name: String,
+    /// TypeScript object literal matching the runtime `SecuritySchemeDefinition` type
+    pub definition: String,
+    /// Credential type returned by the scheme's provider (`string` or `BasicCredentials`)
+    pub credential_type: &'static str,
+    pub description: String,
+}
+
+/// Collect the security schemes declared in `components.securitySchemes`, in spec order.
+pub fn collect_security_schemes(openapi: &OpenAPI) -> Vec<SecuritySchemeInfo> {
+    let Some(components) = &openapi.components else {
+        return Vec::new();
+    };
+
+    components
+        .security_schemes
+        .iter()
+        .filter_map(|(name, scheme_ref)| match scheme_ref {
+            ReferenceOr::Item(scheme) => Some(scheme_info(name, scheme)),
+            // References between security schemes are not resolved
+            ReferenceOr::Reference { .. } => None,
+        })
+        .collect()
+}
+
+fn scheme_info(name: &str, scheme: &SecurityScheme) -> SecuritySchemeInfo {
+    let (definition, credential_type, fallback_description) = match scheme {
+        SecurityScheme::APIKey {
+            location,
+            name: key_name,
+            ..
+        } => {
+            let location = match location {
+                APIKeyLocation::Header => "header",
+                APIKeyLocation::Query => "query",
+                APIKeyLocation::Cookie => "cookie",
+            };
+            (
+                format!(
+                    "{{ type: \"apiKey\", in: \"{}\", name: {} }}",
+                    location,
+                    string_literal(key_name)
+                ),
+                "string",
+                format!("API key sent in the `{}` {}", key_name, location),
+            )
+        }
+        SecurityScheme::HTTP {
+            scheme,
+            bearer_format,
+            ..
+        } => {
+            let scheme = scheme.to_lowercase();
+            let (credential_type, description) = match scheme.as_str() {
+                "basic" => ("BasicCredentials", "HTTP basic credentials".to_string()),
+                "bearer" => (
+                    "string",
+                    match bearer_format {
+                        Some(format) => format!("Bearer token ({})", format),
+                        None => "Bearer token".to_string(),
+                    },
+                ),
+                other => ("string", format!("`{}` authorization credentials", other)),
+            };
+            (
+                format!("{{ type: \"http\", scheme: {} }}", string_literal(&scheme)),
+                credential_type,
+                description,
+            )
+        }
+        SecurityScheme::OAuth2 { .. } => (
+            "{ type: \"oauth2\" }".to_string(),
+            "string",
+            "OAuth2 access token".to_string(),
+        ),
+        SecurityScheme::OpenIDConnect { .. } => (
+            "{ type: \"openIdConnect\" }".to_string(),
+            "string",
+            "OpenID Connect access token".to_string(),
+        ),
+    };
+
+    let description = match scheme {
+        SecurityScheme::APIKey { description, .. }
+        | SecurityScheme::HTTP { description, .. }
+        | SecurityScheme::OAuth2 { description, .. }
+        | SecurityScheme::OpenIDConnect { description, .. } => description
+            .as_ref()
+            .map(|d| d.lines().next().unwrap_or_default().trim().to_string())
+            .filter(|d| !d.is_empty())
+            .unwrap_or(fallback_description),
+    };
+
+    SecuritySchemeInfo {
+        name: name.to_string(),
+        definition,
+        credential_type,
+        description,
+    }
+}
+
+/// Security requirements that apply to an operation.
+///
+/// Operation-level `security` overrides the spec-level default; `Some(vec![])` means the
+/// operation is explicitly anonymous, `None` means the spec declares no requirements.
+pub fn operation_security<'a>(
+    openapi: &'a OpenAPI,
+    operation: &'a Operation,
+) -> Option<&'a Vec<SecurityRequirement>> {
+    operation.security.as_ref().or(openapi.security.as_ref())
+}
+
+/// Render security requirements as a TypeScript array literal, e.g. `[{ bearerAuth: [] }]`.
+pub fn requirements_literal(requirements: &[SecurityRequirement]) -> String {
+    let rendered: Vec<String> = requirements
+        .iter()
+        .map(|requirement| {
+            if requirement.is_empty() {
+                return "{}".to_string();
+            }
+            let entries: Vec<String> = requirement
+                .iter()
+                .map(|(name, scopes)| {
+                    let scopes: Vec<String> =
+                        scopes.iter().map(|scope| string_literal(scope)).collect();
+                    format!("{}: [{}]", sanitize_property_name(name), scopes.join(", "))
+                })
+                .collect();
+            format!("{{ {} }}", entries.join(", "))
+        })
+        .collect();
+    format!("[{}]", rendered.join(", "))
+}
+
+/// Generate the spec-level `security.ts` module (scheme definitions, typed credential
+/// providers and a `configureSecurity` helper). Returns `None` when the spec declares
+/// no security schemes.
+pub fn generate_security_module(openapi: &OpenAPI, runtime_import: &str) -> Option<String> {
+    let schemes = collect_security_schemes(openapi);
+    if schemes.is_empty() {
+        return None;
+    }
+
+    let scheme_names: Vec<String> = schemes
+        .iter()
+        .map(|scheme| string_literal(&scheme.name))
+        .collect();
+    let definitions: Vec<String> = schemes
+        .iter()
+        .map(|scheme| {
+            format!(
+                "  {}: {},",
+                sanitize_property_name(&scheme.name),
+                scheme.definition
+            )
+        })
+        .collect();
+    let credentials: Vec<String> = schemes
+        .iter()
+        .map(|scheme| {
+            format!(
+                "  /** {} */\n  {}?: CredentialProvider<{}>;",
+                doc_comment_text(&scheme.description),
+                sanitize_property_name(&scheme.name),
+                scheme.credential_type
+            )
+        })
+        .collect();
+    let example_scheme = sanitize_property_name(&schemes[0].name);
+    let example_value = if schemes[0].credential_type == "BasicCredentials" {
+        "({ username: \"user\", password: \"secret\" })"
+    } else {
+        "getToken()"
+    };
+
+    Some(format!(
+        r#"import {{
+  vikaClient,
+  type BasicCredentials,
+  type CredentialProvider,
+  type SecuritySchemeDefinition,
+  type VikaClient,
+}} from "{runtime_import}";
+
+/**
+ * Names of the security schemes declared in `components.securitySchemes`.
+ */
+export type SecuritySchemeName = {scheme_names};
+
+/**
+ * Security scheme definitions used by generated API functions to attach credentials.
+ */
+export const securitySchemes: Record<SecuritySchemeName, SecuritySchemeDefinition> = {{
+{definitions}
+}};
+
+/**
+ * Credential providers for each security scheme.
+ * A provider is called before every request that requires its scheme.
+ */
+export interface SecurityCredentials {{
+{credentials}
+}}
+
+/**
+ * Register credential providers on a VikaClient (defaults to the shared `vikaClient`).
+ *
+ * @example
+ * ```typescript
+ * configureSecurity({{ {example_scheme}: () => {example_value} }});
+ * ```
+ */
+export function configureSecurity(
+  credentials: SecurityCredentials,
+  client: VikaClient = vikaClient
+): void {{
+  for (const [scheme, provider] of Object.entries(credentials)) {{
+    client.setCredentials(scheme, provider);
+  }}
+}}
+"#,
+        runtime_import = runtime_import,
+        scheme_names = scheme_names.join(" | "),
+        definitions = definitions.join("\n"),
+        credentials = credentials.join("\n"),
+        example_scheme = example_scheme,
+        example_value = example_value,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_openapi(json: &str) -> OpenAPI {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const SPEC: &str = r#"{
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "security": [{"bearerAuth": []}],
+        "paths": {
+            "/health": {
+                "get": {"security": [], "responses": {"200": {"description": "OK"}}}
+            },
+            "/users": {
+                "get": {"responses": {"200": {"description": "OK"}}},
+                "post": {
+                    "security": [{"oauth": ["users:write"]}, {"apiKey": [], "basicAuth": []}],
+                    "responses": {"201": {"description": "Created"}}
+                }
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": {"type": "http", "scheme": "bearer", "bearerFormat": "JWT"},
+                "basicAuth": {"type": "http", "scheme": "basic"},
+                "apiKey": {"type": "apiKey", "in": "query", "name": "api_key"},
+                "oauth": {
+                    "type": "oauth2",
+                    "flows": {
+                        "clientCredentials": {"tokenUrl": "https://auth.example.com/token", "scopes": {}}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    fn operation<'a>(openapi: &'a OpenAPI, path: &str, method: &str) -> &'a Operation {
+        let item = openapi.paths.paths.get(path).unwrap().as_item().unwrap();
+        match method {
+            "get" => item.get.as_ref().unwrap(),
+            _ => item.post.as_ref().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_collect_security_schemes() {
+        let openapi = parse_openapi(SPEC);
+        let schemes = collect_security_schemes(&openapi);
+
+        assert_eq!(schemes.len(), 4);
+        assert_eq!(
+            schemes[0].definition,
+            "{ type: \"http\", scheme: \"bearer\" }"
+        );
+        assert_eq!(schemes[0].description, "Bearer token (JWT)");
+        assert_eq!(schemes[1].credential_type, "BasicCredentials");
+        assert_eq!(
+            schemes[2].definition,
+            "{ type: \"apiKey\", in: \"query\", name: \"api_key\" }"
+        );
+        assert_eq!(schemes[3].definition, "{ type: \"oauth2\" }");
+    }
+
+    #[test]
+    fn test_operation_security_overrides_spec_default() {
+        let openapi = parse_openapi(SPEC);
+
+        let anonymous = operation_security(&openapi, operation(&openapi, "/health", "get"));
+        assert_eq!(requirements_literal(anonymous.unwrap()), "[]");
+
+        let inherited = operation_security(&openapi, operation(&openapi, "/users", "get"));
+        assert_eq!(
+            requirements_literal(inherited.unwrap()),
+            "[{ bearerAuth: [] }]"
+        );
+
+        let explicit = operation_security(&openapi, operation(&openapi, "/users", "post"));
+        assert_eq!(
+            requirements_literal(explicit.unwrap()),
+            "[{ oauth: [\"users:write\"] }, { apiKey: [], basicAuth: [] }]"
+        );
+    }
+
+    #[test]
+    fn test_generate_security_module() {
+        let openapi = parse_openapi(SPEC);
+        let content = generate_security_module(&openapi, "../runtime").unwrap();
+
+        assert!(content.contains("from \"../runtime\";"));
+        assert!(content.contains(
+            "export type SecuritySchemeName = \"bearerAuth\" | \"basicAuth\" | \"apiKey\" | \"oauth\";"
+        ));
+        assert!(content.contains("  basicAuth?: CredentialProvider<BasicCredentials>;"));
+        assert!(content.contains("client.setCredentials(scheme, provider);"));
+    }
+
+    #[test]
+    fn test_generate_security_module_escapes_spec_values() {
+        let openapi = parse_openapi(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Test", "version": "1.0.0"},
+                "paths": {},
+                "components": {
+                    "securitySchemes": {
+                        "partner\"Key": {
+                            "type": "apiKey",
+                            "in": "header",
+                            "name": "X-\"Key",
+                            "description": "Partner key */ from the portal"
+                        }
+                    }
+                }
+            }"#,
+        );
+        let content = generate_security_module(&openapi, "../runtime").unwrap();
+
+        assert!(content.contains("export type SecuritySchemeName = \"partner\\\"Key\";"));
+        assert!(content.contains("name: \"X-\\\"Key\" }"));
+        assert!(content.contains("  /** Partner key *\\/ from the portal */"));
+
+        let requirement =
+            SecurityRequirement::from([("oauth".to_string(), vec!["users:\"write\"".to_string()])]);
+        assert_eq!(
+            requirements_literal(&[requirement]),
+            "[{ oauth: [\"users:\\\"write\\\"\"] }]"
+        );
+    }
+
+    #[test]
+    fn test_generate_security_module_without_schemes() {
+        let openapi = parse_openapi(
+            r#"{"openapi": "3.0.0", "info": {"title": "Test", "version": "1.0.0"}, "paths": {}}"#,
+        );
+        assert!(generate_security_module(&openapi, "../runtime").is_none());
+    }
+}
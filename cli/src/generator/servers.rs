@@ -0,0 +1,326 @@
+use crate::generator::utils::{
+    doc_comment_text, sanitize_property_name, string_literal, to_camel_case, to_pascal_case,
+};
+use openapiv3::{OpenAPI, Operation, ReferenceOr, Server};
+
+/// File name (relative to the spec's `apis` output) of the generated servers module.
+pub const SERVERS_MODULE_FILE: &str = "servers.ts";
+
+/// Resolve a server URL with the default value of each of its variables.
+pub fn resolve_server_url(server: &Server) -> String {
+    let mut url = server.url.clone();
+    if let Some(variables) = &server.variables {
+        for (name, variable) in variables {
+            url = url.replace(&format!("{{{}}}", name), &variable.default);
+        }
+    }
+    url
+}
+
+/// URL of the spec's first server with default variables, if the spec declares servers.
+pub fn default_server_url(openapi: &OpenAPI) -> Option<String> {
+    openapi.servers.first().map(resolve_server_url)
+}
+
+/// Server override of an operation, resolved with default variables.
+///
+/// Operation-level `servers` take precedence over path-level ones; `None` means the
+/// operation uses the client's base URL.
+pub fn operation_server_url(
+    openapi: &OpenAPI,
+    path: &str,
+    operation: &Operation,
+) -> Option<String> {
+    if let Some(server) = operation.servers.first() {
+        return Some(resolve_server_url(server));
+    }
+    match openapi.paths.paths.get(path) {
+        Some(ReferenceOr::Item(item)) => item.servers.first().map(resolve_server_url),
+        _ => None,
+    }
+}
+
+/// Text of a template literal: backslashes, backticks and `${` are escaped.
+fn template_literal_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+/// Property name of a server in the `servers` object, derived from its description
+/// (e.g. "Production server" -> `production`). Falls back to `server{n}`.
+fn server_key(server: &Server, index: usize) -> String {
+    let words: String = server
+        .description
+        .as_deref()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut words: Vec<&str> = words.split_whitespace().collect();
+    if words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| word.eq_ignore_ascii_case("server"))
+    {
+        words.pop();
+    }
+    let key = to_camel_case(&words.join(" ").to_lowercase());
+    if key.is_empty() || key.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("server{}", index + 1)
+    } else {
+        key
+    }
+}
+
+/// Generate the spec-level `servers.ts` module: one entry per server in the spec's
+/// `servers` list (a URL, or a function of its variables) and the default server.
+/// Returns `None` when the spec declares no servers.
+pub fn generate_servers_module(openapi: &OpenAPI) -> Option<String> {
+    if openapi.servers.is_empty() {
+        return None;
+    }
+
+    let mut keys: Vec<String> = Vec::new();
+    let mut variable_types = Vec::new();
+    let mut entries = Vec::new();
+    for (index, server) in openapi.servers.iter().enumerate() {
+        let mut key = server_key(server, index);
+        if keys.contains(&key) {
+            key = format!("{}{}", key, index + 1);
+        }
+        keys.push(key.clone());
+
+        let description = doc_comment_text(server.description.as_deref().unwrap_or(&server.url));
+        let variables = server
+            .variables
+            .as_ref()
+            .filter(|variables| !variables.is_empty());
+        let Some(variables) = variables else {
+            entries.push(format!(
+                "  /** {} */\n  {}: {},",
+                description,
+                key,
+                string_literal(&server.url)
+            ));
+            continue;
+        };
+
+        let type_name = format!("{}ServerVariables", to_pascal_case(&key));
+        let mut fields = Vec::new();
+        let mut param_docs = Vec::new();
+        let mut url = template_literal_text(&server.url);
+        for (name, variable) in variables {
+            let field_type = if variable.enumeration.is_empty() {
+                "string".to_string()
+            } else {
+                variable
+                    .enumeration
+                    .iter()
+                    .map(|value| string_literal(value))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            };
+            let field_name = sanitize_property_name(name);
+            let field_doc = doc_comment_text(&match &variable.description {
+                Some(text) => format!("{} (default `\"{}\"`)", text, variable.default),
+                None => format!("Default `\"{}\"`", variable.default),
+            });
+            fields.push(format!(
+                "  /** {} */\n  {}?: {};",
+                field_doc, field_name, field_type
+            ));
+            param_docs.push(format!("   * @param variables.{} {}", name, field_doc));
+
+            let access = if field_name.starts_with('"') {
+                format!("variables[{}]", field_name)
+            } else {
+                format!("variables.{}", field_name)
+            };
+            url = url.replace(
+                &format!("{{{}}}", name),
+                &format!("${{{} ?? {}}}", access, string_literal(&variable.default)),
+            );
+        }
+        variable_types.push(format!(
+            "/**\n * Variables of the `{}` server.\n */\nexport interface {} {{\n{}\n}}",
+            key,
+            type_name,
+            fields.join("\n")
+        ));
+        entries.push(format!(
+            "  /**\n   * {}\n   *\n{}\n   */\n  {}: (variables: {} = {{}}): string =>\n    `{}`,",
+            description,
+            param_docs.join("\n"),
+            key,
+            type_name,
+            url
+        ));
+    }
+
+    let default_server = resolve_server_url(&openapi.servers[0]);
+    let example = match openapi.servers[0].variables.as_ref() {
+        Some(variables) if !variables.is_empty() => format!("servers.{}()", keys[0]),
+        _ => format!("servers.{}", keys[0]),
+    };
+    let variable_types = if variable_types.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n\n", variable_types.join("\n\n"))
+    };
+
+    Some(format!(
+        r#"{variable_types}/**
+ * Servers declared in the spec's `servers` list.
+ *
+ * @example
+ * ```typescript
+ * const client = new VikaClient({{ baseUrl: {example} }});
+ * ```
+ */
+export const servers = {{
+{entries}
+}};
+
+/**
+ * Names of the servers declared by the spec.
+ */
+export type ServerName = keyof typeof servers;
+
+/**
+ * URL of the spec's first server with default variables.
+ */
+export const defaultServer = {default_server};
+"#,
+        variable_types = variable_types,
+        example = example,
+        entries = entries.join("\n"),
+        default_server = string_literal(&default_server),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_openapi(json: &str) -> OpenAPI {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const SPEC: &str = r#"{
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "servers": [
+            {"url": "https://api.example.com/v1", "description": "Production server"},
+            {
+                "url": "https://{region}.staging.example.com/{basePath}",
+                "description": "Staging",
+                "variables": {
+                    "region": {"enum": ["eu", "us"], "default": "eu", "description": "Deployment region"},
+                    "basePath": {"default": "v1"}
+                }
+            },
+            {"url": "http://localhost:8080"}
+        ],
+        "paths": {
+            "/files": {
+                "servers": [{"url": "https://files.example.com"}],
+                "get": {"responses": {"200": {"description": "OK"}}},
+                "post": {
+                    "servers": [{"url": "https://{host}/upload", "variables": {"host": {"default": "upload.example.com"}}}],
+                    "responses": {"201": {"description": "Created"}}
+                }
+            },
+            "/users": {
+                "get": {"responses": {"200": {"description": "OK"}}}
+            }
+        }
+    }"#;
+
+    fn operation<'a>(openapi: &'a OpenAPI, path: &str, method: &str) -> &'a Operation {
+        let item = openapi.paths.paths.get(path).unwrap().as_item().unwrap();
+        match method {
+            "get" => item.get.as_ref().unwrap(),
+            _ => item.post.as_ref().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_default_and_operation_servers() {
+        let openapi = parse_openapi(SPEC);
+
+        assert_eq!(
+            default_server_url(&openapi).as_deref(),
+            Some("https://api.example.com/v1")
+        );
+        assert_eq!(
+            resolve_server_url(&openapi.servers[1]),
+            "https://eu.staging.example.com/v1"
+        );
+        assert_eq!(
+            operation_server_url(&openapi, "/files", operation(&openapi, "/files", "get"))
+                .as_deref(),
+            Some("https://files.example.com")
+        );
+        assert_eq!(
+            operation_server_url(&openapi, "/files", operation(&openapi, "/files", "post"))
+                .as_deref(),
+            Some("https://upload.example.com/upload")
+        );
+        assert_eq!(
+            operation_server_url(&openapi, "/users", operation(&openapi, "/users", "get")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_generate_servers_module() {
+        let openapi = parse_openapi(SPEC);
+        let content = generate_servers_module(&openapi).unwrap();
+
+        assert!(content.contains("  production: \"https://api.example.com/v1\","));
+        assert!(content.contains("export interface StagingServerVariables {"));
+        assert!(content.contains("  region?: \"eu\" | \"us\";"));
+        assert!(content.contains(
+            "`https://${variables.region ?? \"eu\"}.staging.example.com/${variables.basePath ?? \"v1\"}`"
+        ));
+        assert!(content.contains("  server3: \"http://localhost:8080\","));
+        assert!(content.contains("export const defaultServer = \"https://api.example.com/v1\";"));
+    }
+
+    #[test]
+    fn test_generate_servers_module_escapes_spec_values() {
+        let openapi = parse_openapi(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Test", "version": "1.0.0"},
+                "servers": [
+                    {"url": "https://api.example.com/\"v1\"", "description": "Main */ server"},
+                    {
+                        "url": "https://{host}/`${path}`",
+                        "description": "Custom",
+                        "variables": {"host": {"enum": ["a\"b", "c"], "default": "a\"b"}}
+                    }
+                ],
+                "paths": {}
+            }"#,
+        );
+        let content = generate_servers_module(&openapi).unwrap();
+
+        assert!(content.contains("  /** Main *\\/ server */"));
+        assert!(content.contains("  main: \"https://api.example.com/\\\"v1\\\"\","));
+        assert!(content.contains("  host?: \"a\\\"b\" | \"c\";"));
+        assert!(content.contains("`https://${variables.host ?? \"a\\\"b\"}/\\`\\${path}\\``"));
+        assert!(content
+            .contains("export const defaultServer = \"https://api.example.com/\\\"v1\\\"\";"));
+    }
+
+    #[test]
+    fn test_generate_servers_module_without_servers() {
+        let openapi = parse_openapi(
+            r#"{"openapi": "3.0.0", "info": {"title": "Test", "version": "1.0.0"}, "paths": {}}"#,
+        );
+        assert!(generate_servers_module(&openapi).is_none());
+    }
+}
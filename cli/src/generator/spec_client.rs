@@ -0,0 +1,375 @@
+use crate::config::model::{ApisConfig, RetryPolicyConfig};
+use crate::generator::utils::string_literal;
+
+/// File name (relative to the spec's `apis` output) of the spec's client instance module.
+pub const SPEC_CLIENT_FILE: &str = "vika-client.ts";
+
+/// Import path of the spec's client instance module, `module_depth` directories below the
+/// spec's `apis` output (0 for spec-level modules such as `security.ts`).
+pub fn spec_client_import_path(module_depth: usize) -> String {
+    let prefix = if module_depth == 0 {
+        "./".to_string()
+    } else {
+        "../".repeat(module_depth)
+    };
+    format!("{}{}", prefix, SPEC_CLIENT_FILE.trim_end_matches(".ts"))
+}
+
+/// Render `apis.retry_policy` as the runtime `RetryPolicy` object literal
+/// (e.g. `{ statusCodes: [429, 503], maxDelay: 10000 }`). Unset fields are omitted.
+pub fn retry_policy_literal(policy: &RetryPolicyConfig) -> String {
+    let mut fields = Vec::new();
+    if let Some(ref methods) = policy.methods {
+        let methods: Vec<String> = methods
+            .iter()
+            .map(|method| format!("\"{}\"", method.to_uppercase()))
+            .collect();
+        fields.push(format!("methods: [{}]", methods.join(", ")));
+    }
+    if let Some(ref status_codes) = policy.status_codes {
+        let status_codes: Vec<String> = status_codes.iter().map(|s| s.to_string()).collect();
+        fields.push(format!("statusCodes: [{}]", status_codes.join(", ")));
+    }
+    if let Some(max_delay) = policy.max_delay {
+        fields.push(format!("maxDelay: {}", max_delay));
+    }
+    if let Some(respect_retry_after) = policy.respect_retry_after {
+        fields.push(format!("respectRetryAfter: {}", respect_retry_after));
+    }
+    if let Some(ref header) = policy.idempotency_header {
+        fields.push(format!("idempotencyHeader: {}", string_literal(header)));
+    }
+    if fields.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", fields.join(", "))
+    }
+}
+
+/// Generate the spec-level `vika-client.ts` module. It re-exports the shared runtime and
+/// declares the spec's own `vikaClient` (plus `axiosClient` for the axios style) configured
+/// from the spec's `apis` settings, so specs share the runtime code but not its configuration.
+///
+/// `default_server` (the spec's first server) is used when `apis.base_url` is not set.
+pub fn generate_spec_client(
+    spec_name: &str,
+    runtime_import: &str,
+    apis_config: &ApisConfig,
+    default_server: Option<&str>,
+) -> String {
+    let is_axios = apis_config.style == "axios";
+
+    let mut options = Vec::new();
+    let mut axios_options = Vec::new();
+    if let Some(base_url) = apis_config.base_url.as_deref().or(default_server) {
+        let line = format!("  baseUrl: {},", string_literal(base_url));
+        options.push(line.clone());
+        axios_options.push(line);
+    }
+    if let Some(timeout) = apis_config.timeout {
+        options.push(format!("  timeout: {},", timeout));
+        axios_options.push(format!("  timeout: {},", timeout));
+    }
+    if let Some(retries) = apis_config.retries {
+        options.push(format!("  retries: {},", retries));
+    }
+    if let Some(retry_delay) = apis_config.retry_delay {
+        options.push(format!("  retryDelay: {},", retry_delay));
+    }
+    if let Some(ref policy) = apis_config.retry_policy {
+        options.push(format!("  retryPolicy: {},", retry_policy_literal(policy)));
+    }
+    let tracing = apis_config.tracing == Some(true);
+    if tracing {
+        options.push("  tracer: createTracer(),".to_string());
+    }
+    if let Some(ref headers) = apis_config.headers {
+        let headers_json = serde_json::to_string(headers).unwrap_or_else(|_| "{}".to_string());
+        options.push(format!("  headers: {},", headers_json));
+        axios_options.push(format!("  headers: {},", headers_json));
+    }
+    if matches!(
+        apis_config.header_strategy.as_str(),
+        "bearerToken" | "fixed" | "consumerInjected"
+    ) {
+        let line = format!("  auth: \"{}\",", apis_config.header_strategy);
+        options.push(line.clone());
+        axios_options.push(line);
+    }
+    if apis_config.validate_responses != "off" {
+        let line = format!(
+            "  validateResponses: \"{}\",",
+            apis_config.validate_responses
+        );
+        options.push(line.clone());
+        axios_options.push(line);
+    }
+    if apis_config.validate_requests != "off" {
+        let line = format!("  validateRequests: \"{}\",", apis_config.validate_requests);
+        options.push(line.clone());
+        axios_options.push(line);
+    }
+
+    let mut imports = vec![format!(
+        "import {{ {} }} from \"{}\";",
+        if tracing {
+            "VikaClient, createTracer"
+        } else {
+            "VikaClient"
+        },
+        runtime_import
+    )];
+    let mut tracing_hook = String::new();
+    if tracing {
+        imports.push(format!(
+            "import type {{ TracerOptions }} from \"{}\";",
+            runtime_import
+        ));
+        tracing_hook = r#"
+/**
+ * Replace the tracer of this spec's client with one that hands every finished span
+ * to `onEnd`. Spans are only propagated as `traceparent` headers until this is called.
+ *
+ * @example
+ * ```typescript
+ * configureTracing({
+ *   onEnd: (span) => analytics.track("api_call", span),
+ * });
+ * ```
+ */
+export function configureTracing(options: TracerOptions): void {
+  vikaClient.useTracer(createTracer(options));
+}
+"#
+        .to_string();
+    }
+    let mut token_refresh = String::new();
+    if let Some(ref config) = apis_config.token_refresh {
+        imports.push(format!(
+            "import type {{ TokenRefreshOptions }} from \"{}\";",
+            runtime_import
+        ));
+        let status_codes: Vec<String> = config
+            .status_codes
+            .clone()
+            .unwrap_or_else(|| vec![401])
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        token_refresh = format!(
+            r#"
+/**
+ * Refresh credentials when a request of this spec is rejected with {statuses} and
+ * replay it. Concurrent rejections share a single refresh.
+ *
+ * @example
+ * ```typescript
+ * configureTokenRefresh({{
+ *   refresh: async () => {{
+ *     token = await renewToken();
+ *   }},
+ *   onRefreshFailed: () => redirectToLogin(),
+ * }});
+ * ```
+ */
+export function configureTokenRefresh(options: Omit<TokenRefreshOptions, "statusCodes">): void {{
+  vikaClient.useTokenRefresh({{ statusCodes: [{status_codes}], ...options }});
+}}
+"#,
+            statuses = status_codes.join(" or "),
+            status_codes = status_codes.join(", "),
+        );
+    }
+    let mut axios_client = String::new();
+    if is_axios {
+        imports.push(format!(
+            "import {{ VikaAxiosClient }} from \"{}/axios-client\";",
+            runtime_import
+        ));
+        axios_client = format!(
+            r#"
+/**
+ * Axios client of the `{spec_name}` spec, configured from its `apis` settings in .vika.json.
+ * Generated functions of this spec send their requests through it.
+ */
+export const axiosClient = new VikaAxiosClient({{
+{options}
+}});
+"#,
+            spec_name = spec_name,
+            options = axios_options.join("\n"),
+        );
+    }
+
+    format!(
+        r#"{imports}
+
+export * from "{runtime_import}";
+
+/**
+ * Client of the `{spec_name}` spec, configured from its `apis` settings in .vika.json.
+ * Register middleware and credentials for this spec here, e.g.
+ * `vikaClient.useBeforeRequest(...)`.
+ */
+export const vikaClient = new VikaClient({{
+{options}
+}});
+{tracing_hook}{token_refresh}{axios_client}"#,
+        imports = imports.join("\n"),
+        runtime_import = runtime_import,
+        spec_name = spec_name,
+        options = options.join("\n"),
+        tracing_hook = tracing_hook,
+        token_refresh = token_refresh,
+        axios_client = axios_client,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::model::TokenRefreshConfig;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_generate_spec_client() {
+        let apis_config = ApisConfig {
+            base_url: Some("https://orders.example.com".to_string()),
+            timeout: Some(5000),
+            retries: Some(2),
+            headers: Some(HashMap::from([(
+                "X-Service".to_string(),
+                "orders".to_string(),
+            )])),
+            ..ApisConfig::default()
+        };
+        let content = generate_spec_client(
+            "orders",
+            "../../runtime",
+            &apis_config,
+            Some("https://api.example.com"),
+        );
+
+        assert!(content.contains("import { VikaClient } from \"../../runtime\";"));
+        assert!(content.contains("export * from \"../../runtime\";"));
+        assert!(content.contains("export const vikaClient = new VikaClient({"));
+        assert!(content.contains("  baseUrl: \"https://orders.example.com\","));
+        assert!(content.contains("  timeout: 5000,"));
+        assert!(content.contains("  retries: 2,"));
+        assert!(content.contains("  headers: {\"X-Service\":\"orders\"},"));
+        assert!(!content.contains("validateRequests"));
+        assert!(!content.contains("axiosClient"));
+
+        let apis_config = ApisConfig {
+            validate_requests: "dev-only".to_string(),
+            validate_responses: "always".to_string(),
+            ..ApisConfig::default()
+        };
+        let content = generate_spec_client("orders", "../../runtime", &apis_config, None);
+        assert!(content.contains("  validateResponses: \"always\","));
+        assert!(content.contains("  validateRequests: \"dev-only\","));
+    }
+
+    #[test]
+    fn test_generate_spec_client_axios_default_server() {
+        let apis_config = ApisConfig {
+            style: "axios".to_string(),
+            ..ApisConfig::default()
+        };
+        let content = generate_spec_client(
+            "billing",
+            "../runtime",
+            &apis_config,
+            Some("https://billing.example.com"),
+        );
+
+        assert!(content.contains("import { VikaAxiosClient } from \"../runtime/axios-client\";"));
+        assert!(content.contains("export const axiosClient = new VikaAxiosClient({"));
+        assert!(content.contains("  baseUrl: \"https://billing.example.com\","));
+        let axios_client = content.split("new VikaAxiosClient").nth(1).unwrap();
+        assert!(axios_client.contains("  auth: \"consumerInjected\","));
+    }
+
+    #[test]
+    fn test_generate_spec_client_escapes_server_url() {
+        let content = generate_spec_client(
+            "orders",
+            "../runtime",
+            &ApisConfig::default(),
+            Some("https://api.example.com/\"; alert(1); \"\\"),
+        );
+
+        assert!(
+            content.contains("  baseUrl: \"https://api.example.com/\\\"; alert(1); \\\"\\\\\",")
+        );
+    }
+
+    #[test]
+    fn test_generate_spec_client_token_refresh() {
+        let apis_config = ApisConfig {
+            token_refresh: Some(TokenRefreshConfig {
+                status_codes: Some(vec![401, 419]),
+            }),
+            ..ApisConfig::default()
+        };
+        let content = generate_spec_client("orders", "../runtime", &apis_config, None);
+
+        assert!(content.contains("import type { TokenRefreshOptions } from \"../runtime\";"));
+        assert!(content.contains(
+            "export function configureTokenRefresh(options: Omit<TokenRefreshOptions, \"statusCodes\">): void {"
+        ));
+        assert!(content
+            .contains("  vikaClient.useTokenRefresh({ statusCodes: [401, 419], ...options });"));
+
+        let content = generate_spec_client("orders", "../runtime", &ApisConfig::default(), None);
+        assert!(!content.contains("configureTokenRefresh"));
+        assert!(!content.contains("configureTracing"));
+    }
+
+    #[test]
+    fn test_generate_spec_client_tracing() {
+        let apis_config = ApisConfig {
+            tracing: Some(true),
+            ..ApisConfig::default()
+        };
+        let content = generate_spec_client("orders", "../runtime", &apis_config, None);
+
+        assert!(content.contains("import { VikaClient, createTracer } from \"../runtime\";"));
+        assert!(content.contains("  tracer: createTracer(),"));
+        assert!(content.contains("import type { TracerOptions } from \"../runtime\";"));
+        assert!(content.contains(
+            "export function configureTracing(options: TracerOptions): void {\n  vikaClient.useTracer(createTracer(options));"
+        ));
+    }
+
+    #[test]
+    fn test_retry_policy_literal() {
+        let policy = RetryPolicyConfig {
+            methods: Some(vec!["get".to_string(), "put".to_string()]),
+            status_codes: Some(vec![429, 503]),
+            max_delay: Some(10000),
+            ..RetryPolicyConfig::default()
+        };
+        assert_eq!(
+            retry_policy_literal(&policy),
+            "{ methods: [\"GET\", \"PUT\"], statusCodes: [429, 503], maxDelay: 10000 }"
+        );
+        assert_eq!(retry_policy_literal(&RetryPolicyConfig::default()), "{}");
+
+        let policy = RetryPolicyConfig {
+            idempotency_header: Some("X-Key\"".to_string()),
+            ..RetryPolicyConfig::default()
+        };
+        assert_eq!(
+            retry_policy_literal(&policy),
+            "{ idempotencyHeader: \"X-Key\\\"\" }"
+        );
+    }
+
+    #[test]
+    fn test_spec_client_import_path() {
+        assert_eq!(spec_client_import_path(0), "./vika-client");
+        assert_eq!(spec_client_import_path(1), "../vika-client");
+        assert_eq!(spec_client_import_path(2), "../../vika-client");
+    }
+}
@@ -0,0 +1,203 @@
+use crate::generator::swagger_parser::{get_schema_name_from_ref, resolve_response_ref};
+use openapiv3::{MediaType, OpenAPI, Operation, ReferenceOr, Schema, SchemaKind, StatusCode, Type};
+
+/// Media type of Server-Sent Events responses.
+const SSE_MEDIA_TYPE: &str = "text/event-stream";
+/// Media types of newline-delimited JSON responses.
+const NDJSON_MEDIA_TYPES: [&str; 4] = [
+    "application/x-ndjson",
+    "application/ndjson",
+    "application/jsonl",
+    "application/x-jsonlines",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// `text/event-stream`: events with a type, id and data
+    Sse,
+    /// Newline-delimited JSON: one value per line
+    Ndjson,
+}
+
+impl StreamFormat {
+    /// Runtime client method streaming this format.
+    pub fn client_method(self) -> &'static str {
+        match self {
+            StreamFormat::Sse => "eventStream",
+            StreamFormat::Ndjson => "ndjsonStream",
+        }
+    }
+}
+
+/// Streaming success response of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamResponse {
+    pub format: StreamFormat,
+    pub status: u16,
+    /// Media type key of the response content (e.g. "text/event-stream")
+    pub media_type: String,
+    /// Component schema of each event, from `x-event-schema` or the media type schema
+    pub event_schema: Option<String>,
+    /// TypeScript type of inline primitive event schemas ("any" otherwise)
+    pub inline_type: &'static str,
+}
+
+/// Find the first 2xx response producing `text/event-stream` or NDJSON.
+/// Responses that also produce `application/json` are left to the regular client.
+pub fn detect_stream(openapi: &OpenAPI, operation: &Operation) -> Option<StreamResponse> {
+    operation
+        .responses
+        .responses
+        .iter()
+        .find_map(|(status, response)| {
+            let StatusCode::Code(code @ 200..=299) = status else {
+                return None;
+            };
+            let response = match response {
+                ReferenceOr::Item(response) => response.clone(),
+                ReferenceOr::Reference { reference } => {
+                    resolve_response_ref(openapi, reference).ok()?.into_item()?
+                }
+            };
+            if response.content.contains_key("application/json") {
+                return None;
+            }
+            let (format, media_type, media) =
+                response.content.iter().find_map(|(media_type, media)| {
+                    let essence = media_type.split(';').next().unwrap_or_default().trim();
+                    if essence.eq_ignore_ascii_case(SSE_MEDIA_TYPE) {
+                        Some((StreamFormat::Sse, media_type, media))
+                    } else if NDJSON_MEDIA_TYPES
+                        .iter()
+                        .any(|ndjson| essence.eq_ignore_ascii_case(ndjson))
+                    {
+                        Some((StreamFormat::Ndjson, media_type, media))
+                    } else {
+                        None
+                    }
+                })?;
+
+            let (event_schema, inline_type) = event_schema(operation, media);
+            Some(StreamResponse {
+                format,
+                status: *code,
+                media_type: media_type.clone(),
+                event_schema,
+                inline_type,
+            })
+        })
+}
+
+/// Event schema of a streaming media type. `x-event-schema` on the media type or the
+/// operation (a `$ref` object or a component name) takes precedence over the media type
+/// schema; an array schema describes its items.
+fn event_schema(operation: &Operation, media: &MediaType) -> (Option<String>, &'static str) {
+    let extension = media
+        .extensions
+        .get("x-event-schema")
+        .or_else(|| operation.extensions.get("x-event-schema"));
+    if let Some(value) = extension {
+        let name = match value {
+            serde_json::Value::String(name) => {
+                get_schema_name_from_ref(name).or_else(|| Some(name.clone()))
+            }
+            serde_json::Value::Object(object) => object
+                .get("$ref")
+                .and_then(|reference| reference.as_str())
+                .and_then(get_schema_name_from_ref),
+            _ => None,
+        };
+        if name.is_some() {
+            return (name, "any");
+        }
+    }
+
+    match &media.schema {
+        Some(ReferenceOr::Reference { reference }) => (get_schema_name_from_ref(reference), "any"),
+        Some(ReferenceOr::Item(schema)) => match &schema.schema_kind {
+            SchemaKind::Type(Type::Array(array)) => match &array.items {
+                Some(ReferenceOr::Reference { reference }) => {
+                    (get_schema_name_from_ref(reference), "any")
+                }
+                Some(ReferenceOr::Item(items)) => (None, primitive_type(items)),
+                None => (None, "any"),
+            },
+            _ => (None, primitive_type(schema)),
+        },
+        None => (None, "any"),
+    }
+}
+
+fn primitive_type(schema: &Schema) -> &'static str {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(_)) => "string",
+        SchemaKind::Type(Type::Number(_)) | SchemaKind::Type(Type::Integer(_)) => "number",
+        SchemaKind::Type(Type::Boolean(_)) => "boolean",
+        _ => "any",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_operation(responses: &str, extensions: &str) -> (OpenAPI, Operation) {
+        let openapi: OpenAPI = serde_json::from_str(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Test", "version": "1.0.0"}},
+                "paths": {{"/events": {{"get": {{"responses": {}{}}}}}}},
+                "components": {{"schemas": {{"OrderEvent": {{"type": "object"}}}}}}}}"#,
+            responses, extensions
+        ))
+        .unwrap();
+        let operation = openapi.paths.paths["/events"]
+            .as_item()
+            .unwrap()
+            .get
+            .clone()
+            .unwrap();
+        (openapi, operation)
+    }
+
+    #[test]
+    fn test_detect_event_stream() {
+        let (openapi, operation) = parse_operation(
+            r##"{"200": {"description": "OK", "content": {"text/event-stream; charset=utf-8": {
+                "schema": {"$ref": "#/components/schemas/OrderEvent"}}}}}"##,
+            "",
+        );
+        let stream = detect_stream(&openapi, &operation).unwrap();
+        assert_eq!(stream.format, StreamFormat::Sse);
+        assert_eq!(stream.status, 200);
+        assert_eq!(stream.event_schema.as_deref(), Some("OrderEvent"));
+    }
+
+    #[test]
+    fn test_detect_ndjson_with_event_schema_extension() {
+        let (openapi, operation) = parse_operation(
+            r#"{"200": {"description": "OK", "content": {"application/x-ndjson": {"schema": {"type": "string"}}}}}"#,
+            r##", "x-event-schema": {"$ref": "#/components/schemas/OrderEvent"}"##,
+        );
+        let stream = detect_stream(&openapi, &operation).unwrap();
+        assert_eq!(stream.format, StreamFormat::Ndjson);
+        assert_eq!(stream.event_schema.as_deref(), Some("OrderEvent"));
+
+        let (openapi, operation) = parse_operation(
+            r#"{"200": {"description": "OK", "content": {"application/x-ndjson": {"schema": {"type": "string"}}}}}"#,
+            "",
+        );
+        let stream = detect_stream(&openapi, &operation).unwrap();
+        assert_eq!(stream.event_schema, None);
+        assert_eq!(stream.inline_type, "string");
+    }
+
+    #[test]
+    fn test_json_responses_are_not_streamed() {
+        let (openapi, operation) = parse_operation(
+            r#"{"200": {"description": "OK", "content": {
+                "application/json": {"schema": {"type": "object"}},
+                "text/event-stream": {"schema": {"type": "string"}}}}}"#,
+            "",
+        );
+        assert!(detect_stream(&openapi, &operation).is_none());
+    }
+}
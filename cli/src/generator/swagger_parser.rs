@@ -1,4 +1,6 @@
 use crate::error::{FileSystemError, NetworkError, Result, SchemaError};
+use crate::generator::streaming::detect_stream;
+use crate::generator::symbols::{default_function_name, resolve_operation_names, Collision};
 use openapiv3::{OpenAPI, Operation, Parameter, PathItem, ReferenceOr, Schema};
 use std::collections::HashMap;
 
@@ -16,6 +18,37 @@ pub struct OperationInfo {
     pub method: String,
     pub path: String,
     pub operation: Operation,
+    /// Function name of the operation within its module, free of collisions
+    pub function_name: String,
+}
+
+impl ParsedSpec {
+    /// Give the operations of every module collision-free function names, applying
+    /// `apis.rename` overrides. Returns the names that had to be disambiguated.
+    pub fn resolve_operation_names(&mut self, renames: &HashMap<String, String>) -> Vec<Collision> {
+        let mut modules: Vec<&String> = self.operations_by_tag.keys().collect();
+        modules.sort();
+        let modules: Vec<String> = modules.into_iter().cloned().collect();
+
+        let mut collisions = Vec::new();
+        for module in modules {
+            let schema_names = self
+                .module_schemas
+                .get(&module)
+                .cloned()
+                .unwrap_or_default();
+            if let Some(operations) = self.operations_by_tag.get_mut(&module) {
+                collisions.extend(resolve_operation_names(
+                    &self.openapi,
+                    &module,
+                    operations,
+                    &schema_names,
+                    renames,
+                ));
+            }
+        }
+        collisions
+    }
 }
 
 pub async fn fetch_and_parse_spec(spec_path: &str) -> Result<ParsedSpec> {
@@ -85,14 +118,16 @@ fn parse_spec_content(content: &str, spec_path: &str) -> Result<ParsedSpec> {
     let schemas = extract_schemas(&openapi);
     let (module_schemas, _) = map_modules_to_schemas(&openapi, &operations_by_tag, &schemas)?;
 
-    Ok(ParsedSpec {
+    let mut parsed = ParsedSpec {
         openapi,
         modules,
         operations_by_tag,
         schemas,
         module_schemas,
         common_schemas: Vec::new(), // Will be filtered based on selected modules
-    })
+    };
+    parsed.resolve_operation_names(&HashMap::new());
+    Ok(parsed)
 }
 
 async fn fetch_remote_spec(url: &str) -> Result<String> {
@@ -184,6 +219,7 @@ fn add_operation(
                     method: method.to_string(),
                     path: path.to_string(),
                     operation: op.clone(),
+                    function_name: default_function_name(method, path, op.operation_id.as_deref()),
                 });
         } else {
             for tag in tags {
@@ -191,6 +227,7 @@ fn add_operation(
                     method: method.to_string(),
                     path: path.to_string(),
                     operation: op.clone(),
+                    function_name: default_function_name(method, path, op.operation_id.as_deref()),
                 });
             }
         }
@@ -429,6 +466,14 @@ pub fn extract_schemas_for_operation(
         }
     }
 
+    // Event schema of `text/event-stream` and NDJSON responses
+    if let Some(ref_name) = detect_stream(openapi, operation).and_then(|stream| stream.event_schema)
+    {
+        if !schema_names.contains(&ref_name) {
+            schema_names.push(ref_name);
+        }
+    }
+
     Ok(schema_names)
 }
 
@@ -0,0 +1,496 @@
+use crate::generator::api_client::generate_function_name_from_path;
+use crate::generator::swagger_parser::{resolve_parameter_ref, resolve_ref, OperationInfo};
+use crate::generator::utils::{to_camel_case, to_pascal_case};
+use openapiv3::{
+    OpenAPI, Operation, ParameterSchemaOrContent, ReferenceOr, SchemaKind, StatusCode, Type,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Suffixes of the types generated from an operation's name (`{Operation}Responses`, ...).
+const OPERATION_TYPE_SUFFIXES: [&str; 10] = [
+    "Responses",
+    "Errors",
+    "Error",
+    "Headers",
+    "Params",
+    "QueryParams",
+    "PathParams",
+    "Page",
+    "Item",
+    "Event",
+];
+
+/// Words that cannot name a function, class or variable declaration in strict-mode TypeScript.
+const RESERVED_WORDS: [&str; 48] = [
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Whether `name` is a reserved word that can't be declared, even though it is a valid
+/// property name (`client.delete` is fine, `function delete()` is not).
+pub fn is_reserved_word(name: &str) -> bool {
+    RESERVED_WORDS.contains(&name)
+}
+
+/// Whether `name` can be used as a TypeScript identifier (function, class or variable
+/// name, or an unquoted property key).
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {
+            chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
+
+/// A generated name that clashed with an earlier one and was disambiguated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub module: String,
+    /// What the name was generated for, e.g. "GET /pets/{id}"
+    pub source: String,
+    /// The name it would have had
+    pub name: String,
+    /// The name it was given instead
+    pub resolved: String,
+}
+
+/// Identifiers declared by one generated module. Functions and types live in separate
+/// TypeScript namespaces, so only names of the same kind clash.
+#[derive(Debug, Default)]
+pub struct SymbolTable {
+    functions: HashSet<String>,
+    types: HashSet<String>,
+}
+
+impl SymbolTable {
+    /// Declare a type that generated names must not reuse (e.g. a schema interface).
+    pub fn reserve_type(&mut self, name: String) {
+        self.types.insert(name);
+    }
+
+    /// Claim a function name along with its hook and the types derived from it, including
+    /// one `{Operation}{Status}Error` class per `error_statuses` entry (`404`, `4XX`,
+    /// `Default`). When any of them is taken, the first free `{name}2`, `{name}3`, ... is
+    /// claimed instead.
+    pub fn claim_operation(&mut self, name: &str, error_statuses: &[String]) -> String {
+        let mut candidate = name.to_string();
+        let mut counter = 2;
+        while self.operation_taken(&candidate, error_statuses) {
+            candidate = format!("{}{}", name, counter);
+            counter += 1;
+        }
+        self.types
+            .extend(operation_types(&candidate, error_statuses));
+        self.functions.extend(operation_functions(&candidate));
+        candidate
+    }
+
+    fn operation_taken(&self, name: &str, error_statuses: &[String]) -> bool {
+        operation_functions(name).any(|name| self.functions.contains(&name))
+            || operation_types(name, error_statuses).any(|name| self.types.contains(&name))
+    }
+}
+
+/// Functions generated from an operation's function name: the function itself, its hook,
+/// the `paginate{Op}`/`fetchAll{Op}` helpers and the `{op}Handler`/`{op}ErrorHandler` mocks.
+fn operation_functions(name: &str) -> impl Iterator<Item = String> {
+    let type_base = to_pascal_case(name);
+    [
+        name.to_string(),
+        hook_name(name),
+        format!("paginate{}", type_base),
+        format!("fetchAll{}", type_base),
+        format!("{}Handler", name),
+        format!("{}ErrorHandler", name),
+    ]
+    .into_iter()
+}
+
+/// Types generated from an operation's function name.
+fn operation_types<'a>(
+    name: &str,
+    error_statuses: &'a [String],
+) -> impl Iterator<Item = String> + 'a {
+    let type_base = to_pascal_case(name);
+    let error_classes = error_statuses.iter().map({
+        let type_base = type_base.clone();
+        move |status| format!("{}{}Error", type_base, status)
+    });
+    OPERATION_TYPE_SUFFIXES
+        .iter()
+        .map(move |suffix| format!("{}{}", type_base, suffix))
+        .chain(error_classes)
+}
+
+/// React Query/SWR hook generated for a function.
+fn hook_name(name: &str) -> String {
+    format!("use{}", to_pascal_case(name))
+}
+
+/// Status part of the error classes generated for an operation's non-2xx responses:
+/// `404`, `4XX` or `Default`.
+fn error_statuses(operation: &Operation) -> Vec<String> {
+    let mut statuses: Vec<String> = operation
+        .responses
+        .responses
+        .keys()
+        .filter_map(|status| match status {
+            StatusCode::Code(code) if !(200..300).contains(code) => Some(code.to_string()),
+            StatusCode::Range(range) if *range != 2 => Some(format!("{}XX", range)),
+            _ => None,
+        })
+        .collect();
+    if operation.responses.default.is_some() {
+        statuses.push("Default".to_string());
+    }
+    statuses
+}
+
+/// `{Param}Enum` types generated for an operation's string enum parameters.
+fn parameter_enum_types(openapi: &OpenAPI, operation: &Operation) -> Vec<String> {
+    operation
+        .parameters
+        .iter()
+        .filter_map(|parameter| match parameter {
+            ReferenceOr::Item(parameter) => Some(parameter.clone()),
+            ReferenceOr::Reference { reference } => {
+                match resolve_parameter_ref(openapi, reference).ok()? {
+                    ReferenceOr::Item(parameter) => Some(parameter),
+                    ReferenceOr::Reference { .. } => None,
+                }
+            }
+        })
+        .filter_map(|parameter| {
+            let data = parameter.parameter_data();
+            let schema = match &data.format {
+                ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => schema.clone(),
+                ParameterSchemaOrContent::Schema(ReferenceOr::Reference { reference }) => {
+                    match resolve_ref(openapi, reference).ok()? {
+                        ReferenceOr::Item(schema) => schema,
+                        ReferenceOr::Reference { .. } => return None,
+                    }
+                }
+                ParameterSchemaOrContent::Content(_) => return None,
+            };
+            match &schema.schema_kind {
+                SchemaKind::Type(Type::String(string_type))
+                    if !string_type.enumeration.is_empty() =>
+                {
+                    Some(format!("{}Enum", to_pascal_case(&data.name)))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Function name of an operation before collision checks: its camelCased `operationId`,
+/// else a name derived from the method and path.
+pub fn default_function_name(method: &str, path: &str, operation_id: Option<&str>) -> String {
+    match operation_id {
+        Some(operation_id) => to_camel_case(operation_id),
+        None => generate_function_name_from_path(path, method),
+    }
+}
+
+/// Explicit function name for an operation from `apis.rename`, keyed by `operationId` or by
+/// "METHOD /path".
+fn renamed<'a>(op_info: &OperationInfo, renames: &'a HashMap<String, String>) -> Option<&'a str> {
+    op_info
+        .operation
+        .operation_id
+        .as_ref()
+        .and_then(|operation_id| renames.get(operation_id))
+        .or_else(|| {
+            renames.get(&format!(
+                "{} {}",
+                op_info.method.to_uppercase(),
+                op_info.path
+            ))
+        })
+        .map(|name| name.as_str())
+}
+
+/// Assign every operation of a module its function name. Renamed operations claim their
+/// names first, then the others in spec order; schema type names and parameter enum types
+/// of the module are reserved.
+pub fn resolve_operation_names(
+    openapi: &OpenAPI,
+    module: &str,
+    operations: &mut [OperationInfo],
+    schema_names: &[String],
+    renames: &HashMap<String, String>,
+) -> Vec<Collision> {
+    let mut table = SymbolTable::default();
+    for schema_name in schema_names {
+        table.reserve_type(to_pascal_case(schema_name));
+    }
+    for op_info in operations.iter() {
+        for enum_type in parameter_enum_types(openapi, &op_info.operation) {
+            table.reserve_type(enum_type);
+        }
+    }
+
+    let (explicit, derived): (Vec<usize>, Vec<usize>) =
+        (0..operations.len()).partition(|&i| renamed(&operations[i], renames).is_some());
+
+    let mut collisions = Vec::new();
+    for index in explicit.into_iter().chain(derived) {
+        let op_info = &mut operations[index];
+        let name = match renamed(op_info, renames) {
+            Some(name) => name.to_string(),
+            None => default_function_name(
+                &op_info.method,
+                &op_info.path,
+                op_info.operation.operation_id.as_deref(),
+            ),
+        };
+        let resolved = table.claim_operation(&name, &error_statuses(&op_info.operation));
+        if resolved != name {
+            collisions.push(Collision {
+                module: module.to_string(),
+                source: format!("{} {}", op_info.method.to_uppercase(), op_info.path),
+                name,
+                resolved: resolved.clone(),
+            });
+        }
+        op_info.function_name = resolved;
+    }
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapiv3::Operation;
+
+    fn operation(method: &str, path: &str, operation_id: Option<&str>) -> OperationInfo {
+        OperationInfo {
+            method: method.to_string(),
+            path: path.to_string(),
+            operation: Operation {
+                operation_id: operation_id.map(|id| id.to_string()),
+                ..Default::default()
+            },
+            function_name: String::new(),
+        }
+    }
+
+    fn names(operations: &[OperationInfo]) -> Vec<&str> {
+        operations
+            .iter()
+            .map(|op| op.function_name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_is_valid_identifier() {
+        assert!(is_valid_identifier("listPets"));
+        assert!(is_valid_identifier("_private"));
+        assert!(is_valid_identifier("$ref"));
+        assert!(!is_valid_identifier("list-pets"));
+        assert!(!is_valid_identifier("2fa"));
+        assert!(!is_valid_identifier(""));
+    }
+
+    #[test]
+    fn test_operation_ids_normalizing_to_the_same_name() {
+        let mut operations = vec![
+            operation("GET", "/pets", Some("list_pets")),
+            operation("GET", "/v2/pets", Some("listPets")),
+            operation("GET", "/v3/pets", Some("ListPets")),
+        ];
+        let collisions = resolve_operation_names(
+            &OpenAPI::default(),
+            "pets",
+            &mut operations,
+            &[],
+            &HashMap::new(),
+        );
+
+        assert_eq!(names(&operations), ["listPets", "listPets2", "listPets3"]);
+        assert_eq!(collisions.len(), 2);
+        assert_eq!(collisions[0].source, "GET /v2/pets");
+        assert_eq!(collisions[0].resolved, "listPets2");
+    }
+
+    #[test]
+    fn test_path_derived_names_collide() {
+        let mut operations = vec![
+            operation("GET", "/posts/{id}", None),
+            operation("GET", "/users/{id}/posts", None),
+        ];
+        resolve_operation_names(
+            &OpenAPI::default(),
+            "posts",
+            &mut operations,
+            &[],
+            &HashMap::new(),
+        );
+
+        assert_eq!(names(&operations), ["getPostById", "getPostById2"]);
+    }
+
+    #[test]
+    fn test_schema_names_are_reserved() {
+        let mut operations = vec![operation("GET", "/pets", Some("listPets"))];
+        let collisions = resolve_operation_names(
+            &OpenAPI::default(),
+            "pets",
+            &mut operations,
+            &["ListPetsResponses".to_string()],
+            &HashMap::new(),
+        );
+
+        assert_eq!(names(&operations), ["listPets2"]);
+        assert_eq!(collisions[0].name, "listPets");
+    }
+
+    #[test]
+    fn test_error_classes_and_hooks_are_reserved() {
+        let mut get_user = operation("GET", "/users/{id}", Some("getUser"));
+        get_user.operation.responses = serde_json::from_str(
+            r#"{"200": {"description": "OK"}, "404": {"description": "Not Found"}}"#,
+        )
+        .unwrap();
+        let mut operations = vec![
+            get_user,
+            // `GetUser404Error` is the 404 class of getUser
+            operation("GET", "/v2/users/{id}", Some("getUser404")),
+            operation("GET", "/orders/{id}", Some("useGetOrder")),
+            // `useGetOrder` is already an API function
+            operation("GET", "/v2/orders/{id}", Some("getOrder")),
+        ];
+        resolve_operation_names(
+            &OpenAPI::default(),
+            "users",
+            &mut operations,
+            &[],
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            names(&operations),
+            ["getUser", "getUser4042", "useGetOrder", "getOrder2"]
+        );
+    }
+
+    #[test]
+    fn test_pagination_helpers_and_mock_handlers_are_reserved() {
+        let mut operations = vec![
+            operation("GET", "/pets", Some("listPets")),
+            // `paginateListPets` is the pagination helper of listPets
+            operation("GET", "/pets/pages", Some("paginateListPets")),
+            operation("GET", "/pets/all", Some("fetchAllListPets")),
+            // `listPetsHandler` is the MSW handler of listPets
+            operation("GET", "/pets/handler", Some("listPetsHandler")),
+        ];
+        resolve_operation_names(
+            &OpenAPI::default(),
+            "pets",
+            &mut operations,
+            &[],
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            names(&operations),
+            [
+                "listPets",
+                "paginateListPets2",
+                "fetchAllListPets2",
+                "listPetsHandler2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_reserved_word() {
+        assert!(is_reserved_word("delete"));
+        assert!(is_reserved_word("default"));
+        assert!(is_reserved_word("class"));
+        assert!(!is_reserved_word("deletePet"));
+        assert!(is_valid_identifier("delete"));
+    }
+
+    #[test]
+    fn test_parameter_enum_types() {
+        let mut list_pets = operation("GET", "/pets", Some("listPets"));
+        list_pets.operation.parameters = serde_json::from_str(
+            r#"[{"name": "sort_order", "in": "query",
+                 "schema": {"type": "string", "enum": ["asc", "desc"]}},
+                {"name": "name", "in": "query", "schema": {"type": "string"}}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parameter_enum_types(&OpenAPI::default(), &list_pets.operation),
+            ["SortOrderEnum"]
+        );
+    }
+
+    #[test]
+    fn test_renames_win_over_derived_names() {
+        let mut operations = vec![
+            operation("GET", "/pets", Some("listPets")),
+            operation("GET", "/v2/pets", Some("listPetsV2")),
+        ];
+        let renames = HashMap::from([
+            ("listPetsV2".to_string(), "listPets".to_string()),
+            ("GET /pets".to_string(), "listLegacyPets".to_string()),
+        ]);
+        let collisions =
+            resolve_operation_names(&OpenAPI::default(), "pets", &mut operations, &[], &renames);
+
+        assert_eq!(names(&operations), ["listLegacyPets", "listPets"]);
+        assert!(collisions.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::generator::deprecation::schema_deprecation;
 use crate::generator::swagger_parser::{get_schema_name_from_ref, resolve_ref};
 use crate::generator::utils::{sanitize_property_name, to_pascal_case};
 use crate::templates::context::{Field, TypeContext};
@@ -262,11 +263,12 @@ fn generate_type_for_schema(
             if obj.properties.is_empty() {
                 // Empty object with additionalProperties - use type alias for Record
                 if let Some(engine) = template_engine {
-                    let context = TypeContext::alias(
+                    let mut context = TypeContext::alias(
                         type_name.clone(),
                         "Record<string, any>".to_string(),
                         spec_name.map(|s| s.to_string()),
                     );
+                    context.deprecated = schema_deprecation(&schema.schema_data);
                     let content = engine.render(TemplateId::TypeAlias, &context)?;
                     types.push(TypeScriptType { content });
                 } else {
@@ -279,12 +281,13 @@ fn generate_type_for_schema(
                 if let Some(engine) = template_engine {
                     let fields = build_fields_from_content(&content);
                     let description = schema.schema_data.description.clone();
-                    let context = TypeContext::interface(
+                    let mut context = TypeContext::interface(
                         type_name.clone(),
                         fields,
                         description,
                         spec_name.map(|s| s.to_string()),
                     );
+                    context.deprecated = schema_deprecation(&schema.schema_data);
                     let content = engine.render(TemplateId::TypeInterface, &context)?;
                     types.push(TypeScriptType { content });
                 } else {
@@ -618,6 +621,19 @@ fn schema_to_typescript(
                                 .as_item()
                                 .and_then(|s| s.schema_data.description.clone());
 
+                            // Deprecated properties get their JSDoc tag on the line before
+                            if let Some(tag) = prop_schema_ref
+                                .as_item()
+                                .and_then(|s| schema_deprecation(&s.schema_data))
+                            {
+                                // Kept on one line, which `build_fields_from_content` reads back
+                                fields.push(format!(
+                                    "{}/** {} */",
+                                    indent_str,
+                                    tag.replace("\n * ", " ")
+                                ));
+                            }
+
                             // Build field string with description comment if available
                             let field_str = if let Some(desc) = &prop_description {
                                 format!(
@@ -819,9 +835,11 @@ pub fn generate_enum_type(name: &str, values: &[String]) -> TypeScriptType {
 }
 
 /// Parse field content string into Field structs.
-/// Format: "  fieldName?: type; // description" or "  fieldName: type;"
+/// Format: "  fieldName?: type; // description" or "  fieldName: type;", optionally preceded
+/// by a "  /** @deprecated ... */" line
 fn build_fields_from_content(content: &str) -> Vec<Field> {
     let mut fields = Vec::new();
+    let mut deprecated = None;
 
     for line in content.lines() {
         let line = line.trim();
@@ -829,6 +847,14 @@ fn build_fields_from_content(content: &str) -> Vec<Field> {
             continue;
         }
 
+        if let Some(tag) = line
+            .strip_prefix("/**")
+            .and_then(|rest| rest.strip_suffix("*/"))
+        {
+            deprecated = Some(tag.trim().to_string());
+            continue;
+        }
+
         // Parse: "fieldName?: type; // description" or "fieldName: type;"
         if let Some(colon_pos) = line.find(':') {
             let before_colon = &line[..colon_pos].trim();
@@ -848,12 +874,9 @@ fn build_fields_from_content(content: &str) -> Vec<Field> {
 
             let description = rest.strip_prefix("//").map(|s| s.trim().to_string());
 
-            fields.push(Field::new(
-                field_name,
-                type_part.to_string(),
-                optional,
-                description,
-            ));
+            let mut field = Field::new(field_name, type_part.to_string(), optional, description);
+            field.deprecated = deprecated.take();
+            fields.push(field);
         }
     }
 
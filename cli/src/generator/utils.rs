@@ -1,3 +1,5 @@
+use crate::generator::symbols::is_valid_identifier;
+
 pub fn to_pascal_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
@@ -30,19 +32,63 @@ pub fn to_camel_case(s: &str) -> String {
 /// Sanitizes a property name to be a valid JavaScript identifier
 /// Returns the original name if valid, or the name in quotes if invalid
 pub fn sanitize_property_name(name: &str) -> String {
-    let first_char = name.chars().next();
-    let needs_quotes = match first_char {
-        Some(c) if c.is_ascii_digit() => true, // starts with number
-        _ => name.contains(' ') || name.contains('-') && !name.starts_with('-'), // contains spaces or hyphens (but not negative numbers)
-    };
+    // Negative numbers are left as they are
+    let needs_quotes = !is_valid_identifier(name) && !name.starts_with('-');
 
     if needs_quotes {
-        format!("\"{}\"", name)
+        string_literal(name)
     } else {
         name.to_string()
     }
 }
 
+/// TypeScript string literal for `value`, escaped as JSON.
+pub fn string_literal(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Text safe to place inside a `/** */` JSDoc comment: `*/` would end the comment early.
+pub fn doc_comment_text(text: &str) -> String {
+    text.replace("*/", "*\\/")
+}
+
+/// Property access on `object`: `object.name`, or `object["name"]` when the name needs quotes.
+/// `optional` uses optional chaining (`object?.name`, `object?.["name"]`).
+pub fn property_access(object: &str, name: &str, optional: bool) -> String {
+    let chain = if optional { "?." } else { "." };
+    if is_valid_identifier(name) {
+        format!("{}{}{}", object, chain, name)
+    } else if optional {
+        format!("{}?.[\"{}\"]", object, name)
+    } else {
+        format!("{}[\"{}\"]", object, name)
+    }
+}
+
+/// Identifier for a parameter used as a function argument or local variable: the name
+/// itself when it is a valid identifier, else camelCased (`pet-id` -> `petId`).
+pub fn parameter_identifier(name: &str) -> String {
+    if is_valid_identifier(name) {
+        return name.to_string();
+    }
+    let words: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let identifier = to_camel_case(&words);
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", identifier)
+    } else {
+        identifier
+    }
+}
+
 /// Sanitizes module names for use as directory/file names
 /// Replaces spaces with hyphens, converts to lowercase, and removes other invalid characters
 /// This ensures consistent casing across case-insensitive filesystems (like macOS)
@@ -138,6 +184,42 @@ mod tests {
         assert_eq!(sanitize_property_name("_private"), "_private");
     }
 
+    #[test]
+    fn test_sanitize_property_name_with_punctuation() {
+        assert_eq!(sanitize_property_name("user.id"), "\"user.id\"");
+        assert_eq!(sanitize_property_name("@type"), "\"@type\"");
+        assert_eq!(sanitize_property_name("$ref"), "$ref");
+    }
+
+    #[test]
+    fn test_doc_comment_text() {
+        assert_eq!(doc_comment_text("Use v2 */ now"), "Use v2 *\\/ now");
+        assert_eq!(doc_comment_text("Plain text"), "Plain text");
+    }
+
+    #[test]
+    fn test_parameter_identifier() {
+        assert_eq!(parameter_identifier("petId"), "petId");
+        assert_eq!(parameter_identifier("user_id"), "user_id");
+        assert_eq!(parameter_identifier("pet-id"), "petId");
+        assert_eq!(parameter_identifier("user.id"), "userId");
+        assert_eq!(parameter_identifier("2fa"), "_2fa");
+    }
+
+    #[test]
+    fn test_property_access() {
+        assert_eq!(property_access("query", "page", false), "query.page");
+        assert_eq!(property_access("query", "page", true), "query?.page");
+        assert_eq!(
+            property_access("query", "page[size]", false),
+            "query[\"page[size]\"]"
+        );
+        assert_eq!(
+            property_access("query", "user.id", true),
+            "query?.[\"user.id\"]"
+        );
+    }
+
     #[test]
     fn test_sanitize_property_name_starts_with_number() {
         assert_eq!(sanitize_property_name("2xl"), "\"2xl\"");
@@ -384,6 +384,9 @@ pub fn write_api_client_with_options(
             let mut func_lines = Vec::new();
             let mut type_lines = Vec::new();
             let mut in_function = false;
+            // Classes (angular services) and function declarations (SDK factories)
+            // end with a top-level "}" instead of "};"
+            let mut function_end = "};";
             let mut in_type = false;
             let mut type_definition = Vec::new();
             let mut brace_count = 0;
@@ -453,6 +456,13 @@ pub fn write_api_client_with_options(
                         type_definition.clear();
                         in_type = false;
                     }
+                } else if in_function {
+                    // Everything inside the function/class body (including member JSDoc) is kept
+                    func_lines.push(line);
+                    // Check if function ends
+                    if line.trim() == function_end && (function_end == "};" || line == "}") {
+                        break;
+                    }
                 } else if line.trim().starts_with("/**") {
                     // Start of JSDoc comment (only collect if not inside a type)
                     in_jsdoc = true;
@@ -463,6 +473,28 @@ pub fn write_api_client_with_options(
                         // End of JSDoc comment
                         in_jsdoc = false;
                     }
+                } else if line.starts_with('@') {
+                    // Decorator (e.g. @Injectable) - kept with the JSDoc of the next declaration
+                    jsdoc_lines.push(line);
+                } else if let Some(declaration) = line
+                    .strip_prefix("export class ")
+                    .or_else(|| line.strip_prefix("export function "))
+                {
+                    let name = declaration
+                        .split(|c: char| !c.is_alphanumeric() && c != '_')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string();
+                    if seen_functions.contains(&name) {
+                        jsdoc_lines.clear();
+                        break;
+                    }
+                    seen_functions.insert(name.clone());
+                    function_name = Some(name);
+                    in_function = true;
+                    function_end = "}";
+                    func_lines.append(&mut jsdoc_lines);
+                    func_lines.push(line);
                 } else if line.trim().starts_with("export const ") {
                     // Extract function name to check for duplicates
                     // Find the function name after "export const " (13 chars)
@@ -489,12 +521,6 @@ pub fn write_api_client_with_options(
                     // Add JSDoc comments before the function
                     func_lines.append(&mut jsdoc_lines);
                     func_lines.push(line);
-                } else if in_function {
-                    func_lines.push(line);
-                    // Check if function ends
-                    if line.trim() == "};" {
-                        break;
-                    }
                 } else if line.trim().starts_with("export type ")
                     || line.trim().starts_with("export interface ")
                 {
@@ -554,7 +580,7 @@ pub fn write_api_client_with_options(
                 // Malformed imports - add as-is (deduplicate)
                 let deduped: std::collections::HashSet<String> =
                     other_imports.iter().cloned().collect();
-                imports_vec.extend(deduped.into_iter());
+                imports_vec.extend(deduped);
             } else {
                 // Deduplicate and separate other imports by type
                 let deduped_imports: std::collections::HashSet<String> =
@@ -615,10 +641,25 @@ pub fn write_api_client_with_options(
 }
 
 /// Write runtime client files (types, http-client, index) to the runtime directory at root_dir.
+///
+/// The runtime is shared by every spec, so its default client instances are left
+/// unconfigured; each spec's `vika-client.ts` declares the configured ones.
 pub fn write_runtime_client(
+    root_dir: &Path,
+    spec_name: Option<&str>,
+    apis_config: Option<&crate::config::model::ApisConfig>,
+) -> Result<Vec<PathBuf>> {
+    write_runtime_client_with_options(root_dir, spec_name, apis_config, false, false)
+}
+
+/// Write the runtime client files, rewriting existing ones that differ from the
+/// templates (with `backup`/`force` applied like any other generated file).
+pub fn write_runtime_client_with_options(
     root_dir: &Path,
     _spec_name: Option<&str>,
     apis_config: Option<&crate::config::model::ApisConfig>,
+    backup: bool,
+    force: bool,
 ) -> Result<Vec<PathBuf>> {
     let runtime_dir = root_dir.join("runtime");
     ensure_directory(&runtime_dir)?;
@@ -634,65 +675,16 @@ pub fn write_runtime_client(
         &serde_json::json!({}),
     )?;
     let types_file = runtime_dir.join("types.ts");
-    write_file_safe(&types_file, &types_content)?;
+    write_file_with_backup(&types_file, &types_content, backup, force)?;
     written_files.push(types_file);
 
-    // Prepare runtime client options from config
-    let mut client_options = serde_json::Map::new();
-    if let Some(config) = apis_config {
-        if let Some(ref base_url) = config.base_url {
-            client_options.insert(
-                "baseUrl".to_string(),
-                serde_json::Value::String(base_url.clone()),
-            );
-        }
-        if let Some(timeout) = config.timeout {
-            client_options.insert(
-                "timeout".to_string(),
-                serde_json::Value::Number(timeout.into()),
-            );
-        }
-        if let Some(retries) = config.retries {
-            client_options.insert(
-                "retries".to_string(),
-                serde_json::Value::Number(retries.into()),
-            );
-        }
-        if let Some(retry_delay) = config.retry_delay {
-            client_options.insert(
-                "retryDelay".to_string(),
-                serde_json::Value::Number(retry_delay.into()),
-            );
-        }
-        if let Some(ref headers) = config.headers {
-            // Convert HashMap to JSON object for template
-            let headers_json: serde_json::Value =
-                serde_json::to_value(headers).unwrap_or(serde_json::json!({}));
-            client_options.insert("headers".to_string(), headers_json);
-        }
-        // Map header_strategy to auth
-        let auth_strategy = match config.header_strategy.as_str() {
-            "bearerToken" => Some("bearerToken"),
-            "fixed" => Some("fixed"),
-            "consumerInjected" => Some("consumerInjected"),
-            _ => None,
-        };
-        if let Some(auth) = auth_strategy {
-            client_options.insert(
-                "auth".to_string(),
-                serde_json::Value::String(auth.to_string()),
-            );
-        }
-    }
-    let client_options_value = serde_json::Value::Object(client_options);
-
-    // Generate http-client.ts with config options
+    // Generate http-client.ts
     let http_client_content = template_engine.render(
         crate::templates::registry::TemplateId::RuntimeHttpClient,
-        &client_options_value,
+        &serde_json::json!({}),
     )?;
     let http_client_file = runtime_dir.join("http-client.ts");
-    write_file_safe(&http_client_file, &http_client_content)?;
+    write_file_with_backup(&http_client_file, &http_client_content, backup, force)?;
     written_files.push(http_client_file);
 
     // Generate index.ts
@@ -701,12 +693,46 @@ pub fn write_runtime_client(
         &serde_json::json!({}),
     )?;
     let index_file = runtime_dir.join("index.ts");
-    write_file_safe(&index_file, &index_content)?;
+    write_file_with_backup(&index_file, &index_content, backup, force)?;
     written_files.push(index_file);
 
+    // The axios client is only written when requested so fetch-only projects don't need axios
+    if apis_config.is_some_and(|config| config.style == "axios") {
+        written_files.push(write_axios_runtime_client_with_options(
+            root_dir, backup, force,
+        )?);
+    }
+
     Ok(written_files)
 }
 
+/// Write the axios runtime client (`runtime/axios-client.ts`) used by the `axios` API style.
+/// It is kept out of `runtime/index.ts` so projects without axios still type-check.
+pub fn write_axios_runtime_client(root_dir: &Path) -> Result<PathBuf> {
+    write_axios_runtime_client_with_options(root_dir, false, false)
+}
+
+pub fn write_axios_runtime_client_with_options(
+    root_dir: &Path,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    let runtime_dir = root_dir.join("runtime");
+    ensure_directory(&runtime_dir)?;
+
+    let project_root = std::env::current_dir().ok();
+    let template_engine = crate::templates::engine::TemplateEngine::new(project_root.as_deref())?;
+
+    let axios_client_content = template_engine.render(
+        crate::templates::registry::TemplateId::RuntimeAxiosClient,
+        &serde_json::json!({}),
+    )?;
+    let axios_client_file = runtime_dir.join("axios-client.ts");
+    write_file_with_backup(&axios_client_file, &axios_client_content, backup, force)?;
+
+    Ok(axios_client_file)
+}
+
 fn format_typescript_code(code: &str) -> String {
     // Basic formatting: remove extra blank lines while preserving indentation
     let lines: Vec<&str> = code.lines().collect();
@@ -1128,3 +1154,143 @@ pub fn write_query_keys_with_options(
 
     Ok(query_keys_file)
 }
+
+/// Write JSON Schema documents next to the module's TypeScript/Zod schemas.
+pub fn write_json_schemas_with_options(
+    output_dir: &Path,
+    module_name: &str,
+    files: &[crate::generator::json_schema::JsonSchemaFile],
+    backup: bool,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    // Build module directory path: {output_dir}/{module_name}
+    // Same layout as types.ts/schemas.ts so relative $refs (e.g. ../common/X.schema.json) resolve
+    let module_dir = output_dir.join(sanitize_module_name(module_name));
+    ensure_directory(&module_dir)?;
+
+    let mut written_files = Vec::new();
+
+    for file in files {
+        let content = serde_json::to_string_pretty(&file.content).map_err(|e| {
+            crate::error::GenerationError::InvalidOperation {
+                message: format!("Failed to serialize {}: {}", file.file_name, e),
+            }
+        })?;
+        let json_file = module_dir.join(&file.file_name);
+        write_file_with_backup(&json_file, &format!("{}\n", content), backup, force)?;
+        written_files.push(json_file);
+    }
+
+    Ok(written_files)
+}
+
+/// Write the spec-level security module (`security.ts`) to the APIs output directory.
+pub fn write_security_module_with_options(
+    output_dir: &Path,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    ensure_directory(output_dir)?;
+
+    let security_file = output_dir.join(crate::generator::security::SECURITY_MODULE_FILE);
+    write_file_with_backup(&security_file, content, backup, force)?;
+
+    Ok(security_file)
+}
+
+/// Write the spec-level servers module (`servers.ts`) to the APIs output directory.
+pub fn write_servers_module_with_options(
+    output_dir: &Path,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    ensure_directory(output_dir)?;
+
+    let servers_file = output_dir.join(crate::generator::servers::SERVERS_MODULE_FILE);
+    write_file_with_backup(&servers_file, content, backup, force)?;
+
+    Ok(servers_file)
+}
+
+/// Write a generated MSW file (a module's `{module}/index.ts`, `utils.ts` or `handlers.ts`)
+/// to the mocks output directory.
+pub fn write_mocks_file_with_options(
+    output_dir: &Path,
+    relative_path: &str,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    let mocks_file = output_dir.join(relative_path);
+    if let Some(parent) = mocks_file.parent() {
+        ensure_directory(parent)?;
+    }
+    write_file_with_backup(&mocks_file, content, backup, force)?;
+
+    Ok(mocks_file)
+}
+
+/// Write a generated factories file (a module's `{module}.ts` or `random.ts`) to the
+/// factories output directory.
+pub fn write_factories_file_with_options(
+    output_dir: &Path,
+    relative_path: &str,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    let factories_file = output_dir.join(relative_path);
+    if let Some(parent) = factories_file.parent() {
+        ensure_directory(parent)?;
+    }
+    write_file_with_backup(&factories_file, content, backup, force)?;
+
+    Ok(factories_file)
+}
+
+/// Write the spec's client instance module (`vika-client.ts`) to the APIs output directory.
+pub fn write_spec_client_with_options(
+    output_dir: &Path,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    ensure_directory(output_dir)?;
+
+    let client_file = output_dir.join(crate::generator::spec_client::SPEC_CLIENT_FILE);
+    write_file_with_backup(&client_file, content, backup, force)?;
+
+    Ok(client_file)
+}
+
+/// Write the spec-level SDK class (`client.ts`) to the APIs output directory.
+pub fn write_sdk_client_with_options(
+    output_dir: &Path,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    ensure_directory(output_dir)?;
+
+    let client_file = output_dir.join(crate::generator::sdk_client::SDK_CLIENT_FILE);
+    write_file_with_backup(&client_file, content, backup, force)?;
+
+    Ok(client_file)
+}
+
+/// Write the spec-level `api-config.ts` (base URL `InjectionToken`) used by angular services.
+pub fn write_angular_api_config_with_options(
+    output_dir: &Path,
+    content: &str,
+    backup: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    ensure_directory(output_dir)?;
+
+    let config_file = output_dir.join("api-config.ts");
+    write_file_with_backup(&config_file, content, backup, force)?;
+
+    Ok(config_file)
+}
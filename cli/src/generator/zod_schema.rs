@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::generator::deprecation::schema_deprecation;
 use crate::generator::swagger_parser::{get_schema_name_from_ref, resolve_ref};
 use crate::generator::utils::{sanitize_property_name, to_pascal_case};
 use crate::templates::context::ZodContext;
@@ -195,12 +196,13 @@ fn generate_zod_for_schema(
                 // Empty object with additionalProperties - use z.record() directly
                 if let Some(engine) = template_engine {
                     let description = schema.schema_data.description.clone();
-                    let context = ZodContext::schema_with_annotation(
+                    let mut context = ZodContext::schema_with_annotation(
                         schema_name.clone(),
                         "z.record(z.string(), z.any())".to_string(),
                         description,
                         spec_name.map(|s| s.to_string()),
                     );
+                    context.deprecated = schema_deprecation(&schema.schema_data);
                     let content = engine.render(TemplateId::ZodSchema, &context)?;
                     zod_schemas.push(ZodSchema { content });
                 } else {
@@ -218,7 +220,7 @@ fn generate_zod_for_schema(
                 if let Some(engine) = template_engine {
                     let description = schema.schema_data.description.clone();
                     let zod_expr = format!("z.object({{\n{}\n}})", zod_def);
-                    let context = if has_circular_ref {
+                    let mut context = if has_circular_ref {
                         ZodContext::schema_with_annotation(
                             schema_name.clone(),
                             zod_expr,
@@ -233,6 +235,7 @@ fn generate_zod_for_schema(
                             spec_name.map(|s| s.to_string()),
                         )
                     };
+                    context.deprecated = schema_deprecation(&schema.schema_data);
                     let content = engine.render(TemplateId::ZodSchema, &context)?;
                     zod_schemas.push(ZodSchema { content });
                 } else {
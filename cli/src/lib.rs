@@ -19,6 +19,7 @@ pub mod config;
 pub mod error;
 pub mod formatter;
 pub mod generator;
+pub mod mock;
 pub mod progress;
 pub mod specs;
 pub mod templates;
@@ -29,6 +29,7 @@ async fn main() {
             force,
             react_query,
             swr,
+            no_deprecated,
         } => {
             if let Err(e) = vika_cli::commands::generate::run(
                 spec,
@@ -40,6 +41,7 @@ async fn main() {
                 force,
                 react_query,
                 swr,
+                no_deprecated,
             )
             .await
             {
@@ -47,8 +49,8 @@ async fn main() {
                 std::process::exit(1);
             }
         }
-        vika_cli::cli::Commands::Update => {
-            if let Err(e) = vika_cli::commands::update::run().await {
+        vika_cli::cli::Commands::Update { no_deprecated } => {
+            if let Err(e) = vika_cli::commands::update::run(no_deprecated).await {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -71,6 +73,30 @@ async fn main() {
                 std::process::exit(1);
             }
         }
+        vika_cli::cli::Commands::Mock {
+            spec_name,
+            port,
+            host,
+            validate,
+            delay,
+            error_rate,
+            error_status,
+        } => {
+            if let Err(e) = vika_cli::commands::mock::run(
+                spec_name,
+                host,
+                port,
+                validate,
+                delay,
+                error_rate,
+                error_status,
+            )
+            .await
+            {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
         vika_cli::cli::Commands::Templates { command } => match command {
             vika_cli::cli::TemplateCommands::List => {
                 if let Err(e) = vika_cli::commands::templates::list() {
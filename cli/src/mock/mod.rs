@@ -0,0 +1,422 @@
+//! Offline mock server for a spec (`vika-cli mock`).
+//!
+//! Requests are matched to the spec's operations and answered with their examples or
+//! with data derived from their schemas. `Prefer: code=404` and `Prefer: example=name`
+//! select another response, and requests can be validated against the spec.
+
+pub mod response;
+pub mod router;
+pub mod server;
+pub mod validate;
+
+use crate::mock::response::{build_response, Preferences};
+use crate::mock::router::{RouteError, Router};
+use openapiv3::OpenAPI;
+use serde_json::json;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Parsed HTTP request.
+#[derive(Debug, Clone, Default)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path without the query string, still percent-encoded
+    pub path: String,
+    /// Decoded query parameters in request order
+    pub query: Vec<(String, String)>,
+    /// Headers with lowercase names
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(header, _)| *header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.header("cookie")?.split(';').find_map(|cookie| {
+            let (key, value) = cookie.split_once('=')?;
+            (key.trim() == name).then(|| value.trim().to_string())
+        })
+    }
+}
+
+/// Response sent for a request.
+#[derive(Debug, Clone, Default)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// Operation that handled the request, for logging
+    pub operation: Option<String>,
+}
+
+impl MockResponse {
+    fn json(status: u16, body: serde_json::Value) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: serde_json::to_vec_pretty(&body).unwrap_or_default(),
+            operation: None,
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Response delay, fixed (`200`) or picked in a range (`100-500`), in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delay {
+    pub min: u64,
+    pub max: u64,
+}
+
+/// Parse `--delay` values.
+pub fn parse_delay(value: &str) -> Result<Delay, String> {
+    let parse = |part: &str| {
+        part.trim().parse::<u64>().map_err(|_| {
+            format!(
+                "invalid delay '{}', expected milliseconds like 200 or 100-500",
+                value
+            )
+        })
+    };
+    let delay = match value.split_once('-') {
+        Some((min, max)) => Delay {
+            min: parse(min)?,
+            max: parse(max)?,
+        },
+        None => {
+            let delay = parse(value)?;
+            Delay {
+                min: delay,
+                max: delay,
+            }
+        }
+    };
+    if delay.min > delay.max {
+        return Err(format!(
+            "invalid delay '{}', the minimum is above the maximum",
+            value
+        ));
+    }
+    Ok(delay)
+}
+
+/// Parse `--error-rate` values.
+pub fn parse_error_rate(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if (0.0..=1.0).contains(&rate) => Ok(rate),
+        _ => Err(format!(
+            "invalid error rate '{}', expected a number between 0 and 1",
+            value
+        )),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockOptions {
+    /// Reject requests whose parameters or body do not match the spec
+    pub validate: bool,
+    pub delay: Option<Delay>,
+    /// Fraction of requests answered with `error_status`
+    pub error_rate: f64,
+    pub error_status: u16,
+}
+
+impl Default for MockOptions {
+    fn default() -> Self {
+        Self {
+            validate: false,
+            delay: None,
+            error_rate: 0.0,
+            error_status: 500,
+        }
+    }
+}
+
+/// Answers requests from the operations of a spec.
+pub struct MockServer {
+    openapi: OpenAPI,
+    router: Router,
+    options: MockOptions,
+    /// xorshift state for delays and injected errors
+    random: AtomicU64,
+}
+
+impl MockServer {
+    pub fn new(openapi: OpenAPI, options: MockOptions) -> Self {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0x9E37_79B9_7F4A_7C15);
+        Self {
+            router: Router::new(&openapi),
+            openapi,
+            options,
+            random: AtomicU64::new(seed | 1),
+        }
+    }
+
+    pub fn router(&self) -> &Router {
+        &self.router
+    }
+
+    /// Respond to a request. Every response allows cross-origin requests, so apps served
+    /// from another port can call the mock server directly.
+    pub fn handle(&self, request: &MockRequest) -> MockResponse {
+        let mut response = self.respond(request);
+        let origin = request.header("origin").unwrap_or("*").to_string();
+        response
+            .headers
+            .push(("Access-Control-Allow-Origin".to_string(), origin));
+        response
+            .headers
+            .push(("Access-Control-Expose-Headers".to_string(), "*".to_string()));
+        response
+    }
+
+    /// Delay to wait before sending the next response.
+    pub fn delay(&self) -> Option<Duration> {
+        let delay = self.options.delay?;
+        let span = delay.max - delay.min;
+        let millis = if span == 0 {
+            delay.min
+        } else {
+            delay.min + self.next_random() % (span + 1)
+        };
+        Some(Duration::from_millis(millis))
+    }
+
+    fn respond(&self, request: &MockRequest) -> MockResponse {
+        if request.method.eq_ignore_ascii_case("OPTIONS")
+            && request.header("access-control-request-method").is_some()
+        {
+            return self.preflight(request);
+        }
+
+        let found = match self.router.find(&request.method, &request.path) {
+            Ok(found) => found,
+            Err(RouteError::NotFound) => {
+                return MockResponse::json(
+                    404,
+                    json!({ "error": format!("No operation matches {} {}", request.method, request.path) }),
+                )
+            }
+            Err(RouteError::MethodNotAllowed(allowed)) => {
+                let mut response = MockResponse::json(
+                    405,
+                    json!({ "error": format!("{} is not allowed on {}", request.method, request.path) }),
+                );
+                response
+                    .headers
+                    .push(("Allow".to_string(), allowed.join(", ")));
+                return response;
+            }
+        };
+        let operation_name = found
+            .route
+            .operation
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| format!("{} {}", found.route.method, found.route.path));
+
+        if self.options.validate {
+            let errors = validate::validate_request(&self.openapi, &found, request);
+            if !errors.is_empty() {
+                let mut response = MockResponse::json(
+                    400,
+                    json!({ "error": "Request validation failed", "details": errors }),
+                );
+                response.operation = Some(operation_name);
+                return response;
+            }
+        }
+
+        let mut preferences = Preferences::parse(request.header("prefer"));
+        if self.options.error_rate > 0.0
+            && (self.next_random() as f64 / u64::MAX as f64) < self.options.error_rate
+        {
+            // Injected errors use the operation's response for that status when it has one
+            preferences = Preferences {
+                code: Some(self.options.error_status.to_string()),
+                example: None,
+            };
+            if build_response(&self.openapi, &found.route.operation, &preferences, None).is_err() {
+                let mut response = MockResponse::json(
+                    self.options.error_status,
+                    json!({ "error": "Injected error" }),
+                );
+                response.operation = Some(operation_name);
+                return response;
+            }
+        }
+
+        let mut response = match build_response(
+            &self.openapi,
+            &found.route.operation,
+            &preferences,
+            request.header("accept"),
+        ) {
+            Ok(body) => MockResponse {
+                status: body.status,
+                headers: body.headers,
+                body: body.body,
+                operation: None,
+            },
+            Err(message) => MockResponse::json(422, json!({ "error": message })),
+        };
+        response.operation = Some(operation_name);
+        response
+    }
+
+    fn preflight(&self, request: &MockRequest) -> MockResponse {
+        let mut headers = vec![
+            (
+                "Access-Control-Allow-Methods".to_string(),
+                "GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS".to_string(),
+            ),
+            ("Access-Control-Max-Age".to_string(), "600".to_string()),
+        ];
+        if let Some(requested) = request.header("access-control-request-headers") {
+            headers.push((
+                "Access-Control-Allow-Headers".to_string(),
+                requested.to_string(),
+            ));
+        }
+        if request.header("origin").is_some() {
+            headers.push((
+                "Access-Control-Allow-Credentials".to_string(),
+                "true".to_string(),
+            ));
+        }
+        MockResponse {
+            status: 204,
+            headers,
+            ..Default::default()
+        }
+    }
+
+    fn next_random(&self) -> u64 {
+        let mut value = self.random.load(Ordering::Relaxed);
+        value ^= value << 13;
+        value ^= value >> 7;
+        value ^= value << 17;
+        self.random.store(value, Ordering::Relaxed);
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(options: MockOptions) -> MockServer {
+        let openapi: OpenAPI = serde_json::from_str(
+            r#"{
+                "openapi": "3.0.0",
+                "info": {"title": "Test", "version": "1.0.0"},
+                "paths": {"/orders/{id}": {"get": {
+                    "operationId": "getOrder",
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}],
+                    "responses": {
+                        "200": {"description": "OK", "content": {"application/json": {"example": {"id": 1}}}},
+                        "503": {"description": "Unavailable", "content": {"application/json": {"example": {"detail": "Try later"}}}}
+                    }
+                }}}
+            }"#,
+        )
+        .unwrap();
+        MockServer::new(openapi, options)
+    }
+
+    fn get(path: &str, headers: &[(&str, &str)]) -> MockRequest {
+        MockRequest {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_ascii_lowercase(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_handle_routes_and_errors() {
+        let server = server(MockOptions::default());
+
+        let response = server.handle(&get("/orders/1", &[("Origin", "http://localhost:3000")]));
+        assert_eq!(response.status, 200);
+        assert_eq!(response.operation.as_deref(), Some("getOrder"));
+        assert_eq!(
+            response.header("access-control-allow-origin"),
+            Some("http://localhost:3000")
+        );
+
+        assert_eq!(server.handle(&get("/customers", &[])).status, 404);
+        assert_eq!(
+            server
+                .handle(&get("/orders/1", &[("Prefer", "code=418")]))
+                .status,
+            422
+        );
+
+        let mut delete = get("/orders/1", &[]);
+        delete.method = "DELETE".to_string();
+        let response = server.handle(&delete);
+        assert_eq!(response.status, 405);
+        assert_eq!(response.header("allow"), Some("GET"));
+    }
+
+    #[test]
+    fn test_handle_validation_and_error_injection() {
+        let server_with_validation = server(MockOptions {
+            validate: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            server_with_validation
+                .handle(&get("/orders/abc", &[]))
+                .status,
+            400
+        );
+        assert_eq!(
+            server_with_validation.handle(&get("/orders/7", &[])).status,
+            200
+        );
+
+        let failing = server(MockOptions {
+            error_rate: 1.0,
+            error_status: 503,
+            ..Default::default()
+        });
+        let response = failing.handle(&get("/orders/1", &[]));
+        assert_eq!(response.status, 503);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&response.body).unwrap(),
+            json!({"detail": "Try later"})
+        );
+    }
+
+    #[test]
+    fn test_parse_delay_and_error_rate() {
+        assert_eq!(parse_delay("200"), Ok(Delay { min: 200, max: 200 }));
+        assert_eq!(parse_delay("100-500"), Ok(Delay { min: 100, max: 500 }));
+        assert!(parse_delay("500-100").is_err());
+        assert!(parse_delay("fast").is_err());
+
+        assert_eq!(parse_error_rate("0.25"), Ok(0.25));
+        assert!(parse_error_rate("2").is_err());
+    }
+}
@@ -0,0 +1,345 @@
+use crate::generator::mock_data::{media_type_value, named_example_value, schema_value};
+use crate::generator::swagger_parser::resolve_response_ref;
+use openapiv3::{
+    Header, MediaType, OpenAPI, Operation, ParameterSchemaOrContent, ReferenceOr, Response,
+    StatusCode,
+};
+use serde_json::Value;
+
+/// Response selection requested with a `Prefer` header, e.g. `Prefer: code=404` or
+/// `Prefer: example=notFound`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Preferences {
+    /// Status code (`404`) or range (`4XX`) to respond with
+    pub code: Option<String>,
+    /// Name of an entry of the media type's `examples`
+    pub example: Option<String>,
+}
+
+impl Preferences {
+    pub fn parse(header: Option<&str>) -> Self {
+        let mut preferences = Self::default();
+        for token in header.unwrap_or_default().split([',', ';']) {
+            let Some((key, value)) = token.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "code" => preferences.code = Some(value),
+                "example" => preferences.example = Some(value),
+                _ => {}
+            }
+        }
+        preferences
+    }
+}
+
+/// Mocked response of an operation.
+#[derive(Debug)]
+pub struct MockBody {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Build the response of `operation`: the status requested with `Prefer: code=`, otherwise
+/// the first 2xx response (then `default`). The body is the requested named example, the
+/// media type's `example`/`examples`, or a value derived from its schema.
+pub fn build_response(
+    openapi: &OpenAPI,
+    operation: &Operation,
+    preferences: &Preferences,
+    accept: Option<&str>,
+) -> Result<MockBody, String> {
+    let (status, response) = select_response(operation, preferences.code.as_deref())?;
+    let response = match response {
+        ReferenceOr::Item(response) => response.clone(),
+        ReferenceOr::Reference { reference } => resolve_response_ref(openapi, reference)
+            .ok()
+            .and_then(|response| response.into_item())
+            .ok_or_else(|| format!("Cannot resolve response {}", reference))?,
+    };
+
+    let mut headers = response_headers(openapi, &response);
+    let Some((media_type, media)) = select_media(&response, accept) else {
+        if let Some(name) = &preferences.example {
+            return Err(format!("The {} response has no example '{}'", status, name));
+        }
+        return Ok(MockBody {
+            status,
+            headers,
+            body: Vec::new(),
+        });
+    };
+
+    let value = match &preferences.example {
+        Some(name) => Some(named_example_value(openapi, media, name).ok_or_else(|| {
+            format!(
+                "The {} {} response has no example '{}'",
+                status, media_type, name
+            )
+        })?),
+        None => media_type_value(openapi, media),
+    };
+
+    headers.push(("Content-Type".to_string(), media_type.clone()));
+    Ok(MockBody {
+        status,
+        headers,
+        body: value
+            .map(|value| encode_body(media_type, value))
+            .unwrap_or_default(),
+    })
+}
+
+fn select_response<'a>(
+    operation: &'a Operation,
+    code: Option<&str>,
+) -> Result<(u16, &'a ReferenceOr<Response>), String> {
+    let responses = &operation.responses;
+    if let Some(code) = code {
+        if let Ok(status) = code.parse::<u16>() {
+            if let Some(response) = responses.responses.get(&StatusCode::Code(status)) {
+                return Ok((status, response));
+            }
+            if let Some(response) = responses.responses.get(&StatusCode::Range(status / 100)) {
+                return Ok((status, response));
+            }
+            if let Some(response) = &responses.default {
+                return Ok((status, response));
+            }
+        } else if let Some(range) = code
+            .to_ascii_uppercase()
+            .strip_suffix("XX")
+            .and_then(|range| range.parse::<u16>().ok())
+        {
+            let found = responses.responses.iter().find(|(status, _)| match status {
+                StatusCode::Code(status) => status / 100 == range,
+                StatusCode::Range(status) => *status == range,
+            });
+            if let Some((status, response)) = found {
+                return Ok((status_code(status), response));
+            }
+        }
+        return Err(format!("The operation has no {} response", code));
+    }
+
+    let success = responses
+        .responses
+        .iter()
+        .filter(|(status, _)| status_code(status) / 100 == 2)
+        .min_by_key(|(status, _)| status_code(status));
+    if let Some((status, response)) = success {
+        return Ok((status_code(status), response));
+    }
+    if let Some(response) = &responses.default {
+        return Ok((200, response));
+    }
+    responses
+        .responses
+        .iter()
+        .next()
+        .map(|(status, response)| (status_code(status), response))
+        .ok_or_else(|| "The operation has no responses".to_string())
+}
+
+/// Status of a response key, with ranges (`2XX`) answered by their first code.
+fn status_code(status: &StatusCode) -> u16 {
+    match status {
+        StatusCode::Code(code) => *code,
+        StatusCode::Range(range) => range * 100,
+    }
+}
+
+/// Media type matching `Accept`, otherwise JSON, otherwise the first one.
+fn select_media<'a>(
+    response: &'a Response,
+    accept: Option<&str>,
+) -> Option<(&'a String, &'a MediaType)> {
+    let accepted: Vec<&str> = accept
+        .unwrap_or_default()
+        .split(',')
+        .map(|range| range.split(';').next().unwrap_or_default().trim())
+        .filter(|range| !range.is_empty() && *range != "*/*")
+        .collect();
+    let essence = |media_type: &str| {
+        media_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    };
+
+    accepted
+        .iter()
+        .find_map(|range| {
+            response.content.iter().find(|(media_type, _)| {
+                let media_type = essence(media_type);
+                match range.strip_suffix("/*") {
+                    Some(prefix) => media_type.starts_with(&format!("{}/", prefix)),
+                    None => media_type == range.to_ascii_lowercase(),
+                }
+            })
+        })
+        .or_else(|| {
+            response
+                .content
+                .iter()
+                .find(|(media_type, _)| is_json(&essence(media_type)))
+        })
+        .or_else(|| response.content.iter().next())
+}
+
+fn is_json(media_type: &str) -> bool {
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+/// Serialize a mock value for a media type. Streams send the value, or each item of an
+/// array value, as one event.
+fn encode_body(media_type: &str, value: Value) -> Vec<u8> {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let events = || match &value {
+        Value::Array(items) => items.clone(),
+        value => vec![value.clone()],
+    };
+    let text = |value: &Value| match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    };
+
+    match essence.as_str() {
+        "text/event-stream" => events()
+            .iter()
+            .map(|event| format!("data: {}\n\n", text(event)))
+            .collect::<String>()
+            .into_bytes(),
+        "application/x-ndjson"
+        | "application/ndjson"
+        | "application/jsonl"
+        | "application/x-jsonlines" => events()
+            .iter()
+            .map(|event| format!("{}\n", event))
+            .collect::<String>()
+            .into_bytes(),
+        media_type if is_json(media_type) => serde_json::to_vec_pretty(&value).unwrap_or_default(),
+        _ => text(&value).into_bytes(),
+    }
+}
+
+/// Values of the headers declared by a response, from their example or schema.
+fn response_headers(openapi: &OpenAPI, response: &Response) -> Vec<(String, String)> {
+    response
+        .headers
+        .iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+        .filter_map(|(name, header)| {
+            let header: &Header = header.as_item()?;
+            let value = header.example.clone().or_else(|| match &header.format {
+                ParameterSchemaOrContent::Schema(schema) => Some(schema_value(openapi, schema)),
+                ParameterSchemaOrContent::Content(_) => None,
+            })?;
+            let value = match value {
+                Value::String(value) => value,
+                Value::Null => return None,
+                value => value.to_string(),
+            };
+            Some((name.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_operation(responses: &str) -> (OpenAPI, Operation) {
+        let openapi: OpenAPI = serde_json::from_str(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Test", "version": "1.0.0"}},
+                "paths": {{"/orders/{{id}}": {{"get": {{"responses": {}}}}}}}}}"#,
+            responses
+        ))
+        .unwrap();
+        let operation = openapi.paths.paths["/orders/{id}"]
+            .as_item()
+            .unwrap()
+            .get
+            .clone()
+            .unwrap();
+        (openapi, operation)
+    }
+
+    const RESPONSES: &str = r#"{
+        "200": {
+            "description": "OK",
+            "headers": {"X-Request-Id": {"schema": {"type": "string", "format": "uuid"}}},
+            "content": {"application/json": {
+                "schema": {"type": "object", "properties": {"id": {"type": "integer"}}},
+                "examples": {"first": {"value": {"id": 1}}, "second": {"value": {"id": 2}}}
+            }}
+        },
+        "404": {"description": "Not found", "content": {"application/json": {"example": {"detail": "Not found"}}}},
+        "default": {"description": "Error", "content": {"application/json": {"schema": {"type": "object", "properties": {"detail": {"type": "string"}}}}}}
+    }"#;
+
+    #[test]
+    fn test_parse_prefer_header() {
+        assert_eq!(
+            Preferences::parse(Some("code=404, example=\"notFound\"")),
+            Preferences {
+                code: Some("404".to_string()),
+                example: Some("notFound".to_string()),
+            }
+        );
+        assert_eq!(Preferences::parse(None), Preferences::default());
+    }
+
+    #[test]
+    fn test_build_response_defaults_to_first_success_example() {
+        let (openapi, operation) = parse_operation(RESPONSES);
+        let response = build_response(&openapi, &operation, &Preferences::default(), None).unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            serde_json::from_slice::<Value>(&response.body).unwrap(),
+            serde_json::json!({"id": 1})
+        );
+        assert!(response.headers.contains(&(
+            "X-Request-Id".to_string(),
+            "00000000-0000-4000-8000-000000000000".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_build_response_honors_preferences() {
+        let (openapi, operation) = parse_operation(RESPONSES);
+
+        let preferences = Preferences::parse(Some("example=second"));
+        let response = build_response(&openapi, &operation, &preferences, None).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&response.body).unwrap(),
+            serde_json::json!({"id": 2})
+        );
+
+        let preferences = Preferences::parse(Some("code=404"));
+        let response = build_response(&openapi, &operation, &preferences, None).unwrap();
+        assert_eq!(response.status, 404);
+
+        // Undeclared statuses fall back to the `default` response
+        let preferences = Preferences::parse(Some("code=503"));
+        let response = build_response(&openapi, &operation, &preferences, None).unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(
+            serde_json::from_slice::<Value>(&response.body).unwrap(),
+            serde_json::json!({"detail": "detail"})
+        );
+
+        let preferences = Preferences::parse(Some("example=missing"));
+        assert!(build_response(&openapi, &operation, &preferences, None).is_err());
+    }
+}
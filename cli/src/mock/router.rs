@@ -0,0 +1,302 @@
+use crate::generator::swagger_parser::resolve_parameter_ref;
+use openapiv3::{OpenAPI, Operation, Parameter, PathItem, ReferenceOr};
+use std::collections::HashMap;
+
+/// Path template segment: a literal, or a parameter with an optional literal prefix and
+/// suffix (`{id}`, `{name}.json`, `v{version}`).
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    Param {
+        prefix: String,
+        name: String,
+        suffix: String,
+    },
+}
+
+/// An operation of the spec with its path template compiled.
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub method: String,
+    pub path: String,
+    pub operation: Operation,
+    /// Path item and operation parameters, with operation parameters taking precedence
+    pub parameters: Vec<Parameter>,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug)]
+pub struct RouteMatch<'a> {
+    pub route: &'a Route,
+    /// Decoded path parameter values by name
+    pub params: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RouteError {
+    NotFound,
+    /// The path exists but not for this method; holds the allowed methods
+    MethodNotAllowed(Vec<String>),
+}
+
+/// Matches requests to the operations of a spec. Paths are matched with or without the
+/// base path of the spec's `servers` (e.g. `/v1` in `https://api.example.com/v1`).
+#[derive(Debug, Clone)]
+pub struct Router {
+    routes: Vec<Route>,
+    base_paths: Vec<String>,
+}
+
+impl Router {
+    pub fn new(openapi: &OpenAPI) -> Self {
+        let mut routes = Vec::new();
+        for (path, item) in openapi.paths.iter() {
+            let ReferenceOr::Item(item) = item else {
+                continue;
+            };
+            for (method, operation) in operations(item) {
+                routes.push(Route {
+                    method: method.to_string(),
+                    path: path.clone(),
+                    operation: operation.clone(),
+                    parameters: merge_parameters(openapi, &item.parameters, &operation.parameters),
+                    segments: compile(path),
+                });
+            }
+        }
+
+        let base_paths = openapi
+            .servers
+            .iter()
+            .filter_map(|server| base_path(&server.url))
+            .collect();
+
+        Self { routes, base_paths }
+    }
+
+    pub fn routes(&self) -> &[Route] {
+        &self.routes
+    }
+
+    /// Find the operation for `method` and `path` (without query string). Literal segments
+    /// win over parameters, so `/orders/latest` matches before `/orders/{id}`.
+    pub fn find(&self, method: &str, path: &str) -> Result<RouteMatch<'_>, RouteError> {
+        let mut candidates = vec![path];
+        for base in &self.base_paths {
+            if let Some(rest) = path.strip_prefix(base.as_str()) {
+                if rest.is_empty() || rest.starts_with('/') {
+                    candidates.push(if rest.is_empty() { "/" } else { rest });
+                }
+            }
+        }
+
+        let mut allowed = Vec::new();
+        let mut best: Option<(usize, RouteMatch)> = None;
+        for candidate in candidates {
+            let parts: Vec<&str> = candidate.trim_end_matches('/').split('/').collect();
+            for route in &self.routes {
+                let Some(params) = match_segments(&route.segments, &parts) else {
+                    continue;
+                };
+                if !route.method.eq_ignore_ascii_case(method) {
+                    if !allowed.contains(&route.method) {
+                        allowed.push(route.method.clone());
+                    }
+                    continue;
+                }
+                let literals = route
+                    .segments
+                    .iter()
+                    .filter(|segment| matches!(segment, Segment::Literal(_)))
+                    .count();
+                if best.as_ref().map_or(true, |(count, _)| literals > *count) {
+                    best = Some((literals, RouteMatch { route, params }));
+                }
+            }
+        }
+
+        match best {
+            Some((_, found)) => Ok(found),
+            None if allowed.is_empty() => Err(RouteError::NotFound),
+            None => Err(RouteError::MethodNotAllowed(allowed)),
+        }
+    }
+}
+
+fn operations(item: &PathItem) -> Vec<(&'static str, &Operation)> {
+    [
+        ("GET", &item.get),
+        ("POST", &item.post),
+        ("PUT", &item.put),
+        ("DELETE", &item.delete),
+        ("PATCH", &item.patch),
+        ("HEAD", &item.head),
+        ("OPTIONS", &item.options),
+        ("TRACE", &item.trace),
+    ]
+    .into_iter()
+    .filter_map(|(method, operation)| operation.as_ref().map(|operation| (method, operation)))
+    .collect()
+}
+
+fn merge_parameters(
+    openapi: &OpenAPI,
+    path_level: &[ReferenceOr<Parameter>],
+    operation_level: &[ReferenceOr<Parameter>],
+) -> Vec<Parameter> {
+    let resolve = |parameter: &ReferenceOr<Parameter>| match parameter {
+        ReferenceOr::Item(parameter) => Some(parameter.clone()),
+        ReferenceOr::Reference { reference } => resolve_parameter_ref(openapi, reference)
+            .ok()
+            .and_then(|parameter| parameter.into_item()),
+    };
+
+    let mut parameters: Vec<Parameter> = operation_level.iter().filter_map(resolve).collect();
+    for parameter in path_level.iter().filter_map(resolve) {
+        let data = parameter.parameter_data_ref();
+        let overridden = parameters.iter().any(|existing| {
+            let existing_data = existing.parameter_data_ref();
+            existing_data.name == data.name
+                && std::mem::discriminant(existing) == std::mem::discriminant(&parameter)
+        });
+        if !overridden {
+            parameters.push(parameter);
+        }
+    }
+    parameters
+}
+
+fn compile(path: &str) -> Vec<Segment> {
+    path.trim_end_matches('/')
+        .split('/')
+        .map(|segment| match (segment.find('{'), segment.rfind('}')) {
+            (Some(start), Some(end)) if start < end => Segment::Param {
+                prefix: segment[..start].to_string(),
+                name: segment[start + 1..end].to_string(),
+                suffix: segment[end + 1..].to_string(),
+            },
+            _ => Segment::Literal(segment.to_string()),
+        })
+        .collect()
+}
+
+fn match_segments(segments: &[Segment], parts: &[&str]) -> Option<HashMap<String, String>> {
+    if segments.len() != parts.len() {
+        return None;
+    }
+    let mut params = HashMap::new();
+    for (segment, part) in segments.iter().zip(parts) {
+        match segment {
+            Segment::Literal(literal) => {
+                if literal != part && *literal != percent_decode(part) {
+                    return None;
+                }
+            }
+            Segment::Param {
+                prefix,
+                name,
+                suffix,
+            } => {
+                let value = part
+                    .strip_prefix(prefix.as_str())?
+                    .strip_suffix(suffix.as_str())?;
+                if value.is_empty() {
+                    return None;
+                }
+                params.insert(name.clone(), percent_decode(value));
+            }
+        }
+    }
+    Some(params)
+}
+
+/// Path of a server URL, without templated or root paths.
+fn base_path(url: &str) -> Option<String> {
+    let path = match url.find("://") {
+        Some(scheme_end) => {
+            let rest = &url[scheme_end + 3..];
+            &rest[rest.find('/')?..]
+        }
+        None => url,
+    };
+    let path = path.trim_end_matches('/');
+    (path.starts_with('/') && !path.contains('{')).then(|| path.to_string())
+}
+
+/// Decode `%XX` escapes of a URL component.
+pub fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' && index + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router(paths: &str, servers: &str) -> Router {
+        let openapi: OpenAPI = serde_json::from_str(&format!(
+            r#"{{"openapi": "3.0.0", "info": {{"title": "Test", "version": "1.0.0"}},
+                "servers": {}, "paths": {}}}"#,
+            servers, paths
+        ))
+        .unwrap();
+        Router::new(&openapi)
+    }
+
+    #[test]
+    fn test_find_matches_literals_before_params() {
+        let router = router(
+            r#"{
+                "/orders/{id}": {"get": {"operationId": "getOrder", "responses": {}}},
+                "/orders/latest": {"get": {"operationId": "getLatestOrder", "responses": {}}},
+                "/files/{name}.json": {"get": {"operationId": "getFile", "responses": {}}}
+            }"#,
+            "[]",
+        );
+
+        let found = router.find("GET", "/orders/latest").unwrap();
+        assert_eq!(found.route.path, "/orders/latest");
+
+        let found = router.find("get", "/orders/a%20b").unwrap();
+        assert_eq!(found.route.path, "/orders/{id}");
+        assert_eq!(found.params["id"], "a b");
+
+        let found = router.find("GET", "/files/report.json").unwrap();
+        assert_eq!(found.params["name"], "report");
+
+        assert_eq!(
+            router.find("DELETE", "/orders/1").unwrap_err(),
+            RouteError::MethodNotAllowed(vec!["GET".to_string()])
+        );
+        assert_eq!(
+            router.find("GET", "/customers").unwrap_err(),
+            RouteError::NotFound
+        );
+    }
+
+    #[test]
+    fn test_find_strips_server_base_path() {
+        let router = router(
+            r#"{"/orders": {"get": {"responses": {}}}}"#,
+            r#"[{"url": "https://api.example.com/v1/"}]"#,
+        );
+
+        assert!(router.find("GET", "/orders").is_ok());
+        assert!(router.find("GET", "/v1/orders").is_ok());
+        assert!(router.find("GET", "/v2/orders").is_err());
+    }
+}
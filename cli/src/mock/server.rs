@@ -0,0 +1,235 @@
+use crate::mock::router::percent_decode;
+use crate::mock::{MockRequest, MockResponse, MockServer};
+use colored::*;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Largest accepted request head (request line and headers).
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+/// Largest accepted request body.
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+/// Accept connections until the listener fails. Each connection serves one request.
+pub async fn serve(listener: TcpListener, server: Arc<MockServer>) -> std::io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let server = Arc::clone(&server);
+        tokio::spawn(async move {
+            if let Err(error) = handle_connection(stream, &server).await {
+                tracing::debug!("Mock server connection failed: {}", error);
+            }
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, server: &MockServer) -> std::io::Result<()> {
+    let started = Instant::now();
+    let request = match read_request(&mut stream).await {
+        Ok(Some(request)) => request,
+        Ok(None) => return Ok(()),
+        Err(RequestError::Io(error)) => return Err(error),
+        Err(RequestError::Invalid(status, message)) => {
+            let response = MockResponse {
+                status,
+                headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+                body: message.into_bytes(),
+                operation: None,
+            };
+            return write_response(&mut stream, "GET", &response).await;
+        }
+    };
+
+    let response = server.handle(&request);
+    if let Some(delay) = server.delay() {
+        tokio::time::sleep(delay).await;
+    }
+    write_response(&mut stream, &request.method, &response).await?;
+
+    let status = match response.status {
+        200..=399 => response.status.to_string().green(),
+        400..=499 => response.status.to_string().yellow(),
+        _ => response.status.to_string().red(),
+    };
+    println!(
+        "{} {} {} {}{}",
+        request.method.bright_cyan(),
+        request.path,
+        status,
+        response
+            .operation
+            .as_deref()
+            .map(|operation| format!("{} ", operation))
+            .unwrap_or_default()
+            .dimmed(),
+        format!("({} ms)", started.elapsed().as_millis()).dimmed()
+    );
+    Ok(())
+}
+
+enum RequestError {
+    Io(std::io::Error),
+    /// Malformed request, answered with this status and message
+    Invalid(u16, String),
+}
+
+impl From<std::io::Error> for RequestError {
+    fn from(error: std::io::Error) -> Self {
+        RequestError::Io(error)
+    }
+}
+
+/// Read an HTTP/1.1 request. `None` when the client closed the connection without one.
+async fn read_request(stream: &mut TcpStream) -> Result<Option<MockRequest>, RequestError> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 8192];
+    let head_end = loop {
+        if let Some(end) = find_head_end(&buffer) {
+            break end;
+        }
+        if buffer.len() > MAX_HEAD_SIZE {
+            return Err(RequestError::Invalid(
+                431,
+                "Request headers are too large".to_string(),
+            ));
+        }
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err(RequestError::Invalid(
+            400,
+            "Malformed request line".to_string(),
+        ));
+    };
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+    let mut request = MockRequest {
+        method: method.to_ascii_uppercase(),
+        headers,
+        ..Default::default()
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    request.path = path.to_string();
+    request.query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                percent_decode(&name.replace('+', " ")),
+                percent_decode(&value.replace('+', " ")),
+            )
+        })
+        .collect();
+
+    if request
+        .header("transfer-encoding")
+        .is_some_and(|encoding| encoding.to_ascii_lowercase().contains("chunked"))
+    {
+        return Err(RequestError::Invalid(
+            411,
+            "Chunked request bodies are not supported, send a Content-Length".to_string(),
+        ));
+    }
+    let length = match request.header("content-length") {
+        Some(length) => length
+            .parse::<usize>()
+            .map_err(|_| RequestError::Invalid(400, "Invalid Content-Length".to_string()))?,
+        None => 0,
+    };
+    if length > MAX_BODY_SIZE {
+        return Err(RequestError::Invalid(
+            413,
+            "Request body is too large".to_string(),
+        ));
+    }
+    if length > 0
+        && request
+            .header("expect")
+            .is_some_and(|expect| expect.eq_ignore_ascii_case("100-continue"))
+    {
+        stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").await?;
+    }
+
+    let mut body = buffer.split_off(head_end + 4);
+    while body.len() < length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(length);
+    request.body = body;
+    Ok(Some(request))
+}
+
+fn find_head_end(buffer: &[u8]) -> Option<usize> {
+    buffer.windows(4).position(|window| window == b"\r\n\r\n")
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    method: &str,
+    response: &MockResponse,
+) -> std::io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        reason_phrase(response.status)
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    ));
+
+    stream.write_all(head.as_bytes()).await?;
+    if !method.eq_ignore_ascii_case("HEAD") {
+        stream.write_all(&response.body).await?;
+    }
+    stream.shutdown().await
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
@@ -0,0 +1,558 @@
+use crate::generator::swagger_parser::{resolve_ref, resolve_request_body_ref};
+use crate::mock::router::RouteMatch;
+use crate::mock::MockRequest;
+use openapiv3::{
+    AdditionalProperties, OpenAPI, Parameter, ParameterSchemaOrContent, ReferenceOr, Schema,
+    SchemaKind, Type,
+};
+use serde_json::Value;
+
+/// Reference depth after which values are no longer checked (recursive schemas).
+const MAX_DEPTH: usize = 32;
+
+/// Check the parameters and body of a request against its operation. Returns one message
+/// per problem, e.g. `query.limit: expected integer`.
+pub fn validate_request(
+    openapi: &OpenAPI,
+    found: &RouteMatch,
+    request: &MockRequest,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for parameter in &found.route.parameters {
+        let (location, values) = match parameter {
+            Parameter::Path { parameter_data, .. } => (
+                "path",
+                found
+                    .params
+                    .get(&parameter_data.name)
+                    .map(|value| vec![value.clone()])
+                    .unwrap_or_default(),
+            ),
+            Parameter::Query { parameter_data, .. } => (
+                "query",
+                request
+                    .query
+                    .iter()
+                    .filter(|(name, _)| *name == parameter_data.name)
+                    .map(|(_, value)| value.clone())
+                    .collect(),
+            ),
+            Parameter::Header { parameter_data, .. } => (
+                "header",
+                request
+                    .header(&parameter_data.name)
+                    .map(|value| vec![value.to_string()])
+                    .unwrap_or_default(),
+            ),
+            Parameter::Cookie { parameter_data, .. } => (
+                "cookie",
+                request
+                    .cookie(&parameter_data.name)
+                    .map(|value| vec![value])
+                    .unwrap_or_default(),
+            ),
+        };
+        let data = parameter.parameter_data_ref();
+        let path = format!("{}.{}", location, data.name);
+        if values.is_empty() {
+            if data.required {
+                errors.push(format!("{}: is required", path));
+            }
+            continue;
+        }
+        if let ParameterSchemaOrContent::Schema(schema) = &data.format {
+            let value = coerce(openapi, schema, &values);
+            validate_value(openapi, schema, &value, &path, &mut errors, 0);
+        }
+    }
+
+    validate_body(openapi, found, request, &mut errors);
+    errors
+}
+
+fn validate_body(
+    openapi: &OpenAPI,
+    found: &RouteMatch,
+    request: &MockRequest,
+    errors: &mut Vec<String>,
+) {
+    let Some(request_body) = &found.route.operation.request_body else {
+        return;
+    };
+    let request_body = match request_body {
+        ReferenceOr::Item(request_body) => request_body.clone(),
+        ReferenceOr::Reference { reference } => {
+            match resolve_request_body_ref(openapi, reference)
+                .ok()
+                .and_then(|request_body| request_body.into_item())
+            {
+                Some(request_body) => request_body,
+                None => return,
+            }
+        }
+    };
+
+    if request.body.is_empty() {
+        if request_body.required {
+            errors.push("body: is required".to_string());
+        }
+        return;
+    }
+
+    let content_type = request
+        .header("content-type")
+        .and_then(|value| value.split(';').next())
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let media = request_body.content.iter().find(|(media_type, _)| {
+        let media_type = media_type.to_ascii_lowercase();
+        media_type == content_type
+            || media_type == "*/*"
+            || media_type
+                .strip_suffix("/*")
+                .is_some_and(|prefix| content_type.starts_with(&format!("{}/", prefix)))
+    });
+    let Some((_, media)) = media else {
+        errors.push(format!(
+            "body: unsupported content type '{}', expected one of: {}",
+            content_type,
+            request_body
+                .content
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        return;
+    };
+
+    if content_type != "application/json" && !content_type.ends_with("+json") {
+        return;
+    }
+    let Some(schema) = &media.schema else {
+        return;
+    };
+    match serde_json::from_slice::<Value>(&request.body) {
+        Ok(value) => validate_value(openapi, schema, &value, "body", errors, 0),
+        Err(error) => errors.push(format!("body: invalid JSON ({})", error)),
+    }
+}
+
+/// Typed value of a parameter from its raw values, e.g. `"5"` -> `5` for integers and
+/// `tags=a&tags=b` or `tags=a,b` -> `["a", "b"]` for arrays.
+fn coerce(openapi: &OpenAPI, schema: &ReferenceOr<Schema>, values: &[String]) -> Value {
+    let Some(schema) = resolve(openapi, schema) else {
+        return Value::String(values[0].clone());
+    };
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => {
+            let items: Vec<String> = if values.len() == 1 {
+                values[0].split(',').map(|item| item.to_string()).collect()
+            } else {
+                values.to_vec()
+            };
+            Value::Array(
+                items
+                    .iter()
+                    .map(|item| match &array.items {
+                        Some(ReferenceOr::Item(schema)) => {
+                            coerce_scalar(&ReferenceOr::Item((**schema).clone()), openapi, item)
+                        }
+                        Some(ReferenceOr::Reference { reference }) => coerce_scalar(
+                            &ReferenceOr::Reference {
+                                reference: reference.clone(),
+                            },
+                            openapi,
+                            item,
+                        ),
+                        None => Value::String(item.clone()),
+                    })
+                    .collect(),
+            )
+        }
+        _ => coerce_scalar(&ReferenceOr::Item(schema), openapi, &values[0]),
+    }
+}
+
+fn coerce_scalar(schema: &ReferenceOr<Schema>, openapi: &OpenAPI, value: &str) -> Value {
+    let parsed = match resolve(openapi, schema).map(|schema| schema.schema_kind) {
+        Some(SchemaKind::Type(Type::Integer(_))) => value.parse::<i64>().ok().map(Value::from),
+        Some(SchemaKind::Type(Type::Number(_))) => value.parse::<f64>().ok().map(Value::from),
+        Some(SchemaKind::Type(Type::Boolean(_))) => value.parse::<bool>().ok().map(Value::from),
+        _ => None,
+    };
+    parsed.unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+fn resolve(openapi: &OpenAPI, schema: &ReferenceOr<Schema>) -> Option<Schema> {
+    match schema {
+        ReferenceOr::Item(schema) => Some(schema.clone()),
+        ReferenceOr::Reference { reference } => resolve_ref(openapi, reference)
+            .ok()
+            .and_then(|schema| schema.into_item()),
+    }
+}
+
+fn boxed(schema: &ReferenceOr<Box<Schema>>) -> ReferenceOr<Schema> {
+    match schema {
+        ReferenceOr::Item(schema) => ReferenceOr::Item((**schema).clone()),
+        ReferenceOr::Reference { reference } => ReferenceOr::Reference {
+            reference: reference.clone(),
+        },
+    }
+}
+
+/// Check `value` against `schema`: types, `required`, `enum`, length, range and item
+/// constraints, `additionalProperties: false` and composition. `pattern` and `format`
+/// are not checked.
+pub fn validate_value(
+    openapi: &OpenAPI,
+    schema: &ReferenceOr<Schema>,
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<String>,
+    depth: usize,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let Some(schema) = resolve(openapi, schema) else {
+        return;
+    };
+    if value.is_null() {
+        if !schema.schema_data.nullable && !matches!(schema.schema_kind, SchemaKind::Any(_)) {
+            errors.push(format!("{}: must not be null", path));
+        }
+        return;
+    }
+
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            let Some(text) = value.as_str() else {
+                errors.push(format!("{}: expected string", path));
+                return;
+            };
+            if !string.enumeration.is_empty()
+                && !string
+                    .enumeration
+                    .iter()
+                    .any(|allowed| allowed.as_deref() == Some(text))
+            {
+                errors.push(format!(
+                    "{}: must be one of {}",
+                    path,
+                    string
+                        .enumeration
+                        .iter()
+                        .flatten()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            let length = text.chars().count();
+            if string.min_length.is_some_and(|min| length < min) {
+                errors.push(format!(
+                    "{}: must be at least {} characters",
+                    path,
+                    string.min_length.unwrap_or_default()
+                ));
+            }
+            if string.max_length.is_some_and(|max| length > max) {
+                errors.push(format!(
+                    "{}: must be at most {} characters",
+                    path,
+                    string.max_length.unwrap_or_default()
+                ));
+            }
+        }
+        SchemaKind::Type(Type::Integer(integer)) => {
+            let Some(number) = value.as_i64().or_else(|| {
+                value
+                    .as_f64()
+                    .filter(|number| number.fract() == 0.0)
+                    .map(|number| number as i64)
+            }) else {
+                errors.push(format!("{}: expected integer", path));
+                return;
+            };
+            if !integer.enumeration.is_empty() && !integer.enumeration.contains(&Some(number)) {
+                errors.push(format!("{}: is not an allowed value", path));
+            }
+            check_range(
+                number as f64,
+                integer.minimum.map(|min| min as f64),
+                integer.exclusive_minimum,
+                integer.maximum.map(|max| max as f64),
+                integer.exclusive_maximum,
+                path,
+                errors,
+            );
+        }
+        SchemaKind::Type(Type::Number(number_type)) => {
+            let Some(number) = value.as_f64() else {
+                errors.push(format!("{}: expected number", path));
+                return;
+            };
+            check_range(
+                number,
+                number_type.minimum,
+                number_type.exclusive_minimum,
+                number_type.maximum,
+                number_type.exclusive_maximum,
+                path,
+                errors,
+            );
+        }
+        SchemaKind::Type(Type::Boolean(_)) => {
+            if !value.is_boolean() {
+                errors.push(format!("{}: expected boolean", path));
+            }
+        }
+        SchemaKind::Type(Type::Array(array)) => {
+            let Some(items) = value.as_array() else {
+                errors.push(format!("{}: expected array", path));
+                return;
+            };
+            if array.min_items.is_some_and(|min| items.len() < min) {
+                errors.push(format!(
+                    "{}: must have at least {} items",
+                    path,
+                    array.min_items.unwrap_or_default()
+                ));
+            }
+            if array.max_items.is_some_and(|max| items.len() > max) {
+                errors.push(format!(
+                    "{}: must have at most {} items",
+                    path,
+                    array.max_items.unwrap_or_default()
+                ));
+            }
+            if let Some(item_schema) = &array.items {
+                let item_schema = boxed(item_schema);
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = format!("{}[{}]", path, index);
+                    validate_value(openapi, &item_schema, item, &item_path, errors, depth + 1);
+                }
+            }
+        }
+        SchemaKind::Type(Type::Object(object)) => {
+            let Some(fields) = value.as_object() else {
+                errors.push(format!("{}: expected object", path));
+                return;
+            };
+            for required in &object.required {
+                if !fields.contains_key(required) {
+                    errors.push(format!("{}.{}: is required", path, required));
+                }
+            }
+            for (name, property) in &object.properties {
+                if let Some(field) = fields.get(name) {
+                    let field_path = format!("{}.{}", path, name);
+                    validate_value(
+                        openapi,
+                        &boxed(property),
+                        field,
+                        &field_path,
+                        errors,
+                        depth + 1,
+                    );
+                }
+            }
+            for (name, field) in fields {
+                if object.properties.contains_key(name) {
+                    continue;
+                }
+                let field_path = format!("{}.{}", path, name);
+                match &object.additional_properties {
+                    Some(AdditionalProperties::Any(false)) => {
+                        errors.push(format!("{}: is not allowed", field_path));
+                    }
+                    Some(AdditionalProperties::Schema(schema)) => {
+                        validate_value(openapi, schema, field, &field_path, errors, depth + 1)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        SchemaKind::OneOf { one_of: variants } | SchemaKind::AnyOf { any_of: variants } => {
+            let matches = variants.iter().any(|variant| {
+                let mut variant_errors = Vec::new();
+                validate_value(
+                    openapi,
+                    variant,
+                    value,
+                    path,
+                    &mut variant_errors,
+                    depth + 1,
+                );
+                variant_errors.is_empty()
+            });
+            if !matches {
+                errors.push(format!("{}: does not match any allowed schema", path));
+            }
+        }
+        SchemaKind::AllOf { all_of } => {
+            for part in all_of {
+                validate_value(openapi, part, value, path, errors, depth + 1);
+            }
+        }
+        SchemaKind::Not { not } => {
+            let mut not_errors = Vec::new();
+            validate_value(openapi, not, value, path, &mut not_errors, depth + 1);
+            if not_errors.is_empty() {
+                errors.push(format!("{}: matches a disallowed schema", path));
+            }
+        }
+        SchemaKind::Any(any) => {
+            let fields = value.as_object();
+            for required in &any.required {
+                if fields.is_some_and(|fields| !fields.contains_key(required)) {
+                    errors.push(format!("{}.{}: is required", path, required));
+                }
+            }
+            for (name, property) in &any.properties {
+                if let Some(field) = fields.and_then(|fields| fields.get(name)) {
+                    let field_path = format!("{}.{}", path, name);
+                    validate_value(
+                        openapi,
+                        &boxed(property),
+                        field,
+                        &field_path,
+                        errors,
+                        depth + 1,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_range(
+    number: f64,
+    minimum: Option<f64>,
+    exclusive_minimum: bool,
+    maximum: Option<f64>,
+    exclusive_maximum: bool,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    if let Some(min) = minimum {
+        if number < min || (exclusive_minimum && number == min) {
+            let operator = if exclusive_minimum { ">" } else { ">=" };
+            errors.push(format!("{}: must be {} {}", path, operator, min));
+        }
+    }
+    if let Some(max) = maximum {
+        if number > max || (exclusive_maximum && number == max) {
+            let operator = if exclusive_maximum { "<" } else { "<=" };
+            errors.push(format!("{}: must be {} {}", path, operator, max));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::router::Router;
+
+    const SPEC: &str = r##"{
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "paths": {
+            "/orders/{id}": {
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}],
+                "put": {
+                    "parameters": [
+                        {"name": "notify", "in": "query", "schema": {"type": "boolean"}},
+                        {"name": "X-Tenant", "in": "header", "required": true, "schema": {"type": "string"}}
+                    ],
+                    "requestBody": {"required": true, "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+                    "responses": {"200": {"description": "OK"}}
+                }
+            }
+        },
+        "components": {"schemas": {"Order": {
+            "type": "object",
+            "required": ["status", "items"],
+            "properties": {
+                "status": {"type": "string", "enum": ["pending", "paid"]},
+                "items": {"type": "array", "minItems": 1, "items": {"type": "object", "properties": {"quantity": {"type": "integer", "minimum": 1}}}}
+            }
+        }}}
+    }"##;
+
+    fn request(
+        path: &str,
+        query: &[(&str, &str)],
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> (OpenAPI, MockRequest) {
+        let openapi: OpenAPI = serde_json::from_str(SPEC).unwrap();
+        let request = MockRequest {
+            method: "PUT".to_string(),
+            path: path.to_string(),
+            query: query
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_ascii_lowercase(), value.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        };
+        (openapi, request)
+    }
+
+    #[test]
+    fn test_validate_request_accepts_valid_request() {
+        let (openapi, request) = request(
+            "/orders/42",
+            &[("notify", "true")],
+            &[("X-Tenant", "acme"), ("Content-Type", "application/json")],
+            r#"{"status": "paid", "items": [{"quantity": 2}]}"#,
+        );
+        let router = Router::new(&openapi);
+        let found = router.find("PUT", &request.path).unwrap();
+
+        assert!(validate_request(&openapi, &found, &request).is_empty());
+    }
+
+    #[test]
+    fn test_validate_request_reports_each_problem() {
+        let (openapi, request) = request(
+            "/orders/abc",
+            &[("notify", "maybe")],
+            &[("Content-Type", "application/json")],
+            r#"{"status": "lost", "items": [{"quantity": 0}]}"#,
+        );
+        let router = Router::new(&openapi);
+        let found = router.find("PUT", &request.path).unwrap();
+
+        assert_eq!(
+            validate_request(&openapi, &found, &request),
+            vec![
+                "query.notify: expected boolean",
+                "header.X-Tenant: is required",
+                "path.id: expected integer",
+                "body.status: must be one of pending, paid",
+                "body.items[0].quantity: must be >= 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_request_requires_body() {
+        let (openapi, request) = request("/orders/1", &[], &[("X-Tenant", "acme")], "");
+        let router = Router::new(&openapi);
+        let found = router.find("PUT", &request.path).unwrap();
+
+        assert_eq!(
+            validate_request(&openapi, &found, &request),
+            vec!["body: is required"]
+        );
+    }
+}
@@ -83,6 +83,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
                 SpecEntry {
@@ -91,6 +93,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
             ],
@@ -112,6 +116,8 @@ mod tests {
                 schemas: SchemasConfig::default(),
                 apis: ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: ModulesConfig::default(),
             }],
             ..Default::default()
@@ -135,6 +141,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
                 SpecEntry {
@@ -143,6 +151,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
             ],
@@ -167,6 +177,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
                 SpecEntry {
@@ -175,6 +187,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
             ],
@@ -195,6 +209,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
                 SpecEntry {
@@ -203,6 +219,8 @@ mod tests {
                     schemas: SchemasConfig::default(),
                     apis: ApisConfig::default(),
                     hooks: None,
+                    mocks: None,
+                    factories: None,
                     modules: ModulesConfig::default(),
                 },
             ],
@@ -223,6 +241,8 @@ mod tests {
                 schemas: SchemasConfig::default(),
                 apis: ApisConfig::default(),
                 hooks: None,
+                mocks: None,
+                factories: None,
                 modules: ModulesConfig::default(),
             }],
             ..Default::default()
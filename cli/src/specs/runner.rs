@@ -1,13 +1,16 @@
 use crate::config::model::{Config, SpecEntry};
 use crate::error::Result;
 use crate::formatter::FormatterManager;
-use crate::generator::api_client::generate_api_client_with_registry_and_engine_and_spec;
+use crate::generator::api_client::generate_api_client_with_config;
 use crate::generator::module_selector::select_modules;
-use crate::generator::swagger_parser::filter_common_schemas;
+use crate::generator::schema_resolver::SchemaResolver;
+use crate::generator::swagger_parser::{filter_common_schemas, OperationInfo, ParsedSpec};
+use crate::generator::symbols::Collision;
 use crate::generator::ts_typings::generate_typings_with_registry_and_engine_and_spec;
 use crate::generator::writer::write_api_client_with_options;
 use crate::generator::zod_schema::generate_zod_schemas_with_registry_and_engine_and_spec;
 use crate::progress::ProgressReporter;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Statistics for a single spec generation run
@@ -33,6 +36,340 @@ pub struct GenerateOptions {
     pub use_force: bool,
     pub verbose: bool,
     pub hook_type: Option<HookType>,
+    /// Leave out operations marked `deprecated: true` (`--no-deprecated`)
+    pub skip_deprecated: bool,
+}
+
+/// Prepare a freshly parsed spec for generation: resolve operation names with the spec's
+/// `apis.rename` overrides, then with `skip_deprecated` drop deprecated operations and the
+/// modules left without operations. Shared by `generate` and `update` so both produce the
+/// same modules and names. Returns the names that had to be disambiguated.
+pub fn prepare_parsed_spec(
+    parsed: &mut ParsedSpec,
+    spec: &SpecEntry,
+    skip_deprecated: bool,
+) -> Result<Vec<Collision>> {
+    // Names are resolved before deprecated operations are dropped so they don't depend on it
+    let collisions = parsed.resolve_operation_names(&spec.apis.rename.clone().unwrap_or_default());
+    if skip_deprecated {
+        crate::generator::deprecation::drop_deprecated_operations(parsed)?;
+    }
+    Ok(collisions)
+}
+
+/// Warnings for the name collisions in the modules being generated.
+pub fn collision_warnings(collisions: &[Collision], modules: &[String]) -> Vec<String> {
+    collisions
+        .iter()
+        .filter(|collision| modules.contains(&collision.module))
+        .map(|collision| {
+            format!(
+                "{}: `{}` for {} clashes with another generated name, generated as `{}` (set apis.rename to choose a name)",
+                collision.module, collision.name, collision.source, collision.resolved
+            )
+        })
+        .collect()
+}
+
+/// What `generate` and `update` share to write the files of one spec.
+pub struct SpecOutput<'a> {
+    pub config: &'a Config,
+    pub spec: &'a SpecEntry,
+    pub parsed: &'a ParsedSpec,
+    /// Schemas of the selected modules, without the shared ones
+    pub module_schemas: &'a HashMap<String, Vec<String>>,
+    pub common_schemas: &'a [String],
+    pub default_server: Option<&'a str>,
+    /// Dependency graph for the spec's factories (`None` when factories are not configured)
+    pub factories_resolver: Option<&'a SchemaResolver>,
+    pub backup: bool,
+    pub force: bool,
+}
+
+/// Dependency graph factories use to break reference cycles, built when the spec has
+/// `factories` configured.
+pub fn factories_resolver(spec: &SpecEntry, parsed: &ParsedSpec) -> Result<Option<SchemaResolver>> {
+    if spec.factories.is_none() {
+        return Ok(None);
+    }
+    let mut resolver = SchemaResolver::new(parsed.openapi.clone());
+    resolver.build_dependency_graph()?;
+    Ok(Some(resolver))
+}
+
+/// Write the JSON Schema files of `module` when `schemas.emit_json_schema` is set.
+/// Returns the number of files written.
+pub fn write_module_json_schemas(
+    output: &SpecOutput,
+    module: &str,
+    schema_names: &[String],
+) -> Result<usize> {
+    if !output.spec.schemas.emit_json_schema {
+        return Ok(0);
+    }
+    let json_schemas = crate::generator::json_schema::generate_json_schemas(
+        &output.parsed.openapi,
+        module,
+        schema_names,
+        output.common_schemas,
+        output.module_schemas,
+    )?;
+    let files = crate::generator::writer::write_json_schemas_with_options(
+        &PathBuf::from(&output.spec.schemas.output),
+        module,
+        &json_schemas,
+        output.backup,
+        output.force,
+    )?;
+    Ok(files.len())
+}
+
+/// Write the files shared by every module of the spec: its `vika-client.ts` and
+/// `security.ts` (or the Angular API config) and its servers. Returns the number of files written.
+pub fn write_spec_files(output: &SpecOutput) -> Result<usize> {
+    let apis_config = &output.spec.apis;
+    let apis_dir = PathBuf::from(&apis_config.output);
+    let mut files = 0;
+
+    if apis_config.style == "angular" {
+        // Angular services share a base URL InjectionToken instead of the runtime client
+        let api_config = crate::generator::api_client::generate_angular_api_config(
+            Some(&output.spec.name),
+            apis_config.base_url.as_deref().or(output.default_server),
+        );
+        crate::generator::writer::write_angular_api_config_with_options(
+            &apis_dir,
+            &api_config,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+    } else {
+        let runtime_import = crate::generator::api_client::runtime_import_path(
+            Some(&output.config.root_dir),
+            Some(&apis_config.output),
+            0,
+        );
+
+        // Each spec gets its own client instance, configured from its `apis` settings
+        let spec_client = crate::generator::spec_client::generate_spec_client(
+            &output.spec.name,
+            &runtime_import,
+            apis_config,
+            output.default_server,
+        );
+        crate::generator::writer::write_spec_client_with_options(
+            &apis_dir,
+            &spec_client,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+
+        // Security schemes are shared by every module of the spec and use its client instance
+        let spec_client_import = crate::generator::spec_client::spec_client_import_path(0);
+        if let Some(security_module) = crate::generator::security::generate_security_module(
+            &output.parsed.openapi,
+            &spec_client_import,
+        ) {
+            crate::generator::writer::write_security_module_with_options(
+                &apis_dir,
+                &security_module,
+                output.backup,
+                output.force,
+            )?;
+            files += 1;
+        }
+    }
+
+    // Servers declared by the spec, with typed variables
+    if let Some(servers_module) =
+        crate::generator::servers::generate_servers_module(&output.parsed.openapi)
+    {
+        crate::generator::writer::write_servers_module_with_options(
+            &apis_dir,
+            &servers_module,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+    }
+
+    Ok(files)
+}
+
+/// Write the MSW handlers and test data factories of `module` when the spec configures
+/// `mocks` and `factories`. Returns the number of files written.
+pub fn write_module_test_helpers(
+    output: &SpecOutput,
+    module: &str,
+    operations: &[OperationInfo],
+    schema_names: &[String],
+) -> Result<usize> {
+    let mut files = 0;
+
+    if let Some(ref mocks_config) = output.spec.mocks {
+        let api_import = crate::generator::msw::api_import_path(
+            &mocks_config.output,
+            &output.spec.apis.output,
+            module,
+        );
+        let handlers = crate::generator::msw::generate_msw_handlers(
+            &output.parsed.openapi,
+            operations,
+            module,
+            &api_import,
+            &output.spec.apis,
+        )?;
+        crate::generator::writer::write_mocks_file_with_options(
+            &PathBuf::from(&mocks_config.output),
+            &format!(
+                "{}/index.ts",
+                crate::generator::utils::sanitize_module_name(module)
+            ),
+            &handlers,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+    }
+
+    if let (Some(ref factories_config), Some(resolver)) =
+        (&output.spec.factories, output.factories_resolver)
+    {
+        if !schema_names.is_empty() {
+            let schemas_import = crate::generator::factories::schemas_import_path(
+                &factories_config.output,
+                &output.spec.schemas.output,
+                module,
+            );
+            let factories = crate::generator::factories::generate_factories(
+                &crate::generator::factories::FactoriesContext {
+                    openapi: &output.parsed.openapi,
+                    resolver,
+                    module_name: module,
+                    schema_names,
+                    common_schemas: output.common_schemas,
+                    module_schemas: output.module_schemas,
+                    schemas_import: &schemas_import,
+                },
+            );
+            crate::generator::writer::write_factories_file_with_options(
+                &PathBuf::from(&factories_config.output),
+                &format!(
+                    "{}.ts",
+                    crate::generator::utils::sanitize_module_name(module)
+                ),
+                &factories,
+                output.backup,
+                output.force,
+            )?;
+            files += 1;
+        }
+    }
+
+    Ok(files)
+}
+
+/// Write the files that combine the generated `modules`: the SDK class, the MSW
+/// utilities and handlers index, and the shared and seeded factories. Returns the
+/// number of files written.
+pub fn write_spec_aggregates(output: &SpecOutput, modules: &[String]) -> Result<usize> {
+    let apis_config = &output.spec.apis;
+    let mut files = 0;
+
+    // The SDK class composes the generated modules
+    if let Some(ref class_name) = apis_config.client_class {
+        let runtime_import = crate::generator::api_client::runtime_import_path(
+            Some(&output.config.root_dir),
+            Some(&apis_config.output),
+            0,
+        );
+        let has_security =
+            !crate::generator::security::collect_security_schemes(&output.parsed.openapi)
+                .is_empty();
+        let sdk_client = crate::generator::sdk_client::generate_sdk_client(
+            class_name,
+            modules,
+            &runtime_import,
+            apis_config,
+            output.default_server,
+            has_security,
+        );
+        crate::generator::writer::write_sdk_client_with_options(
+            &PathBuf::from(&apis_config.output),
+            &sdk_client,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+    }
+
+    // The MSW utilities and combined handlers cover the generated modules
+    if let Some(ref mocks_config) = output.spec.mocks {
+        let mocks_dir = PathBuf::from(&mocks_config.output);
+        crate::generator::writer::write_mocks_file_with_options(
+            &mocks_dir,
+            crate::generator::msw::MSW_UTILS_FILE,
+            crate::generator::msw::MSW_UTILS,
+            output.backup,
+            output.force,
+        )?;
+        crate::generator::writer::write_mocks_file_with_options(
+            &mocks_dir,
+            crate::generator::msw::MSW_HANDLERS_FILE,
+            &crate::generator::msw::generate_msw_index(modules),
+            output.backup,
+            output.force,
+        )?;
+        files += 2;
+    }
+
+    // Factories of shared schemas and the seeded helpers every factories file imports
+    if let (Some(ref factories_config), Some(resolver)) =
+        (&output.spec.factories, output.factories_resolver)
+    {
+        let factories_dir = PathBuf::from(&factories_config.output);
+        if !output.common_schemas.is_empty() {
+            let schemas_import = crate::generator::factories::schemas_import_path(
+                &factories_config.output,
+                &output.spec.schemas.output,
+                crate::generator::factories::FACTORIES_COMMON_MODULE,
+            );
+            let factories = crate::generator::factories::generate_factories(
+                &crate::generator::factories::FactoriesContext {
+                    openapi: &output.parsed.openapi,
+                    resolver,
+                    module_name: crate::generator::factories::FACTORIES_COMMON_MODULE,
+                    schema_names: output.common_schemas,
+                    common_schemas: output.common_schemas,
+                    module_schemas: output.module_schemas,
+                    schemas_import: &schemas_import,
+                },
+            );
+            crate::generator::writer::write_factories_file_with_options(
+                &factories_dir,
+                &format!(
+                    "{}.ts",
+                    crate::generator::factories::FACTORIES_COMMON_MODULE
+                ),
+                &factories,
+                output.backup,
+                output.force,
+            )?;
+            files += 1;
+        }
+        crate::generator::writer::write_factories_file_with_options(
+            &factories_dir,
+            crate::generator::factories::FACTORIES_RANDOM_FILE,
+            crate::generator::factories::FACTORIES_RANDOM,
+            output.backup,
+            output.force,
+        )?;
+        files += 1;
+    }
+
+    Ok(files)
 }
 
 /// Generate code for a single spec
@@ -46,12 +383,13 @@ pub async fn run_single_spec(
     let spec_name = Some(spec.name.as_str());
 
     progress.start_spinner(&format!("Fetching spec from: {}", spec.path));
-    let parsed = crate::generator::swagger_parser::fetch_and_parse_spec_with_cache_and_name(
+    let mut parsed = crate::generator::swagger_parser::fetch_and_parse_spec_with_cache_and_name(
         &spec.path,
         options.use_cache,
         Some(&spec.name),
     )
     .await?;
+    let collisions = prepare_parsed_spec(&mut parsed, spec, options.skip_deprecated)?;
     progress.finish_spinner(&format!(
         "Parsed spec with {} modules",
         parsed.modules.len()
@@ -94,6 +432,10 @@ pub async fn run_single_spec(
         select_modules(&available_modules, &modules_config.ignore)?
     };
 
+    for warning in collision_warnings(&collisions, &selected_modules) {
+        progress.warning(&warning);
+    }
+
     // Filter common schemas based on selected modules only
     let (filtered_module_schemas, common_schemas) =
         filter_common_schemas(&parsed.module_schemas, &selected_modules);
@@ -101,12 +443,35 @@ pub async fn run_single_spec(
     // Generate code for each module (using spec-specific or global output directories)
     let schemas_dir = PathBuf::from(&schemas_config.output);
     let apis_dir = PathBuf::from(&apis_config.output);
-    let _root_dir = PathBuf::from(&config.root_dir);
+    let root_dir = PathBuf::from(&config.root_dir);
 
     // Get hooks config (use defaults if not specified)
     let hooks_config = spec.hooks.clone().unwrap_or_default();
 
-    // Runtime client is generated once at root_dir level (handled in generate.rs)
+    let default_server = crate::generator::servers::default_server_url(&parsed.openapi);
+
+    // Runtime client is generated once at root_dir level (handled in generate.rs),
+    // the axios client only for specs that use the axios style
+    if apis_config.style == "axios" {
+        crate::generator::writer::write_axios_runtime_client_with_options(
+            &root_dir,
+            options.use_backup,
+            options.use_force,
+        )?;
+    }
+
+    let resolver = factories_resolver(spec, &parsed)?;
+    let output = SpecOutput {
+        config,
+        spec,
+        parsed: &parsed,
+        module_schemas: &filtered_module_schemas,
+        common_schemas: &common_schemas,
+        default_server: default_server.as_deref(),
+        factories_resolver: resolver.as_ref(),
+        backup: options.use_backup,
+        force: options.use_force,
+    };
 
     let mut total_files = 0;
 
@@ -160,12 +525,17 @@ pub async fn run_single_spec(
             &common_schemas,
         )?;
         total_files += common_files.len();
+
+        total_files += write_module_json_schemas(&output, "common", &common_schemas)?;
         progress.finish_spinner(&format!(
             "Generated {} common schema files",
             common_files.len()
         ));
     }
 
+    total_files += write_spec_files(&output)?;
+
+    let mut sdk_modules = Vec::new();
     for module in &selected_modules {
         progress.start_spinner(&format!("Generating code for module: {}", module));
 
@@ -238,10 +608,11 @@ pub async fn run_single_spec(
             spec_name,
             existing_types: &types,
             existing_zod_schemas: &zod_schemas,
+            include_path_params: apis_config.validate_requests != "off",
         })?;
 
         // Generate API client (using same enum registry as schemas)
-        let api_result = generate_api_client_with_registry_and_engine_and_spec(
+        let api_result = generate_api_client_with_config(
             &parsed.openapi,
             &operations,
             module,
@@ -252,6 +623,7 @@ pub async fn run_single_spec(
             Some(&config.root_dir),
             Some(&apis_config.output),
             Some(&schemas_config.output),
+            apis_config,
         )?;
 
         // Response types are written to API files, not schema files
@@ -279,6 +651,8 @@ pub async fn run_single_spec(
         )?;
         total_files += schema_files.len();
 
+        total_files += write_module_json_schemas(&output, module, &module_schema_names)?;
+
         // Write API client (with backup and conflict detection)
         let api_files = write_api_client_with_options(
             &apis_dir,
@@ -289,19 +663,24 @@ pub async fn run_single_spec(
             options.use_force,
         )?;
         total_files += api_files.len();
+        sdk_modules.push(module.clone());
 
         // Determine hook type: options.hook_type (from CLI) takes precedence,
-        // otherwise check spec's hooks.library config
-        let hook_type = options.hook_type.or_else(|| {
-            hooks_config
-                .library
-                .as_ref()
-                .and_then(|lib| match lib.as_str() {
-                    "react-query" => Some(HookType::ReactQuery),
-                    "swr" => Some(HookType::Swr),
-                    _ => None,
-                })
-        });
+        // otherwise check spec's hooks.library config.
+        // Angular services return Observables, which React hooks cannot wrap.
+        let hook_type = options
+            .hook_type
+            .or_else(|| {
+                hooks_config
+                    .library
+                    .as_ref()
+                    .and_then(|lib| match lib.as_str() {
+                        "react-query" => Some(HookType::ReactQuery),
+                        "swr" => Some(HookType::Swr),
+                        _ => None,
+                    })
+            })
+            .filter(|_| apis_config.style != "angular");
 
         // Generate hooks if requested
         if let Some(hook_type) = hook_type {
@@ -309,7 +688,13 @@ pub async fn run_single_spec(
 
             // Generate query keys first (hooks depend on them)
             use crate::generator::query_keys::generate_query_keys;
-            let query_keys_context = generate_query_keys(&operations, module, spec_name);
+            let query_keys_context = generate_query_keys(
+                &operations,
+                module,
+                spec_name,
+                Some(&hooks_config.query_keys_output),
+                apis_config,
+            );
 
             // Render query keys template
             let query_keys_content = template_engine.render(
@@ -348,6 +733,7 @@ pub async fn run_single_spec(
                         Some(&schemas_config.output),
                         Some(&hooks_config.output),
                         Some(&hooks_config.query_keys_output),
+                        apis_config,
                     )?
                 }
                 HookType::Swr => {
@@ -364,6 +750,7 @@ pub async fn run_single_spec(
                         Some(&schemas_config.output),
                         Some(&hooks_config.output),
                         Some(&hooks_config.query_keys_output),
+                        apis_config,
                     )?
                 }
             };
@@ -390,6 +777,10 @@ pub async fn run_single_spec(
             ));
         }
 
+        // MSW handlers and test data factories, if configured
+        total_files +=
+            write_module_test_helpers(&output, module, &operations, &module_schema_names)?;
+
         progress.finish_spinner(&format!(
             "Generated {} files for module: {}",
             schema_files.len() + api_files.len(),
@@ -397,6 +788,9 @@ pub async fn run_single_spec(
         ));
     }
 
+    // The SDK class, MSW handlers index and shared factories cover the modules generated above
+    total_files += write_spec_aggregates(&output, &sdk_modules)?;
+
     // Format all generated files with prettier/biome if available
     let mut all_generated_files = Vec::new();
 
@@ -441,6 +835,22 @@ pub async fn run_single_spec(
         }
     }
 
+    // Collect MSW handler files if mocks were generated
+    if let Some(ref mocks_config) = spec.mocks {
+        let mocks_dir = PathBuf::from(&mocks_config.output);
+        if mocks_dir.exists() {
+            collect_ts_files(&mocks_dir, &mut all_generated_files)?;
+        }
+    }
+
+    // Collect factory files if factories were generated
+    if let Some(ref factories_config) = spec.factories {
+        let factories_dir = PathBuf::from(&factories_config.output);
+        if factories_dir.exists() {
+            collect_ts_files(&factories_dir, &mut all_generated_files)?;
+        }
+    }
+
     // Format files if formatter is available
     if !all_generated_files.is_empty() {
         // Get current directory to resolve relative paths
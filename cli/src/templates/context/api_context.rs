@@ -21,6 +21,10 @@ pub struct ApiContext {
     pub description: String,
     /// Spec name (for multi-spec mode)
     pub spec_name: Option<String>,
+    /// The function returns an async generator of streamed events (not `async`)
+    pub streaming: bool,
+    /// `@deprecated` JSDoc tag when the operation is deprecated
+    pub deprecated: Option<String>,
 }
 
 /// Parameter information for API functions.
@@ -86,6 +90,8 @@ impl ApiContext {
             params,
             description,
             spec_name,
+            streaming: false,
+            deprecated: None,
         }
     }
 }
@@ -10,6 +10,8 @@ pub struct TypeContext {
     pub is_alias: bool,
     pub alias_target: Option<String>,
     pub description: Option<String>,
+    /// `@deprecated` JSDoc tag when the schema is deprecated
+    pub deprecated: Option<String>,
     /// Spec name (for multi-spec mode)
     pub spec_name: Option<String>,
 }
@@ -21,6 +23,8 @@ pub struct Field {
     pub type_name: String,
     pub optional: bool,
     pub description: Option<String>,
+    /// `@deprecated` JSDoc tag when the field is deprecated
+    pub deprecated: Option<String>,
 }
 
 impl TypeContext {
@@ -39,6 +43,7 @@ impl TypeContext {
             is_alias: false,
             alias_target: None,
             description,
+            deprecated: None,
             spec_name,
         }
     }
@@ -57,6 +62,7 @@ impl TypeContext {
             is_alias: false,
             alias_target: None,
             description: None,
+            deprecated: None,
             spec_name,
         }
     }
@@ -71,6 +77,7 @@ impl TypeContext {
             is_alias: true,
             alias_target: Some(alias_target),
             description: None,
+            deprecated: None,
             spec_name,
         }
     }
@@ -89,6 +96,7 @@ impl Field {
             type_name,
             optional,
             description,
+            deprecated: None,
         }
     }
 }
@@ -8,6 +8,8 @@ pub struct ZodContext {
     pub is_enum: bool,
     pub enum_values: Option<Vec<String>>,
     pub description: Option<String>,
+    /// `@deprecated` JSDoc tag when the schema is deprecated
+    pub deprecated: Option<String>,
     pub needs_type_annotation: bool,
     /// Spec name (for multi-spec mode)
     pub spec_name: Option<String>,
@@ -27,6 +29,7 @@ impl ZodContext {
             is_enum: false,
             enum_values: None,
             description,
+            deprecated: None,
             needs_type_annotation: false,
             spec_name,
         }
@@ -45,6 +48,7 @@ impl ZodContext {
             is_enum: false,
             enum_values: None,
             description,
+            deprecated: None,
             needs_type_annotation: true,
             spec_name,
         }
@@ -69,6 +73,7 @@ impl ZodContext {
             is_enum: true,
             enum_values: Some(enum_values),
             description: None,
+            deprecated: None,
             needs_type_annotation: false,
             spec_name,
         }
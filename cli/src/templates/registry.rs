@@ -9,6 +9,9 @@ pub enum TemplateId {
     ZodSchema,
     ZodEnum,
     ApiClientFetch,
+    ApiClientAxios,
+    ApiClientAngular,
+    ApiClientSdkMethod,
     ReactQueryQuery,
     ReactQueryMutation,
     SwrQuery,
@@ -17,6 +20,7 @@ pub enum TemplateId {
     RuntimeTypes,
     RuntimeHttpClient,
     RuntimeIndex,
+    RuntimeAxiosClient,
 }
 
 impl TemplateId {
@@ -29,6 +33,9 @@ impl TemplateId {
             TemplateId::ZodSchema => "zod-schema",
             TemplateId::ZodEnum => "zod-enum",
             TemplateId::ApiClientFetch => "api-client-fetch",
+            TemplateId::ApiClientAxios => "api-client-axios",
+            TemplateId::ApiClientAngular => "api-client-angular",
+            TemplateId::ApiClientSdkMethod => "api-client-sdk-method",
             TemplateId::ReactQueryQuery => "hooks/react-query-query",
             TemplateId::ReactQueryMutation => "hooks/react-query-mutation",
             TemplateId::SwrQuery => "hooks/swr-query",
@@ -37,6 +44,7 @@ impl TemplateId {
             TemplateId::RuntimeTypes => "runtime/types",
             TemplateId::RuntimeHttpClient => "runtime/http-client",
             TemplateId::RuntimeIndex => "runtime/index",
+            TemplateId::RuntimeAxiosClient => "runtime/axios-client",
         }
     }
 
@@ -54,6 +62,9 @@ impl TemplateId {
             TemplateId::ZodSchema,
             TemplateId::ZodEnum,
             TemplateId::ApiClientFetch,
+            TemplateId::ApiClientAxios,
+            TemplateId::ApiClientAngular,
+            TemplateId::ApiClientSdkMethod,
             TemplateId::ReactQueryQuery,
             TemplateId::ReactQueryMutation,
             TemplateId::SwrQuery,
@@ -62,6 +73,7 @@ impl TemplateId {
             TemplateId::RuntimeTypes,
             TemplateId::RuntimeHttpClient,
             TemplateId::RuntimeIndex,
+            TemplateId::RuntimeAxiosClient,
         ]
     }
 }
@@ -77,6 +89,9 @@ impl FromStr for TemplateId {
             "zod-schema" => Ok(TemplateId::ZodSchema),
             "zod-enum" => Ok(TemplateId::ZodEnum),
             "api-client-fetch" => Ok(TemplateId::ApiClientFetch),
+            "api-client-axios" => Ok(TemplateId::ApiClientAxios),
+            "api-client-angular" => Ok(TemplateId::ApiClientAngular),
+            "api-client-sdk-method" => Ok(TemplateId::ApiClientSdkMethod),
             "hooks/react-query-query" => Ok(TemplateId::ReactQueryQuery),
             "hooks/react-query-mutation" => Ok(TemplateId::ReactQueryMutation),
             "hooks/swr-query" => Ok(TemplateId::SwrQuery),
@@ -85,6 +100,7 @@ impl FromStr for TemplateId {
             "runtime/types" => Ok(TemplateId::RuntimeTypes),
             "runtime/http-client" => Ok(TemplateId::RuntimeHttpClient),
             "runtime/index" => Ok(TemplateId::RuntimeIndex),
+            "runtime/axios-client" => Ok(TemplateId::RuntimeAxiosClient),
             _ => Err(format!("Unknown template: {}", s)),
         }
     }
@@ -117,7 +133,7 @@ mod tests {
     #[test]
     fn test_template_id_all() {
         let all = TemplateId::all();
-        assert_eq!(all.len(), 14);
+        assert_eq!(all.len(), 18);
         assert!(all.contains(&TemplateId::TypeInterface));
     }
 }
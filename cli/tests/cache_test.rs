@@ -32,11 +32,8 @@ fn test_get_cached_spec_no_cache() {
         result.is_ok() || result.is_err(),
         "get_cached_spec should return a result"
     );
-    if result.is_ok() {
-        assert!(
-            result.unwrap().is_none(),
-            "Should return None when no cache exists"
-        );
+    if let Ok(cached) = result {
+        assert!(cached.is_none(), "Should return None when no cache exists");
     }
 
     if let Some(orig) = original_dir {
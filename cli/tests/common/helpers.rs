@@ -41,6 +41,7 @@ pub fn create_mock_config_with_paths(schemas_output: &str, apis_output: &str) ->
             schemas: vika_cli::config::model::SchemasConfig {
                 output: schemas_output.to_string(),
                 naming: "PascalCase".to_string(),
+                ..vika_cli::config::model::SchemasConfig::default()
             },
             apis: vika_cli::config::model::ApisConfig {
                 output: apis_output.to_string(),
@@ -50,6 +51,8 @@ pub fn create_mock_config_with_paths(schemas_output: &str, apis_output: &str) ->
                 ..vika_cli::config::model::ApisConfig::default()
             },
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig {
                 ignore: vec![],
                 selected: vec![],
@@ -60,6 +63,7 @@ pub fn create_mock_config_with_paths(schemas_output: &str, apis_output: &str) ->
 }
 
 /// Setup test environment (create temp dir, etc.)
+#[allow(dead_code)]
 pub fn setup_test_env() -> TempDir {
     create_temp_dir()
 }
@@ -110,3 +114,44 @@ pub fn minimal_spec_json() -> &'static str {
     }
     "#
 }
+
+/// Run `script` against the generated runtime with Node's TypeScript type stripping
+/// and return its trimmed stdout. The script imports `./runtime/http-client.ts`.
+#[allow(dead_code)]
+pub fn run_runtime_script(script: &str) -> String {
+    let supported = std::process::Command::new("node")
+        .args(["--experimental-strip-types", "-e", ""])
+        .output()
+        .is_ok_and(|output| output.status.success());
+    assert!(
+        supported,
+        "runtime script tests require Node >= 22.6 (node --experimental-strip-types)"
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    vika_cli::generator::writer::write_runtime_client(
+        temp_dir.path(),
+        None,
+        Some(&vika_cli::config::model::ApisConfig::default()),
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("package.json"),
+        r#"{"type": "module"}"#,
+    )
+    .unwrap();
+    let script_path = temp_dir.path().join("script.ts");
+    std::fs::write(&script_path, script).unwrap();
+
+    let output = std::process::Command::new("node")
+        .args(["--experimental-strip-types", "--no-warnings"])
+        .arg(&script_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
@@ -78,6 +78,8 @@ paths:
             schemas: SchemasConfig::default(),
             apis: ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: ModulesConfig::default(),
         }],
         ..Default::default()
@@ -139,6 +141,8 @@ paths:
             schemas: SchemasConfig::default(),
             apis: ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: ModulesConfig {
                 ignore: vec![],
                 selected: vec!["test".to_string()],
@@ -149,7 +153,7 @@ paths:
     save_config(&config).unwrap();
 
     // Update command succeeds even if no operations found (graceful handling)
-    let result = update::run().await;
+    let result = update::run(false).await;
     // Should succeed - update handles missing modules gracefully
     assert!(result.is_ok());
 
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use vika_cli::commands::update;
+use vika_cli::config::loader::{load_config, save_config};
+use vika_cli::config::model::{
+    ApisConfig, Config, FactoriesConfig, MocksConfig, ModulesConfig, SchemasConfig, SpecEntry,
+};
+
+// `update` works in the current directory, so tests in this file take turns
+static CWD_LOCK: Mutex<()> = Mutex::const_new(());
+
+fn write_project(spec_content: &str, apis: ApisConfig, selected: &[&str]) {
+    fs::write("spec.json", spec_content).unwrap();
+    let config = Config {
+        specs: vec![SpecEntry {
+            name: "test".to_string(),
+            path: "spec.json".to_string(),
+            schemas: SchemasConfig::default(),
+            apis,
+            hooks: None,
+            mocks: None,
+            factories: None,
+            modules: ModulesConfig {
+                ignore: vec![],
+                selected: selected.iter().map(|m| m.to_string()).collect(),
+            },
+        }],
+        ..Default::default()
+    };
+    save_config(&config).unwrap();
+}
+
+const DEPRECATED_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}, {"name": "legacy"}],
+    "paths": {
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "deprecated": true,
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/v2/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrderV2",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/reports": {
+            "get": {
+                "tags": ["legacy"],
+                "operationId": "listReports",
+                "deprecated": true,
+                "responses": {"200": {"description": "OK"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_update_no_deprecated() {
+    let _cwd = CWD_LOCK.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    let original_dir = env::current_dir().unwrap();
+    env::set_current_dir(temp_dir.path()).unwrap();
+
+    write_project(DEPRECATED_SPEC, ApisConfig::default(), &["shop", "legacy"]);
+    let result = update::run(true).await;
+    let shop = fs::read_to_string("src/apis/shop/index.ts");
+    let legacy_exists = std::path::Path::new("src/apis/legacy").exists();
+    env::set_current_dir(original_dir).unwrap();
+
+    result.unwrap();
+    let shop = shop.unwrap();
+    assert!(shop.contains("export const getOrderV2 = "));
+    assert!(!shop.contains("export const getOrder = "));
+    // Every operation of `legacy` is deprecated, so the module is not generated
+    assert!(!legacy_exists);
+}
+
+const COLLIDING_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "get_order",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/v2/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_update_applies_renames() {
+    let _cwd = CWD_LOCK.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    let original_dir = env::current_dir().unwrap();
+    env::set_current_dir(temp_dir.path()).unwrap();
+
+    let apis = ApisConfig {
+        rename: Some(HashMap::from([(
+            "get_order".to_string(),
+            "getLegacyOrder".to_string(),
+        )])),
+        ..ApisConfig::default()
+    };
+    write_project(COLLIDING_SPEC, apis, &["shop"]);
+    let result = update::run(false).await;
+    let shop = fs::read_to_string("src/apis/shop/index.ts");
+    env::set_current_dir(original_dir).unwrap();
+
+    result.unwrap();
+    let shop = shop.unwrap();
+    assert!(shop.contains("export const getLegacyOrder = "));
+    assert!(shop.contains("export const getOrder = "));
+    assert!(!shop.contains("getOrder2"));
+}
+
+#[tokio::test]
+async fn test_update_writes_spec_files() {
+    let _cwd = CWD_LOCK.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    let original_dir = env::current_dir().unwrap();
+    env::set_current_dir(temp_dir.path()).unwrap();
+
+    write_project(COLLIDING_SPEC, ApisConfig::default(), &["shop"]);
+    let mut config = load_config().unwrap();
+    config.specs[0].mocks = Some(MocksConfig::default());
+    config.specs[0].factories = Some(FactoriesConfig::default());
+    save_config(&config).unwrap();
+    let result = update::run(false).await;
+    let spec_client_exists = std::path::Path::new("src/apis/vika-client.ts").exists();
+    let handlers = fs::read_to_string("src/mocks/handlers.ts");
+    let module_handlers_exists = std::path::Path::new("src/mocks/shop/index.ts").exists();
+    let random_exists = std::path::Path::new("src/factories/random.ts").exists();
+    env::set_current_dir(original_dir).unwrap();
+
+    // update writes the same spec-level files as generate
+    result.unwrap();
+    assert!(spec_client_exists);
+    assert!(handlers.unwrap().contains("shop"));
+    assert!(module_handlers_exists);
+    assert!(random_exists);
+}
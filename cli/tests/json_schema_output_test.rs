@@ -0,0 +1,169 @@
+mod common;
+
+use insta::assert_snapshot;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+use vika_cli::generator::swagger_parser::{fetch_and_parse_spec, filter_common_schemas};
+use vika_cli::specs::runner::{write_module_json_schemas, SpecOutput};
+
+const SPEC: &str = r##"{
+  "openapi": "3.0.0",
+  "info": {"title": "Shop", "version": "1.0.0"},
+  "paths": {
+    "/users/{id}": {
+      "get": {
+        "tags": ["users"],
+        "operationId": "getUser",
+        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+        "responses": {
+          "200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+          "404": {"description": "Not found", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Error"}}}}
+        }
+      }
+    },
+    "/orders/{id}": {
+      "get": {
+        "tags": ["orders"],
+        "operationId": "getOrder",
+        "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}],
+        "responses": {
+          "200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+          "400": {"description": "Bad request", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Error"}}}}
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": {
+        "type": "object",
+        "required": ["id"],
+        "properties": {
+          "id": {"type": "string", "format": "uuid"},
+          "address": {"$ref": "#/components/schemas/Address"}
+        }
+      },
+      "Address": {
+        "type": "object",
+        "properties": {"city": {"type": "string", "example": "Berlin"}}
+      },
+      "Order": {
+        "type": "object",
+        "required": ["id", "items"],
+        "properties": {
+          "id": {"type": "integer", "minimum": 1},
+          "items": {"type": "array", "items": {"$ref": "#/components/schemas/OrderItem"}},
+          "error": {"$ref": "#/components/schemas/Error"}
+        }
+      },
+      "OrderItem": {
+        "type": "object",
+        "properties": {"quantity": {"type": "integer", "minimum": 0, "exclusiveMinimum": true}}
+      },
+      "Error": {
+        "type": "object",
+        "required": ["message"],
+        "properties": {"message": {"type": "string"}}
+      }
+    }
+  }
+}"##;
+
+/// Every written `*.schema.json`, relative to the schemas output, in path order.
+fn read_written_schemas(schemas_dir: &std::path::Path) -> String {
+    let mut paths = Vec::new();
+    for module in fs::read_dir(schemas_dir).unwrap() {
+        for file in fs::read_dir(module.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            if path.to_string_lossy().ends_with(".schema.json") {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| {
+            format!(
+                "// {}\n{}",
+                path.strip_prefix(schemas_dir).unwrap().display(),
+                fs::read_to_string(path).unwrap()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[tokio::test]
+async fn test_write_module_json_schemas_rewrites_refs_to_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("shop.json");
+    fs::write(&spec_path, SPEC).unwrap();
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let schemas_dir = temp_dir.path().join("schemas");
+    let mut config = common::create_mock_config_with_paths(
+        schemas_dir.to_str().unwrap(),
+        temp_dir.path().join("apis").to_str().unwrap(),
+    );
+    config.specs[0].schemas.emit_json_schema = true;
+
+    let modules = vec!["orders".to_string(), "users".to_string()];
+    let (module_schemas, common_schemas) = filter_common_schemas(&parsed.module_schemas, &modules);
+    let output = SpecOutput {
+        config: &config,
+        spec: &config.specs[0],
+        parsed: &parsed,
+        module_schemas: &module_schemas,
+        common_schemas: &common_schemas,
+        default_server: None,
+        factories_resolver: None,
+        backup: false,
+        force: true,
+    };
+
+    let mut written = write_module_json_schemas(&output, "common", &common_schemas).unwrap();
+    for module in &modules {
+        let schema_names = module_schemas.get(module).cloned().unwrap_or_default();
+        written += write_module_json_schemas(&output, module, &schema_names).unwrap();
+    }
+
+    assert_eq!(common_schemas, vec!["Error".to_string()]);
+    assert_eq!(written, 5);
+    assert_snapshot!(read_written_schemas(&schemas_dir));
+}
+
+#[tokio::test]
+async fn test_write_module_json_schemas_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("shop.json");
+    fs::write(&spec_path, SPEC).unwrap();
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let schemas_dir = temp_dir.path().join("schemas");
+    let config = common::create_mock_config_with_paths(
+        schemas_dir.to_str().unwrap(),
+        temp_dir.path().join("apis").to_str().unwrap(),
+    );
+    let output = SpecOutput {
+        config: &config,
+        spec: &config.specs[0],
+        parsed: &parsed,
+        module_schemas: &HashMap::new(),
+        common_schemas: &[],
+        default_server: None,
+        factories_resolver: None,
+        backup: false,
+        force: true,
+    };
+
+    let written = write_module_json_schemas(&output, "users", &["User".to_string()]).unwrap();
+
+    assert_eq!(written, 0);
+    assert!(!schemas_dir.exists());
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use vika_cli::mock::server::serve;
+use vika_cli::mock::{MockOptions, MockServer};
+
+const SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "servers": [{"url": "https://api.example.com/v1"}],
+    "paths": {
+        "/orders": {
+            "post": {
+                "operationId": "createOrder",
+                "requestBody": {"required": true, "content": {"application/json": {"schema": {"$ref": "#/components/schemas/NewOrder"}}}},
+                "responses": {"201": {"description": "Created", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}}}
+            }
+        },
+        "/orders/{id}": {
+            "get": {
+                "operationId": "getOrder",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "content": {"application/json": {
+                            "schema": {"$ref": "#/components/schemas/Order"},
+                            "examples": {"paid": {"value": {"id": "ord_1", "status": "paid"}}, "pending": {"value": {"id": "ord_2", "status": "pending"}}}
+                        }}
+                    },
+                    "404": {"description": "Not found", "content": {"application/json": {"example": {"detail": "Order not found"}}}}
+                }
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "NewOrder": {"type": "object", "required": ["quantity"], "properties": {"quantity": {"type": "integer", "minimum": 1}}},
+            "Order": {"type": "object", "properties": {"id": {"type": "string"}, "status": {"type": "string", "enum": ["pending", "paid"]}}}
+        }
+    }
+}
+"##;
+
+async fn start(options: MockOptions) -> String {
+    let openapi = serde_json::from_str(SPEC).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(serve(listener, Arc::new(MockServer::new(openapi, options))));
+    format!("http://{}", address)
+}
+
+#[tokio::test]
+async fn test_mock_server_serves_examples_and_preferences() {
+    let base = start(MockOptions::default()).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/v1/orders/ord_1", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body, serde_json::json!({"id": "ord_1", "status": "paid"}));
+
+    let response = client
+        .get(format!("{}/orders/ord_2", base))
+        .header("Prefer", "example=pending")
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "pending");
+
+    let response = client
+        .get(format!("{}/orders/ord_3", base))
+        .header("Prefer", "code=404")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["detail"], "Order not found");
+}
+
+#[tokio::test]
+async fn test_mock_server_validates_requests() {
+    let base = start(MockOptions {
+        validate: true,
+        ..Default::default()
+    })
+    .await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&serde_json::json!({"quantity": 0}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        body["details"],
+        serde_json::json!(["body.quantity: must be >= 1"])
+    );
+
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&serde_json::json!({"quantity": 2}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body, serde_json::json!({"id": "id", "status": "pending"}));
+}
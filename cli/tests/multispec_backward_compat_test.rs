@@ -12,6 +12,8 @@ fn test_single_spec_config_validation_still_works() {
             schemas: vika_cli::config::model::SchemasConfig::default(),
             apis: vika_cli::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig::default(),
         }],
         ..Default::default()
@@ -34,6 +36,8 @@ fn test_single_spec_config_serialization() {
             schemas: vika_cli::config::model::SchemasConfig::default(),
             apis: vika_cli::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig::default(),
         }],
         ..Config::default()
@@ -116,6 +120,8 @@ fn test_config_with_single_spec() {
             schemas: vika_cli::config::model::SchemasConfig::default(),
             apis: vika_cli::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig::default(),
         }],
         ..Default::default()
@@ -135,6 +141,8 @@ fn test_config_with_multiple_specs() {
             schemas: vika_cli::config::model::SchemasConfig::default(),
             apis: vika_cli::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig::default(),
         },
         SpecEntry {
@@ -143,6 +151,8 @@ fn test_config_with_multiple_specs() {
             schemas: vika_cli::config::model::SchemasConfig::default(),
             apis: vika_cli::config::model::ApisConfig::default(),
             hooks: None,
+            mocks: None,
+            factories: None,
             modules: vika_cli::config::model::ModulesConfig::default(),
         },
     ];
@@ -14,6 +14,8 @@ fn default_spec_entry(name: &str, path: &str) -> SpecEntry {
         schemas: SchemasConfig::default(),
         apis: ApisConfig::default(),
         hooks: None,
+        mocks: None,
+        factories: None,
         modules: ModulesConfig::default(),
     }
 }
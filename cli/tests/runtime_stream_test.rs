@@ -0,0 +1,77 @@
+mod common;
+
+use common::run_runtime_script;
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_event_stream_reconnects_after_server_closes_stream() {
+    let script = r#"
+import { VikaClient } from "./runtime/http-client.ts";
+
+const bodies = ['id: 1\ndata: {"n":1}\n\n', 'id: 2\ndata: {"n":2}\n\n'];
+const lastEventIds: (string | null)[] = [];
+globalThis.fetch = async (_url: any, init: any) => {
+  lastEventIds.push(init.headers["Last-Event-ID"] ?? null);
+  const body = bodies.shift();
+  if (body === undefined) {
+    return new Response(null, { status: 204 });
+  }
+  return new Response(body, { status: 200, headers: { "Content-Type": "text/event-stream" } });
+};
+
+const client = new VikaClient({ baseUrl: "http://localhost" });
+const events: unknown[] = [];
+for await (const event of client.eventStream("GET", "/events", { reconnectDelay: 0 })) {
+  events.push(event.data);
+}
+console.log(JSON.stringify({ lastEventIds, events }));
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"lastEventIds":[null,"1","2"],"events":[{"n":1},{"n":2}]}"#
+    );
+}
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_event_stream_stops_reconnecting_without_events() {
+    let script = r#"
+import { VikaClient } from "./runtime/http-client.ts";
+
+let requests = 0;
+globalThis.fetch = async () => {
+  requests++;
+  return new Response("", { status: 200, headers: { "Content-Type": "text/event-stream" } });
+};
+
+const client = new VikaClient({ baseUrl: "http://localhost" });
+for await (const _event of client.eventStream("GET", "/events", { reconnectDelay: 0, maxReconnects: 2 })) {
+}
+console.log(requests);
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(output, "3");
+}
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_failed_stream_throws_stream_error() {
+    let script = r#"
+import { failedStream, StreamError } from "./runtime/http-client.ts";
+
+const validation = { ok: false, kind: "validation", status: 0, errors: [] } as any;
+try {
+  for await (const _event of failedStream(validation)) {
+  }
+  console.log("no error");
+} catch (error) {
+  console.log(error instanceof StreamError && error.result === validation);
+}
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(output, "true");
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::run_runtime_script;
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_concurrent_401s_share_one_refresh_and_replay() {
+    let script = r#"
+import { VikaClient } from "./runtime/http-client.ts";
+
+let token = "expired";
+let refreshes = 0;
+const authorizations: string[] = [];
+globalThis.fetch = async (_url: any, init: any) => {
+  authorizations.push(init.headers["Authorization"]);
+  const status = init.headers["Authorization"] === "Bearer fresh" ? 200 : 401;
+  return new Response(JSON.stringify({ status }), {
+    status,
+    headers: { "Content-Type": "application/json" },
+  });
+};
+
+const client = new VikaClient({ baseUrl: "http://localhost" });
+client.useBeforeRequest((ctx) => {
+  ctx.headers["Authorization"] = `Bearer ${token}`;
+});
+client.useTokenRefresh({
+  refresh: async () => {
+    refreshes++;
+    await new Promise((resolve) => setTimeout(resolve, 20));
+    token = "fresh";
+  },
+});
+
+const first = client.request("GET", "/a");
+const second = client.request("GET", "/b");
+// Started while the refresh runs: waits for it instead of being rejected
+await new Promise((resolve) => setTimeout(resolve, 5));
+const third = client.request("GET", "/c");
+const results = await Promise.all([first, second, third]);
+console.log(JSON.stringify({ refreshes, statuses: results.map((result) => result.status), authorizations }));
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"refreshes":1,"statuses":[200,200,200],"authorizations":["Bearer expired","Bearer expired","Bearer fresh","Bearer fresh","Bearer fresh"]}"#
+    );
+}
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_failed_refresh_returns_original_401() {
+    let script = r#"
+import { VikaClient } from "./runtime/http-client.ts";
+
+let requests = 0;
+let refreshes = 0;
+const failures: string[] = [];
+globalThis.fetch = async () => {
+  requests++;
+  return new Response(JSON.stringify({ message: "expired" }), {
+    status: 401,
+    headers: { "Content-Type": "application/json" },
+  });
+};
+
+const client = new VikaClient({ baseUrl: "http://localhost" });
+client.useTokenRefresh({
+  refresh: async () => {
+    refreshes++;
+    await new Promise((resolve) => setTimeout(resolve, 20));
+    throw new Error("session revoked");
+  },
+  onRefreshFailed: (error: any, ctx) => {
+    failures.push(`${ctx.url}: ${error.message}`);
+  },
+});
+
+const results = await Promise.all([client.request("GET", "/a"), client.request("GET", "/b")]);
+console.log(JSON.stringify({ requests, refreshes, failures, results }));
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"requests":2,"refreshes":1,"failures":["http://localhost/a: session revoked"],"results":[{"ok":false,"kind":"http","status":401,"error":{"message":"expired"}},{"ok":false,"kind":"http","status":401,"error":{"message":"expired"}}]}"#
+    );
+}
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_replay_rejected_again_is_not_refreshed_twice() {
+    let script = r#"
+import { VikaClient } from "./runtime/http-client.ts";
+
+let requests = 0;
+let refreshes = 0;
+const failures: string[] = [];
+globalThis.fetch = async () => {
+  requests++;
+  return new Response("", { status: 401 });
+};
+
+const client = new VikaClient({ baseUrl: "http://localhost" });
+client.useTokenRefresh({
+  refresh: async () => {
+    refreshes++;
+  },
+  onRefreshFailed: (error: any) => {
+    failures.push(error.message);
+  },
+});
+
+const result = await client.request("GET", "/a");
+console.log(JSON.stringify({ requests, refreshes, failures, status: result.status }));
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"requests":2,"refreshes":1,"failures":["Request still rejected with 401 after refreshing credentials"],"status":401}"#
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::run_runtime_script;
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_request_span_wraps_fetch_and_sends_traceparent() {
+    let script = r#"
+import { VikaClient, createTracer } from "./runtime/http-client.ts";
+
+const log: string[] = [];
+const spans: any[] = [];
+let traceparent: string | undefined;
+globalThis.fetch = async (_url: any, init: any) => {
+  log.push("fetch");
+  traceparent = init.headers["traceparent"];
+  return new Response(JSON.stringify({ id: 1 }), {
+    status: 200,
+    headers: { "Content-Type": "application/json" },
+  });
+};
+
+const tracer = createTracer({
+  onEnd: (span) => {
+    log.push("end");
+    spans.push(span);
+  },
+});
+const client = new VikaClient({ baseUrl: "http://localhost" });
+client.useTracer({
+  startSpan: (name, options) => {
+    log.push(`start ${name}`);
+    return tracer.startSpan(name, options);
+  },
+});
+
+await client.request("GET", "/users/1", {
+  operation: { operationId: "getUser", module: "users", path: "/users/{id}", tags: [] },
+});
+const [span] = spans;
+console.log(
+  JSON.stringify({
+    log,
+    traceparent: traceparent === `00-${span.traceId}-${span.spanId}-01`,
+    format: /^00-[0-9a-f]{32}-[0-9a-f]{16}-01$/.test(traceparent ?? ""),
+    attributes: span.attributes,
+    status: span.status,
+    ended: span.endTime >= span.startTime,
+  })
+);
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"log":["start getUser","fetch","end"],"traceparent":true,"format":true,"attributes":{"http.request.method":"GET","vika.operation.id":"getUser","vika.operation.module":"users","url.template":"/users/{id}","url.full":"http://localhost/users/1","http.response.status_code":200},"status":{"code":0},"ended":true}"#
+    );
+}
+
+#[test]
+#[ignore = "requires Node >= 22.6"]
+fn test_request_span_records_failures() {
+    let script = r#"
+import { VikaClient, createTracer } from "./runtime/http-client.ts";
+
+const spans: any[] = [];
+let calls = 0;
+globalThis.fetch = async () => {
+  calls++;
+  if (calls === 1) {
+    return new Response("boom", { status: 500 });
+  }
+  throw new TypeError("fetch failed");
+};
+
+const client = new VikaClient({
+  baseUrl: "http://localhost",
+  tracer: createTracer({ onEnd: (span) => spans.push(span) }),
+});
+
+const result = await client.request("GET", "/orders");
+let thrown: string | undefined;
+try {
+  await client.request("GET", "/orders");
+} catch (error: any) {
+  thrown = error.message;
+}
+console.log(
+  JSON.stringify({
+    result: result.status,
+    thrown,
+    names: spans.map((span) => span.name),
+    statuses: spans.map((span) => span.status),
+    exception: spans[1]?.exception?.message,
+  })
+);
+"#;
+    let output = run_runtime_script(script);
+
+    assert_eq!(
+        output,
+        r#"{"result":500,"thrown":"fetch failed","names":["HTTP GET","HTTP GET"],"statuses":[{"code":2,"message":"HTTP 500"},{"code":2,"message":"fetch failed"}],"exception":"fetch failed"}"#
+    );
+}
@@ -448,3 +448,54 @@ components:
     let result = resolver.build_dependency_graph();
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_closes_cycle() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_yaml = r##"
+openapi: 3.0.0
+info:
+  title: Test
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Category:
+      type: object
+      properties:
+        parent:
+          $ref: "#/components/schemas/Category"
+        owner:
+          $ref: "#/components/schemas/User"
+    User:
+      type: object
+      properties:
+        team:
+          $ref: "#/components/schemas/Team"
+    Team:
+      type: object
+      properties:
+        members:
+          type: array
+          items:
+            $ref: "#/components/schemas/User"
+"##;
+
+    let spec_path = temp_dir.path().join("spec.yaml");
+    fs::write(&spec_path, spec_yaml).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let mut resolver = SchemaResolver::new(parsed.openapi);
+    resolver.build_dependency_graph().unwrap();
+
+    assert!(resolver
+        .dependencies("Category")
+        .contains(&"Team".to_string()));
+    assert!(resolver.closes_cycle("Category", "Category"));
+    assert!(!resolver.closes_cycle("Category", "User"));
+    assert!(resolver.closes_cycle("User", "Team"));
+    assert!(resolver.closes_cycle("Team", "User"));
+}
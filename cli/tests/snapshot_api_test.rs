@@ -1,8 +1,17 @@
 use insta::assert_snapshot;
 use std::fs;
 use tempfile::TempDir;
-use vika_cli::generator::api_client::generate_api_client;
+use vika_cli::config::model::{ApisConfig, PaginationConfig, PaginationRule};
+use vika_cli::generator::api_client::{generate_api_client, generate_api_client_with_config};
+use vika_cli::generator::hooks::react_query::generate_react_query_hooks;
+use vika_cli::generator::msw::generate_msw_handlers;
+use vika_cli::generator::query_keys::generate_query_keys;
+use vika_cli::generator::query_params::{generate_query_params_for_module, QueryParamsContext};
+use vika_cli::generator::security::generate_security_module;
 use vika_cli::generator::swagger_parser::fetch_and_parse_spec;
+use vika_cli::generator::writer::write_api_client_with_options;
+use vika_cli::templates::engine::TemplateEngine;
+use vika_cli::templates::registry::TemplateId;
 
 #[tokio::test]
 async fn test_all_http_methods() {
@@ -400,3 +409,1663 @@ async fn test_error_responses() {
     let output = output_parts.join("\n\n");
     assert_snapshot!("error_responses", output);
 }
+
+#[tokio::test]
+async fn test_response_validation_schemas() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "test"}],
+        "components": {
+            "schemas": {
+                "Product": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"}
+                    }
+                },
+                "Error": {
+                    "type": "object",
+                    "properties": {
+                        "message": {"type": "string"}
+                    }
+                }
+            }
+        },
+        "paths": {
+            "/products": {
+                "post": {
+                    "tags": ["test"],
+                    "operationId": "createProduct",
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": {"$ref": "#/components/schemas/Product"}
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "OK",
+                            "content": {
+                                "application/json": {
+                                    "schema": {"$ref": "#/components/schemas/Product"}
+                                }
+                            }
+                        },
+                        "400": {
+                            "description": "Bad Request",
+                            "content": {
+                                "application/json": {
+                                    "schema": {"$ref": "#/components/schemas/Error"}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("test").unwrap();
+    let apis_config = ApisConfig {
+        validate_responses: "dev-only".to_string(),
+        ..ApisConfig::default()
+    };
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "test",
+        &["Error".to_string()],
+        &mut std::collections::HashMap::new(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        &apis_config,
+    )
+    .unwrap();
+
+    let output: String = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("response_validation_schemas", output);
+}
+
+#[tokio::test]
+async fn test_array_and_primitive_body_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "test"}],
+        "components": {
+            "schemas": {
+                "Product": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"}
+                    }
+                },
+                "ProductList": {
+                    "type": "array",
+                    "items": {"$ref": "#/components/schemas/Product"}
+                }
+            }
+        },
+        "paths": {
+            "/products": {
+                "get": {
+                    "tags": ["test"],
+                    "operationId": "listProducts",
+                    "responses": {
+                        "200": {
+                            "description": "OK",
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "array",
+                                        "items": {"$ref": "#/components/schemas/Product"}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                "put": {
+                    "tags": ["test"],
+                    "operationId": "replaceProducts",
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": {"$ref": "#/components/schemas/ProductList"}
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "OK",
+                            "content": {
+                                "application/json": {
+                                    "schema": {"$ref": "#/components/schemas/ProductList"}
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "/products/count": {
+                "get": {
+                    "tags": ["test"],
+                    "operationId": "countProducts",
+                    "responses": {
+                        "200": {
+                            "description": "OK",
+                            "content": {
+                                "application/json": {
+                                    "schema": {"type": "integer", "nullable": true}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("test").unwrap();
+    let apis_config = ApisConfig {
+        validate_requests: "always".to_string(),
+        validate_responses: "always".to_string(),
+        ..ApisConfig::default()
+    };
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "test",
+        &[],
+        &mut std::collections::HashMap::new(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        &apis_config,
+    )
+    .unwrap();
+
+    let output: String = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("array_and_primitive_body_validation", output);
+}
+
+#[tokio::test]
+async fn test_operation_metadata_is_escaped() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "paths": {
+            "/reports/{id}": {
+                "get": {
+                    "tags": ["Reports \"beta\""],
+                    "operationId": "getReport",
+                    "parameters": [
+                        {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}
+                    ],
+                    "responses": {"204": {"description": "No Content"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.values().next().unwrap();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "reports",
+        &[],
+        &mut std::collections::HashMap::new(),
+        None,
+        Some("back\\office"),
+        None,
+        None,
+        None,
+        &ApisConfig::default(),
+    )
+    .unwrap();
+
+    let output = &api_result.functions[0].content;
+    assert!(output.contains(r#"tags: ["Reports \"beta\""],"#));
+    assert!(output.contains(r#"spec: "back\\office","#));
+    assert!(output.contains(r#"path: "/reports/{id}","#));
+}
+
+#[tokio::test]
+async fn test_request_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "test"}],
+        "components": {
+            "schemas": {
+                "Product": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string"}
+                    }
+                }
+            }
+        },
+        "paths": {
+            "/stores/{storeId}/products/{kind}": {
+                "put": {
+                    "tags": ["test"],
+                    "operationId": "updateProduct",
+                    "parameters": [
+                        {"name": "storeId", "in": "path", "required": true, "schema": {"type": "integer"}},
+                        {"name": "kind", "in": "path", "required": true, "schema": {"type": "string", "enum": ["digital", "physical"]}},
+                        {"name": "notify", "in": "query", "schema": {"type": "boolean"}}
+                    ],
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": {"$ref": "#/components/schemas/Product"}
+                            }
+                        }
+                    },
+                    "responses": {"200": {"description": "OK"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("test").unwrap();
+    let apis_config = ApisConfig {
+        validate_requests: "always".to_string(),
+        ..ApisConfig::default()
+    };
+    let mut enum_registry = std::collections::HashMap::new();
+    let query_params_result = generate_query_params_for_module(QueryParamsContext {
+        openapi: &parsed.openapi,
+        operations,
+        enum_registry: &mut enum_registry,
+        template_engine: None,
+        spec_name: None,
+        existing_types: &[],
+        existing_zod_schemas: &[],
+        include_path_params: true,
+    })
+    .unwrap();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "test",
+        &[],
+        &mut enum_registry,
+        None,
+        None,
+        None,
+        None,
+        None,
+        &apis_config,
+    )
+    .unwrap();
+
+    let mut output_parts = Vec::new();
+    for schema_type in &query_params_result.types {
+        output_parts.push(schema_type.content.clone());
+    }
+    for zod_schema in &query_params_result.zod_schemas {
+        output_parts.push(zod_schema.content.clone());
+    }
+    for func in &api_result.functions {
+        output_parts.push(func.content.clone());
+    }
+    let output = output_parts.join("\n\n");
+    assert_snapshot!("request_validation", output);
+
+    let axios_config = ApisConfig {
+        style: "axios".to_string(),
+        ..apis_config
+    };
+    let axios_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "test",
+        &[],
+        &mut enum_registry,
+        None,
+        None,
+        None,
+        None,
+        None,
+        &axios_config,
+    )
+    .unwrap();
+    let axios_output = axios_result.functions[0].content.clone();
+    assert!(axios_output.contains("const validation = axiosClient.validateRequest<"));
+    assert!(!axios_output.contains("options?.validateRequests"));
+}
+
+#[tokio::test]
+async fn test_operation_security() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "test"}],
+        "security": [{"bearerAuth": []}],
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": {"type": "http", "scheme": "bearer", "bearerFormat": "JWT"},
+                "basicAuth": {"type": "http", "scheme": "basic"},
+                "apiKey": {"type": "apiKey", "in": "header", "name": "X-API-Key"}
+            }
+        },
+        "paths": {
+            "/health": {
+                "get": {"tags": ["test"], "operationId": "getHealth", "security": [], "responses": {"200": {"description": "OK"}}}
+            },
+            "/reports": {
+                "get": {"tags": ["test"], "operationId": "listReports", "responses": {"200": {"description": "OK"}}},
+                "post": {
+                    "tags": ["test"],
+                    "operationId": "createReport",
+                    "security": [{"apiKey": []}, {"basicAuth": []}],
+                    "responses": {"201": {"description": "Created"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("test").unwrap();
+    let api_result = generate_api_client(&parsed.openapi, operations, "test", &[]).unwrap();
+
+    let mut output_parts = vec![generate_security_module(&parsed.openapi, "../runtime").unwrap()];
+    for func in &api_result.functions {
+        output_parts.push(func.content.clone());
+    }
+    let output = output_parts.join("\n\n");
+    assert_snapshot!("operation_security", output);
+}
+
+#[tokio::test]
+async fn test_axios_style() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "files"}],
+        "components": {
+            "schemas": {
+                "Upload": {
+                    "type": "object",
+                    "properties": {"name": {"type": "string"}}
+                }
+            }
+        },
+        "paths": {
+            "/files/{id}": {
+                "get": {
+                    "tags": ["files"],
+                    "operationId": "getFile",
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                    "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Upload"}}}}}
+                }
+            },
+            "/files": {
+                "post": {
+                    "tags": ["files"],
+                    "operationId": "uploadFile",
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Upload"}}}},
+                    "responses": {"201": {"description": "Created"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("files").unwrap();
+    let apis_config = ApisConfig {
+        style: "axios".to_string(),
+        ..ApisConfig::default()
+    };
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "files",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        &apis_config,
+    )
+    .unwrap();
+
+    let output = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("axios_style", output);
+}
+
+#[tokio::test]
+async fn test_angular_style() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "users"}],
+        "components": {
+            "schemas": {
+                "User": {
+                    "type": "object",
+                    "properties": {"id": {"type": "string"}, "name": {"type": "string"}}
+                }
+            }
+        },
+        "paths": {
+            "/users/{id}": {
+                "get": {
+                    "tags": ["users"],
+                    "operationId": "getUser",
+                    "parameters": [
+                        {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}},
+                        {"name": "expand", "in": "query", "required": false, "schema": {"type": "array", "items": {"type": "string"}}},
+                        {"name": "X-Request-Id", "in": "header", "required": false, "schema": {"type": "string"}}
+                    ],
+                    "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+                },
+                "delete": {
+                    "tags": ["users"],
+                    "operationId": "deleteUser",
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                    "responses": {"204": {"description": "Deleted"}}
+                }
+            },
+            "/users": {
+                "post": {
+                    "tags": ["users"],
+                    "operationId": "createUser",
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+                    "responses": {"201": {"description": "Created", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("users").unwrap();
+    let apis_config = ApisConfig {
+        style: "angular".to_string(),
+        ..ApisConfig::default()
+    };
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "users",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        &apis_config,
+    )
+    .unwrap();
+
+    let output = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("angular_style", output);
+}
+
+#[tokio::test]
+async fn test_sdk_client_factory() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "invoices"}],
+        "security": [{"bearerAuth": []}],
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": {"type": "http", "scheme": "bearer"}
+            },
+            "schemas": {
+                "Invoice": {
+                    "type": "object",
+                    "properties": {"id": {"type": "string"}, "total": {"type": "number"}}
+                }
+            }
+        },
+        "paths": {
+            "/invoices": {
+                "get": {
+                    "tags": ["invoices"],
+                    "operationId": "listInvoices",
+                    "summary": "List invoices",
+                    "parameters": [{"name": "page", "in": "query", "schema": {"type": "integer"}}],
+                    "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"type": "array", "items": {"$ref": "#/components/schemas/Invoice"}}}}}}
+                },
+                "post": {
+                    "tags": ["invoices"],
+                    "operationId": "createInvoice",
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/Invoice"}}}},
+                    "responses": {"201": {"description": "Created", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Invoice"}}}}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("invoices").unwrap();
+    let apis_config = ApisConfig {
+        client_class: Some("BillingClient".to_string()),
+        ..ApisConfig::default()
+    };
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "invoices",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        &apis_config,
+    )
+    .unwrap();
+
+    let output = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("sdk_client_factory", output);
+}
+
+const OBJECT_SIGNATURE_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "users"}],
+    "components": {
+        "schemas": {
+            "User": {
+                "type": "object",
+                "properties": {"id": {"type": "string"}, "name": {"type": "string"}}
+            }
+        }
+    },
+    "paths": {
+        "/orgs/{orgId}/users/{userId}": {
+            "get": {
+                "tags": ["users"],
+                "operationId": "getUser",
+                "parameters": [
+                    {"name": "orgId", "in": "path", "required": true, "schema": {"type": "string"}},
+                    {"name": "userId", "in": "path", "required": true, "schema": {"type": "integer"}},
+                    {"name": "expand", "in": "query", "schema": {"type": "string"}},
+                    {"name": "X-Request-Id", "in": "header", "schema": {"type": "string"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+            },
+            "put": {
+                "tags": ["users"],
+                "operationId": "updateUser",
+                "parameters": [
+                    {"name": "orgId", "in": "path", "required": true, "schema": {"type": "string"}},
+                    {"name": "userId", "in": "path", "required": true, "schema": {"type": "integer"}}
+                ],
+                "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+            }
+        },
+        "/users": {
+            "get": {
+                "tags": ["users"],
+                "operationId": "listUsers",
+                "parameters": [{"name": "page", "in": "query", "schema": {"type": "integer"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_object_signature() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, OBJECT_SIGNATURE_SPEC).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("users").unwrap();
+    let apis_config = ApisConfig {
+        signature: "object".to_string(),
+        ..ApisConfig::default()
+    };
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "users",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        &apis_config,
+    )
+    .unwrap();
+
+    let output = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("object_signature", output);
+}
+
+#[tokio::test]
+async fn test_object_signature_hooks() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, OBJECT_SIGNATURE_SPEC).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("users").unwrap();
+    let apis_config = ApisConfig {
+        signature: "object".to_string(),
+        ..ApisConfig::default()
+    };
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+
+    let query_keys = generate_query_keys(
+        operations,
+        "users",
+        None,
+        Some("src/query-keys"),
+        &apis_config,
+    );
+    let mut output = vec![template_engine
+        .render(TemplateId::QueryKeys, &query_keys)
+        .unwrap()];
+
+    let hooks = generate_react_query_hooks(
+        &parsed.openapi,
+        operations,
+        "users",
+        None,
+        &[],
+        &mut enum_registry,
+        &template_engine,
+        Some("src/apis"),
+        Some("src/schemas"),
+        Some("src/hooks"),
+        Some("src/query-keys"),
+        &apis_config,
+    )
+    .unwrap();
+    output.extend(
+        hooks
+            .iter()
+            .map(|hook| format!("// {}\n{}", hook.filename, hook.content)),
+    );
+
+    assert_snapshot!("object_signature_hooks", output.join("\n\n"));
+}
+
+#[tokio::test]
+async fn test_server_overrides() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "servers": [{"url": "https://api.example.com"}],
+        "tags": [{"name": "files"}],
+        "paths": {
+            "/files": {
+                "servers": [{"url": "https://files.example.com"}],
+                "get": {
+                    "tags": ["files"],
+                    "operationId": "listFiles",
+                    "responses": {"200": {"description": "OK"}}
+                },
+                "post": {
+                    "tags": ["files"],
+                    "operationId": "uploadFile",
+                    "servers": [
+                        {"url": "https://{region}.upload.example.com", "variables": {"region": {"enum": ["eu", "us"], "default": "eu"}}}
+                    ],
+                    "responses": {"201": {"description": "Created"}}
+                }
+            },
+            "/files/{id}": {
+                "delete": {
+                    "tags": ["files"],
+                    "operationId": "deleteFile",
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                    "responses": {"204": {"description": "Deleted"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("files").unwrap();
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut output = Vec::new();
+    for style in ["fetch", "axios"] {
+        let apis_config = ApisConfig {
+            style: style.to_string(),
+            ..ApisConfig::default()
+        };
+        let mut enum_registry = std::collections::HashMap::new();
+        let api_result = generate_api_client_with_config(
+            &parsed.openapi,
+            operations,
+            "files",
+            &[],
+            &mut enum_registry,
+            Some(&template_engine),
+            None,
+            Some("src"),
+            Some("src/apis"),
+            Some("src/schemas"),
+            &apis_config,
+        )
+        .unwrap();
+        output.extend(api_result.functions.iter().map(|f| f.content.clone()));
+    }
+
+    assert_snapshot!("server_overrides", output.join("\n\n"));
+}
+
+#[tokio::test]
+async fn test_idempotent_operation() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "tags": [{"name": "payments"}],
+        "paths": {
+            "/payments": {
+                "post": {
+                    "tags": ["payments"],
+                    "operationId": "createPayment",
+                    "x-idempotent": true,
+                    "requestBody": {"content": {"application/json": {"schema": {"type": "object", "properties": {"amount": {"type": "number"}}}}}},
+                    "responses": {"201": {"description": "Created"}}
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+
+    let operations = parsed.operations_by_tag.get("payments").unwrap();
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "payments",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        &ApisConfig::default(),
+    )
+    .unwrap();
+
+    let output = api_result
+        .functions
+        .iter()
+        .map(|f| f.content.clone())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("idempotent_operation", output);
+}
+
+const PAGINATION_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listOrders",
+                "parameters": [
+                    {"name": "cursor", "in": "query", "schema": {"type": "string"}},
+                    {"name": "limit", "in": "query", "schema": {"type": "integer"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/OrderPage"}}}}}
+            }
+        },
+        "/customers": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listCustomers",
+                "parameters": [
+                    {"name": "page", "in": "query", "schema": {"type": "integer"}},
+                    {"name": "pageSize", "in": "query", "schema": {"type": "integer"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {
+                    "type": "object",
+                    "properties": {
+                        "data": {"type": "array", "items": {"type": "string"}},
+                        "meta": {"type": "object", "properties": {"total": {"type": "integer"}, "totalPages": {"type": "integer"}}}
+                    }
+                }}}}}
+            }
+        },
+        "/customers/{id}/invoices": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listInvoices",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}},
+                    {"name": "offset", "in": "query", "schema": {"type": "integer"}},
+                    {"name": "limit", "in": "query", "schema": {"type": "integer"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {
+                    "type": "object",
+                    "properties": {
+                        "results": {"type": "array", "items": {"type": "string"}},
+                        "total": {"type": "integer"}
+                    }
+                }}}}}
+            }
+        },
+        "/events": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listEvents",
+                "x-pagination": {"style": "cursor", "param": "since", "next": "links.next"},
+                "parameters": [
+                    {"name": "since", "in": "query", "schema": {"type": "string"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {
+                    "type": "object",
+                    "properties": {
+                        "events": {"type": "array", "items": {"type": "string"}},
+                        "links": {"type": "object", "properties": {"next": {"type": "string"}}}
+                    }
+                }}}}}
+            }
+        },
+        "/reports": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listReports",
+                "x-pagination": false,
+                "parameters": [
+                    {"name": "page", "in": "query", "schema": {"type": "integer"}}
+                ],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {
+                    "type": "object",
+                    "properties": {"items": {"type": "array", "items": {"type": "string"}}}
+                }}}}}
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "OrderPage": {
+                "type": "object",
+                "properties": {
+                    "items": {"type": "array", "items": {"type": "string"}},
+                    "nextCursor": {"type": "string", "nullable": true}
+                }
+            }
+        }
+    }
+}
+"##;
+
+/// Generate the `shop` module of `spec` and return the written module file.
+async fn generate_shop_module(spec: &str, apis_config: &ApisConfig) -> String {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let operations = parsed.operations_by_tag.get("shop").unwrap();
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let mut enum_registry = std::collections::HashMap::new();
+    let api_result = generate_api_client_with_config(
+        &parsed.openapi,
+        operations,
+        "shop",
+        &[],
+        &mut enum_registry,
+        Some(&template_engine),
+        None,
+        Some("src"),
+        Some("src/apis"),
+        Some("src/schemas"),
+        apis_config,
+    )
+    .unwrap();
+
+    let files = write_api_client_with_options(
+        temp_dir.path(),
+        "shop",
+        &api_result.functions,
+        None,
+        false,
+        true,
+    )
+    .unwrap();
+    fs::read_to_string(&files[0]).unwrap()
+}
+
+#[tokio::test]
+async fn test_pagination_helpers() {
+    let output = generate_shop_module(PAGINATION_SPEC, &ApisConfig::default()).await;
+
+    assert!(!output.contains("paginateListReports"));
+    assert_snapshot!("pagination_helpers", output);
+}
+
+#[tokio::test]
+async fn test_pagination_config_override() {
+    let apis_config = ApisConfig {
+        signature: "object".to_string(),
+        pagination: Some(PaginationConfig {
+            detect: Some(false),
+            operations: std::collections::HashMap::from([(
+                "listReports".to_string(),
+                PaginationRule {
+                    style: "page".to_string(),
+                    ..PaginationRule::default()
+                },
+            )]),
+        }),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(PAGINATION_SPEC, &apis_config).await;
+
+    // Detection is off, but x-pagination and config rules still apply
+    assert!(!output.contains("paginateListOrders"));
+    assert!(output.contains("export function paginateListEvents("));
+    assert_snapshot!("pagination_config_override", output);
+}
+
+const STREAMING_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "paths": {
+        "/orders/{id}/events": {
+            "get": {
+                "operationId": "streamOrderEvents",
+                "tags": ["shop"],
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {
+                    "200": {"description": "Order updates", "content": {"text/event-stream": {"schema": {"$ref": "#/components/schemas/OrderEvent"}}}},
+                    "404": {"description": "Not found", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                }
+            }
+        },
+        "/completions": {
+            "post": {
+                "operationId": "createCompletion",
+                "tags": ["shop"],
+                "requestBody": {"required": true, "content": {"application/json": {"schema": {"$ref": "#/components/schemas/CompletionRequest"}}}},
+                "responses": {
+                    "200": {
+                        "description": "Completion chunks",
+                        "content": {"application/x-ndjson": {"schema": {"type": "string"}, "x-event-schema": {"$ref": "#/components/schemas/CompletionChunk"}}}
+                    }
+                }
+            }
+        },
+        "/logs": {
+            "get": {
+                "operationId": "tailLogs",
+                "tags": ["shop"],
+                "responses": {"200": {"description": "Log lines", "content": {"text/event-stream": {"schema": {"type": "string"}}}}}
+            }
+        },
+        "/orders/{id}": {
+            "get": {
+                "operationId": "getOrder",
+                "tags": ["shop"],
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/OrderEvent"}}}}}
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "OrderEvent": {"type": "object", "properties": {"orderId": {"type": "string"}, "status": {"type": "string"}}},
+            "CompletionRequest": {"type": "object", "properties": {"prompt": {"type": "string"}}},
+            "CompletionChunk": {"type": "object", "properties": {"text": {"type": "string"}}},
+            "Problem": {"type": "object", "properties": {"detail": {"type": "string"}}}
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_streaming_functions() {
+    let output = generate_shop_module(STREAMING_SPEC, &ApisConfig::default()).await;
+
+    assert!(output.contains("export const getOrder = async (id: string, options?: CallOptions)"));
+    insta::assert_snapshot!("streaming_functions", output);
+}
+
+#[tokio::test]
+async fn test_streaming_functions_request_validation() {
+    let apis_config = ApisConfig {
+        validate_requests: "always".to_string(),
+        ..Default::default()
+    };
+    let output = generate_shop_module(STREAMING_SPEC, &apis_config).await;
+
+    // A generator is returned either way, so `for await` sees the validation failure
+    assert!(output.contains("if (validation) return failedStream(validation);"));
+    insta::assert_snapshot!("streaming_functions_request_validation", output);
+}
+
+#[tokio::test]
+async fn test_streaming_axios_style_unchanged() {
+    let apis_config = ApisConfig {
+        style: "axios".to_string(),
+        ..Default::default()
+    };
+    let output = generate_shop_module(STREAMING_SPEC, &apis_config).await;
+
+    assert!(!output.contains("eventStream"));
+    assert!(output.contains(
+        "export const streamOrderEvents = async (id: string, config?: AxiosRequestConfig)"
+    ));
+}
+
+const MOCKS_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "paths": {
+        "/orders": {
+            "get": {
+                "operationId": "listOrders",
+                "tags": ["shop"],
+                "responses": {"200": {"description": "OK", "content": {"application/json": {"schema": {"type": "array", "items": {"$ref": "#/components/schemas/Order"}}}}}}
+            }
+        },
+        "/orders/{order-id}": {
+            "get": {
+                "operationId": "getOrder",
+                "tags": ["shop"],
+                "parameters": [{"name": "order-id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "content": {"application/json": {
+                            "schema": {"$ref": "#/components/schemas/Order"},
+                            "examples": {"paid": {"value": {"id": "ord_1", "status": "paid", "total": 42.5}}}
+                        }}
+                    },
+                    "404": {"description": "Not found", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                }
+            },
+            "delete": {
+                "operationId": "deleteOrder",
+                "tags": ["shop"],
+                "parameters": [{"name": "order-id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"204": {"description": "Deleted"}}
+            }
+        },
+        "/orders/{order-id}/events": {
+            "get": {
+                "operationId": "streamOrderEvents",
+                "tags": ["shop"],
+                "parameters": [{"name": "order-id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "Order updates", "content": {"text/event-stream": {"schema": {"$ref": "#/components/schemas/OrderEvent"}}}}}
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "Order": {"type": "object", "properties": {
+                "id": {"type": "string", "format": "uuid"},
+                "status": {"type": "string", "enum": ["pending", "paid"]},
+                "total": {"type": "number"},
+                "createdAt": {"type": "string", "format": "date-time"}
+            }},
+            "OrderEvent": {"type": "object", "properties": {"orderId": {"type": "string"}, "status": {"type": "string"}}},
+            "Problem": {"type": "object", "properties": {"detail": {"type": "string"}}}
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_msw_handlers() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, MOCKS_SPEC).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let operations = parsed.operations_by_tag.get("shop").unwrap();
+    let output = generate_msw_handlers(
+        &parsed.openapi,
+        operations,
+        "shop",
+        "../../apis/shop",
+        &ApisConfig::default(),
+    )
+    .unwrap();
+
+    assert!(output.contains("http.get(\"*/orders/:order_id\""));
+    assert_snapshot!("msw_handlers", output);
+}
+
+const ERROR_MODE_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listOrders",
+                "parameters": [
+                    {"name": "cursor", "in": "query", "schema": {"type": "string"}}
+                ],
+                "responses": {
+                    "200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/OrderPage"}}}},
+                    "401": {"description": "Unauthorized", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                }
+            }
+        },
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}
+                ],
+                "responses": {
+                    "200": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+                    "404": {"description": "Not Found", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}},
+                    "500": {"description": "Server Error"}
+                }
+            },
+            "delete": {
+                "tags": ["shop"],
+                "operationId": "deleteOrder",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}
+                ],
+                "responses": {"204": {"description": "Deleted"}}
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "Order": {
+                "type": "object",
+                "properties": {"id": {"type": "integer"}}
+            },
+            "OrderPage": {
+                "type": "object",
+                "properties": {
+                    "items": {"type": "array", "items": {"$ref": "#/components/schemas/Order"}},
+                    "nextCursor": {"type": "string", "nullable": true}
+                }
+            },
+            "Problem": {
+                "type": "object",
+                "properties": {"message": {"type": "string"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_error_mode_throw() {
+    let apis_config = ApisConfig {
+        error_mode: "throw".to_string(),
+        validate_requests: "always".to_string(),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(ERROR_MODE_SPEC, &apis_config).await;
+
+    assert!(!output.contains("ApiResult<"));
+    assert_snapshot!("error_mode_throw", output);
+}
+
+const RESPONSE_RANGES_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders": {
+            "post": {
+                "tags": ["shop"],
+                "operationId": "createOrder",
+                "responses": {
+                    "201": {"description": "Created", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+                    "4XX": {"description": "Client Error", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}},
+                    "default": {"description": "Unexpected Error", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}}
+                }
+            }
+        },
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}
+                ],
+                "responses": {
+                    "2XX": {"description": "OK", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}},
+                    "404": {"description": "Not Found", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}},
+                    "5XX": {"description": "Server Error"}
+                }
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "Order": {
+                "type": "object",
+                "properties": {"id": {"type": "integer"}}
+            },
+            "Problem": {
+                "type": "object",
+                "properties": {"message": {"type": "string"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_response_ranges_and_default() {
+    let apis_config = ApisConfig {
+        validate_responses: "always".to_string(),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(RESPONSE_RANGES_SPEC, &apis_config).await;
+    assert_snapshot!("response_ranges_and_default", output);
+
+    let apis_config = ApisConfig {
+        error_mode: "throw".to_string(),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(RESPONSE_RANGES_SPEC, &apis_config).await;
+    assert!(output.contains("{ \"4XX\": CreateOrder4XXError, default: CreateOrderDefaultError }"));
+    assert!(output.contains(
+        "export class CreateOrderDefaultError extends ApiError<CreateOrderErrors[\"default\"]> {}"
+    ));
+    assert!(output
+        .contains("export class GetOrder5XXError extends ApiError<GetOrderErrors[\"5XX\"]> {}"));
+}
+
+const RESPONSE_HEADERS_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listOrders",
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "headers": {
+                            "X-Total-Count": {"description": "Total number of orders", "schema": {"type": "integer"}},
+                            "X-Has-More": {"schema": {"type": "boolean"}},
+                            "ETag": {"$ref": "#/components/headers/ETag"},
+                            "Content-Type": {"schema": {"type": "string"}}
+                        },
+                        "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}
+                    },
+                    "429": {
+                        "description": "Too Many Requests",
+                        "headers": {
+                            "Retry-After": {"required": true, "schema": {"type": "integer"}}
+                        }
+                    }
+                }
+            },
+            "post": {
+                "tags": ["shop"],
+                "operationId": "createOrder",
+                "responses": {
+                    "201": {
+                        "description": "Created",
+                        "headers": {
+                            "Location": {"required": true, "schema": {"type": "string"}},
+                            "X-Order-State": {"schema": {"type": "string", "enum": ["pending", "placed", "on \"hold\""]}}
+                        }
+                    }
+                }
+            }
+        }
+    },
+    "components": {
+        "headers": {
+            "ETag": {"description": "Version of the order list", "schema": {"type": "string"}}
+        },
+        "schemas": {
+            "Order": {
+                "type": "object",
+                "properties": {"id": {"type": "integer"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_response_headers() {
+    let output = generate_shop_module(RESPONSE_HEADERS_SPEC, &ApisConfig::default()).await;
+
+    assert!(!output.contains("Content-Type"));
+    assert!(output.contains(r#""pending" | "placed" | "on \"hold\"""#));
+    assert_snapshot!("response_headers", output);
+}
+
+const DEPRECATED_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "summary": "Get an order",
+                "deprecated": true,
+                "x-deprecated-message": "Use getOrderV2 instead.",
+                "x-sunset": "2026-12-31",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}},
+                    {"name": "expand", "in": "query", "deprecated": true, "schema": {"type": "boolean"}}
+                ],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/v2/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrderV2",
+                "parameters": [
+                    {"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}
+                ],
+                "responses": {"200": {"description": "OK"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_deprecated_operations() {
+    let output = generate_shop_module(DEPRECATED_SPEC, &ApisConfig::default()).await;
+
+    assert_eq!(output.matches("@deprecated").count(), 1);
+    assert_snapshot!("deprecated_operations", output);
+}
+
+#[tokio::test]
+async fn test_deprecated_operation_message_stays_in_comment() {
+    let spec = DEPRECATED_SPEC.replace(
+        "Use getOrderV2 instead.",
+        "Use v2 */ now.\\nSee the migration guide.",
+    );
+    let apis_config = ApisConfig {
+        client_class: Some("ShopClient".to_string()),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(&spec, &apis_config).await;
+
+    // Both the module function and its create{Module}Api method carry the tag
+    assert_eq!(output.matches("See the migration guide.").count(), 2);
+    assert!(output.contains(
+        " * @deprecated Use v2 *\\/ now.\n * See the migration guide. Sunset: 2026-12-31."
+    ));
+    assert!(!output.contains("v2 */"));
+}
+
+const NAME_COLLISIONS_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "get_order",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/v2/orders/{id}": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "getOrder",
+                "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "string"}}],
+                "responses": {"200": {"description": "OK"}}
+            }
+        },
+        "/orders": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listOrders",
+                "responses": {
+                    "200": {
+                        "description": "OK",
+                        "content": {"application/json": {"schema": {"$ref": "#/components/schemas/ListOrdersResponses"}}}
+                    }
+                }
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "ListOrdersResponses": {
+                "type": "object",
+                "properties": {"total": {"type": "integer"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_name_collisions() {
+    let output = generate_shop_module(NAME_COLLISIONS_SPEC, &ApisConfig::default()).await;
+
+    assert!(output.contains("export const getOrder = "));
+    assert!(output.contains("export const getOrder2 = "));
+    assert!(output.contains("export type ListOrders2Responses = "));
+    assert_snapshot!("name_collisions", output);
+}
+
+const NON_IDENTIFIER_PARAMS_SPEC: &str = r##"
+{
+    "openapi": "3.0.0",
+    "info": {"title": "Test", "version": "1.0.0"},
+    "tags": [{"name": "shop"}],
+    "paths": {
+        "/stores/{store-id}/orders": {
+            "get": {
+                "tags": ["shop"],
+                "operationId": "listStoreOrders",
+                "parameters": [
+                    {"name": "store-id", "in": "path", "required": true, "schema": {"type": "string"}},
+                    {"name": "page[size]", "in": "query", "schema": {"type": "integer"}},
+                    {"name": "filter.status", "in": "query", "schema": {"type": "array", "items": {"type": "string"}}}
+                ],
+                "responses": {"200": {"description": "OK"}}
+            }
+        }
+    }
+}
+"##;
+
+#[tokio::test]
+async fn test_non_identifier_parameter_names() {
+    let output = generate_shop_module(NON_IDENTIFIER_PARAMS_SPEC, &ApisConfig::default()).await;
+
+    assert!(output.contains("(storeId: string, query?: "));
+    assert!(output.contains("`/stores/${storeId}/orders`"));
+    assert!(output.contains("String(query[\"page[size]\"])"));
+    assert!(!output.contains("query?.page[size]"));
+    assert_snapshot!("non_identifier_parameter_names", output);
+
+    let apis_config = ApisConfig {
+        signature: "object".to_string(),
+        ..ApisConfig::default()
+    };
+    let output = generate_shop_module(NON_IDENTIFIER_PARAMS_SPEC, &apis_config).await;
+    assert!(output.contains("const { \"store-id\": storeId } = path;"));
+}
@@ -12,6 +12,7 @@ use vika_cli::generator::zod_schema::{
     generate_zod_schemas, generate_zod_schemas_with_registry_and_engine,
 };
 use vika_cli::templates::engine::TemplateEngine;
+use vika_cli::templates::registry::TemplateId;
 
 /// Test that template-based TypeScript enum generation matches current output
 #[tokio::test]
@@ -438,3 +439,13 @@ async fn test_template_api_client_matches_current() {
     assert_eq!(current_normalized, template_normalized);
     assert_snapshot!("template_api_client", template_output);
 }
+
+/// Snapshot of the axios runtime client rendered with config options
+#[test]
+fn test_template_runtime_axios_client() {
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let output = template_engine
+        .render(TemplateId::RuntimeAxiosClient, &serde_json::json!({}))
+        .unwrap();
+    assert_snapshot!("template_runtime_axios_client", output);
+}
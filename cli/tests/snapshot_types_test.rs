@@ -1,8 +1,15 @@
 use insta::assert_snapshot;
+use std::collections::HashMap;
 use std::fs;
 use tempfile::TempDir;
+use vika_cli::generator::factories::{generate_factories, FactoriesContext};
+use vika_cli::generator::schema_resolver::SchemaResolver;
 use vika_cli::generator::swagger_parser::fetch_and_parse_spec;
-use vika_cli::generator::ts_typings::generate_typings;
+use vika_cli::generator::ts_typings::{
+    generate_typings, generate_typings_with_registry_and_engine_and_spec,
+};
+use vika_cli::generator::zod_schema::generate_zod_schemas_with_registry_and_engine_and_spec;
+use vika_cli::templates::engine::TemplateEngine;
 
 #[tokio::test]
 async fn test_complex_nested_objects() {
@@ -276,3 +283,152 @@ async fn test_allof_schemas() {
         .join("\n\n");
     assert_snapshot!("allof_schemas", output);
 }
+
+#[tokio::test]
+async fn test_factories() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "paths": {},
+        "components": {
+            "schemas": {
+                "Category": {
+                    "type": "object",
+                    "required": ["id", "name", "children"],
+                    "properties": {
+                        "id": {"type": "string", "format": "uuid"},
+                        "name": {"type": "string", "minLength": 3, "maxLength": 40},
+                        "parent": {"$ref": "#/components/schemas/Category"},
+                        "children": {"type": "array", "items": {"$ref": "#/components/schemas/Category"}}
+                    }
+                },
+                "Product": {
+                    "type": "object",
+                    "required": ["sku", "price", "status", "category"],
+                    "properties": {
+                        "sku": {"type": "string", "example": "SKU-001"},
+                        "price": {"type": "number", "minimum": 0.5, "maximum": 99.99},
+                        "quantity": {"type": "integer", "minimum": 1, "maximum": 10, "default": 1},
+                        "status": {"$ref": "#/components/schemas/ProductStatus"},
+                        "category": {"$ref": "#/components/schemas/Category"},
+                        "tags": {"type": "array", "maxItems": 2, "items": {"type": "string"}},
+                        "dimensions": {
+                            "type": "object",
+                            "properties": {
+                                "width": {"type": "integer", "exclusiveMinimum": true, "minimum": 0},
+                                "unit": {"type": "string", "enum": ["cm", "in"]}
+                            }
+                        },
+                        "created-at": {"type": "string", "format": "date-time"},
+                        "featured": {"type": "boolean"}
+                    }
+                },
+                "ProductStatus": {"type": "string", "enum": ["draft", "active", "archived"]}
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let mut resolver = SchemaResolver::new(parsed.openapi.clone());
+    resolver.build_dependency_graph().unwrap();
+
+    let schema_names = vec![
+        "Category".to_string(),
+        "Product".to_string(),
+        "ProductStatus".to_string(),
+    ];
+    let module_schemas = HashMap::from([("catalog".to_string(), schema_names.clone())]);
+    let output = generate_factories(&FactoriesContext {
+        openapi: &parsed.openapi,
+        resolver: &resolver,
+        module_name: "catalog",
+        schema_names: &schema_names,
+        common_schemas: &[],
+        module_schemas: &module_schemas,
+        schemas_import: "../schemas/catalog",
+    });
+
+    // Optional self-references are left out, required ones end with an empty array
+    assert!(!output.contains("parent:"));
+    assert!(output.contains("children: [],"));
+    assert_snapshot!("factories", output);
+}
+
+#[tokio::test]
+async fn test_deprecated_schemas() {
+    let temp_dir = TempDir::new().unwrap();
+    let spec_json = r##"
+    {
+        "openapi": "3.0.0",
+        "info": {"title": "Test", "version": "1.0.0"},
+        "paths": {},
+        "components": {
+            "schemas": {
+                "LegacyOrder": {
+                    "type": "object",
+                    "description": "Order of the v1 API",
+                    "deprecated": true,
+                    "x-sunset": "2026-12-31",
+                    "properties": {
+                        "id": {"type": "string"},
+                        "total": {
+                            "type": "number",
+                            "description": "Order total",
+                            "deprecated": true,
+                            "x-deprecated-message": "Use totalAmount instead."
+                        },
+                        "totalAmount": {"type": "number"}
+                    }
+                }
+            }
+        }
+    }
+    "##;
+
+    let spec_path = temp_dir.path().join("spec.json");
+    fs::write(&spec_path, spec_json).unwrap();
+
+    let parsed = fetch_and_parse_spec(spec_path.to_str().unwrap())
+        .await
+        .unwrap();
+    let template_engine = TemplateEngine::new(None).unwrap();
+    let schema_names = vec!["LegacyOrder".to_string()];
+    let mut enum_registry = HashMap::new();
+
+    let types = generate_typings_with_registry_and_engine_and_spec(
+        &parsed.openapi,
+        &parsed.schemas,
+        &schema_names,
+        &mut enum_registry,
+        &[],
+        Some(&template_engine),
+        None,
+    )
+    .unwrap();
+    let zod_schemas = generate_zod_schemas_with_registry_and_engine_and_spec(
+        &parsed.openapi,
+        &parsed.schemas,
+        &schema_names,
+        &mut enum_registry,
+        &[],
+        Some(&template_engine),
+        None,
+    )
+    .unwrap();
+
+    let output: String = types
+        .iter()
+        .map(|t| t.content.clone())
+        .chain(zod_schemas.iter().map(|z| z.content.clone()))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    assert_snapshot!("deprecated_schemas", output);
+}
@@ -174,7 +174,7 @@ fn test_template_engine_render_type_alias() {
 #[test]
 fn test_template_registry_all() {
     let all = TemplateId::all();
-    assert_eq!(all.len(), 11);
+    assert_eq!(all.len(), 18);
     assert!(all.contains(&TemplateId::TypeInterface));
     assert!(all.contains(&TemplateId::TypeEnum));
     assert!(all.contains(&TemplateId::TypeAlias));
@@ -186,6 +186,13 @@ fn test_template_registry_all() {
     assert!(all.contains(&TemplateId::SwrQuery));
     assert!(all.contains(&TemplateId::SwrMutation));
     assert!(all.contains(&TemplateId::QueryKeys));
+    assert!(all.contains(&TemplateId::RuntimeTypes));
+    assert!(all.contains(&TemplateId::RuntimeHttpClient));
+    assert!(all.contains(&TemplateId::RuntimeIndex));
+    assert!(all.contains(&TemplateId::ApiClientAxios));
+    assert!(all.contains(&TemplateId::ApiClientAngular));
+    assert!(all.contains(&TemplateId::ApiClientSdkMethod));
+    assert!(all.contains(&TemplateId::RuntimeAxiosClient));
 }
 
 #[test]
@@ -1,6 +1,13 @@
 use std::fs;
 use tempfile::TempDir;
-use vika_cli::generator::writer::{ensure_directory, write_file_safe};
+use vika_cli::config::model::{ApisConfig, RetryPolicyConfig};
+use vika_cli::generator::api_client::{
+    generate_angular_service, generate_sdk_module_factory, ApiFunction,
+};
+use vika_cli::generator::writer::{
+    ensure_directory, write_api_client_with_options, write_file_safe, write_runtime_client,
+    write_runtime_client_with_options,
+};
 
 #[test]
 fn test_ensure_directory() {
@@ -43,3 +50,118 @@ fn test_write_file_safe_skip_unchanged() {
     let diff = mtime2.duration_since(mtime1).unwrap();
     assert!(diff.as_secs() < 2);
 }
+
+#[test]
+fn test_write_runtime_client_axios_style() {
+    let temp_dir = TempDir::new().unwrap();
+    let fetch_root = temp_dir.path().join("fetch");
+    let axios_root = temp_dir.path().join("axios");
+
+    write_runtime_client(&fetch_root, None, Some(&ApisConfig::default())).unwrap();
+    assert!(fetch_root.join("runtime/http-client.ts").exists());
+    assert!(!fetch_root.join("runtime/axios-client.ts").exists());
+
+    let apis_config = ApisConfig {
+        style: "axios".to_string(),
+        base_url: Some("https://spec.example.com".to_string()),
+        ..ApisConfig::default()
+    };
+    write_runtime_client(&axios_root, None, Some(&apis_config)).unwrap();
+    let axios_client = fs::read_to_string(axios_root.join("runtime/axios-client.ts")).unwrap();
+    assert!(axios_client.contains("export const axiosClient = new VikaAxiosClient();"));
+    assert!(!axios_client.contains("https://spec.example.com"));
+}
+
+#[test]
+fn test_write_runtime_client_is_unconfigured() {
+    let temp_dir = TempDir::new().unwrap();
+    let apis_config = ApisConfig {
+        base_url: Some("https://spec.example.com".to_string()),
+        retries: Some(7),
+        retry_policy: Some(RetryPolicyConfig {
+            status_codes: Some(vec![429, 503]),
+            ..RetryPolicyConfig::default()
+        }),
+        validate_responses: "always".to_string(),
+        ..ApisConfig::default()
+    };
+
+    // The runtime is shared by every spec; each spec's vika-client.ts carries its settings
+    write_runtime_client(temp_dir.path(), None, Some(&apis_config)).unwrap();
+    let http_client = fs::read_to_string(temp_dir.path().join("runtime/http-client.ts")).unwrap();
+    assert!(http_client.contains("export const vikaClient = new VikaClient();"));
+    assert!(!http_client.contains("https://spec.example.com"));
+    assert!(!http_client.contains("retries: 7"));
+}
+
+#[test]
+fn test_write_runtime_client_rewrites_existing_runtime() {
+    let temp_dir = TempDir::new().unwrap();
+    let http_client_file = temp_dir.path().join("runtime/http-client.ts");
+
+    // A runtime written by an older version is replaced, not kept
+    ensure_directory(&temp_dir.path().join("runtime")).unwrap();
+    write_file_safe(&http_client_file, "export class VikaClient {}").unwrap();
+    write_runtime_client_with_options(
+        temp_dir.path(),
+        None,
+        Some(&ApisConfig::default()),
+        false,
+        false,
+    )
+    .unwrap();
+    let http_client = fs::read_to_string(&http_client_file).unwrap();
+    assert!(http_client.contains("export const vikaClient = new VikaClient();"));
+}
+
+#[test]
+fn test_write_api_client_keeps_angular_service() {
+    let temp_dir = TempDir::new().unwrap();
+    let functions = vec![
+        ApiFunction {
+            content: "export type GetUserResponses = {\n    200: string;\n};".to_string(),
+        },
+        generate_angular_service(
+            "users",
+            &[
+                "  /**\n   * Get a user\n   */\n  getUser(id: string): Observable<string> {\n    return this.http.get<string>(`${this.baseUrl}/users/${id}`);\n  }".to_string(),
+            ],
+        ),
+    ];
+
+    let files =
+        write_api_client_with_options(temp_dir.path(), "users", &functions, None, false, false)
+            .unwrap();
+    let content = fs::read_to_string(&files[0]).unwrap();
+
+    assert!(content.contains("@Injectable({ providedIn: \"root\" })\nexport class UsersService {"));
+    assert!(content.contains("   * Get a user\n   */\n  getUser(id: string)"));
+    assert!(content
+        .contains("import { HttpClient, HttpHeaders, HttpParams } from \"@angular/common/http\";"));
+    assert!(content.trim_end().ends_with('}'));
+}
+
+#[test]
+fn test_write_api_client_keeps_sdk_factory() {
+    let temp_dir = TempDir::new().unwrap();
+    let functions = vec![
+        ApiFunction {
+            content: "import { vikaClient, type ApiResult } from \"../runtime\";\n\nexport const getUser = async (id: string): Promise<ApiResult<any, any>> => {\n    return vikaClient.get<any, any>(`/users/${id}`);\n};".to_string(),
+        },
+        generate_sdk_module_factory(
+            "users",
+            &["    getUser: async (id: string): Promise<ApiResult<any, any>> => {\n      return client.get<any, any>(`/users/${id}`);\n    },".to_string()],
+            "../runtime",
+        ),
+    ];
+
+    let files =
+        write_api_client_with_options(temp_dir.path(), "users", &functions, None, false, false)
+            .unwrap();
+    let content = fs::read_to_string(&files[0]).unwrap();
+
+    assert!(content.contains("export type UsersApi = ReturnType<typeof createUsersApi>;"));
+    assert!(content.contains("export function createUsersApi(client: VikaClient) {\n  return {"));
+    assert!(content.contains("      return client.get<any, any>(`/users/${id}`);\n    },\n  };\n}"));
+    assert!(content.contains("export const getUser = async"));
+}